@@ -0,0 +1,57 @@
+//! Compare the compiled [Format] path with the strftime pattern being parsed on every call
+//!
+//! Run with `cargo bench --bench format`
+
+use std::hint::black_box;
+
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+use criterion::{Criterion, criterion_group, criterion_main};
+use timeflow::prelude::*;
+
+const PATTERN: &str = "%Y-%m-%dT%H:%M:%S%.3f";
+const INPUT: &str = "2024-10-31T06:32:28.123";
+
+fn to_string(c: &mut Criterion) {
+    let mut group = c.benchmark_group("to_string");
+    let naive = NaiveDateTime::new(
+        NaiveDate::from_ymd_opt(2024, 10, 31).unwrap(),
+        NaiveTime::from_hms_milli_opt(6, 32, 28, 123).unwrap(),
+    );
+    group.bench_function("pattern parsed on every call", |b| {
+        b.iter(|| black_box(naive).format(black_box(PATTERN)).to_string())
+    });
+
+    let datetime = DateTime::from(naive).with_format(Format::datetime(PATTERN).unwrap());
+    group.bench_function("compiled format", |b| {
+        b.iter(|| black_box(&datetime).to_string())
+    });
+    group.finish();
+}
+
+fn parse(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parse");
+    group.bench_function("NaiveDateTime::parse_from_str", |b| {
+        b.iter(|| NaiveDateTime::parse_from_str(black_box(INPUT), black_box(PATTERN)).unwrap())
+    });
+    group.bench_function("TryFrom<(&str, &str)>", |b| {
+        b.iter(|| DateTime::try_from((black_box(INPUT), black_box(PATTERN))).unwrap())
+    });
+
+    let format = Format::datetime(PATTERN).unwrap();
+    group.bench_function("TryFrom<(&str, &Format)>", |b| {
+        b.iter(|| DateTime::try_from((black_box(INPUT), black_box(&format))).unwrap())
+    });
+    group.finish();
+}
+
+fn clone(c: &mut Criterion) {
+    let mut group = c.benchmark_group("clone");
+    let datetime = DateTime::try_from((INPUT, PATTERN)).unwrap();
+    group.bench_function("DateTime with shared format", |b| {
+        b.iter(|| black_box(&datetime).clone())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, to_string, parse, clone);
+criterion_main!(benches);
@@ -0,0 +1,350 @@
+use std::sync::{LazyLock, RwLock};
+
+#[cfg(any(feature = "date", feature = "datetime"))]
+use chrono::{Datelike, Days, NaiveDate, NaiveDateTime};
+use serde::{Deserialize, Serialize};
+
+use crate::context;
+#[cfg(any(feature = "date", feature = "datetime"))]
+use crate::{error::SpanError, span::Unit};
+
+pub(crate) static BASE_POLICY: LazyLock<RwLock<UpdatePolicy>> =
+    LazyLock::new(|| RwLock::new(UpdatePolicy::new()));
+
+/// What `update` does when the result is out of range
+///
+/// Out of range means past midnight for a [Time](crate::time::Time), and outside of the range
+/// supported by [chrono] for a [Date](crate::date::Date) or a [DateTime](crate::datetime::DateTime).
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Overflow {
+    /// Return an error, the default for Date and DateTime
+    Checked,
+    /// Stop at the bound, `00:00:00`/`23:59:59` for a Time
+    Saturating,
+    /// Wrap around midnight, the default for Time
+    ///
+    /// Dates do not wrap, so Date and DateTime return an error like [Overflow::Checked]
+    Wrapping,
+}
+
+/// What `update` does when adding months or years lands on a day missing from the month,
+/// e.g. January 31st plus one month
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MonthEnd {
+    /// Use the last day of the month, February 28th, the default
+    Clamp,
+    /// Carry the extra days into the next month, March 3rd
+    Overflow,
+    /// Return a [SpanError::InvalidDate]
+    Error,
+}
+
+/// How a difference in a unit is turned into a whole number of units
+///
+/// The fraction compared is the part of the next unit elapsed, e.g. 14 days out of the 28 of a
+/// February for half a month.
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Rounding {
+    /// Count completed units only, toward zero, the default
+    #[default]
+    Trunc,
+    /// Round to the nearest unit, half a unit away from zero
+    Round,
+    /// Round toward the past
+    Floor,
+    /// Round toward the future
+    Ceil,
+}
+
+#[cfg(any(feature = "date", feature = "datetime"))]
+impl Rounding {
+    /// Apply the rounding to `whole` completed units and the signed `fraction` of the next one
+    pub(crate) fn apply(self, whole: i64, fraction: f64) -> i64 {
+        match self {
+            Rounding::Trunc => whole,
+            Rounding::Round if fraction >= 0.5 => whole + 1,
+            Rounding::Round if fraction <= -0.5 => whole - 1,
+            Rounding::Floor if fraction < 0.0 => whole - 1,
+            Rounding::Ceil if fraction > 0.0 => whole + 1,
+            _ => whole,
+        }
+    }
+}
+
+/// [Overflow] and [MonthEnd] policies of `update`
+///
+/// A policy left unset falls back to the [Context](crate::context::Context), then to the
+/// [SpanBuilder](crate::builder::SpanBuilder), then to the default of the type.
+///
+/// # Example
+/// ```rust,ignore
+/// let policy = UpdatePolicy::new().month_end(MonthEnd::Overflow);
+/// let date = Date::new(2023, 1, 31)?.update_with(DateUnit::Month, 1, policy)?;
+/// assert_eq!(date.to_string(), "2023-03-03".to_string());
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone, Copy, Serialize, Deserialize)]
+pub struct UpdatePolicy {
+    overflow: Option<Overflow>,
+    month_end: Option<MonthEnd>,
+}
+
+impl UpdatePolicy {
+    /// Create a policy with nothing set
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Setter for the [Overflow] policy
+    pub fn overflow(mut self, overflow: Overflow) -> Self {
+        self.overflow = Some(overflow);
+        self
+    }
+
+    /// Setter for the [MonthEnd] policy
+    pub fn month_end(mut self, month_end: MonthEnd) -> Self {
+        self.month_end = Some(month_end);
+        self
+    }
+
+    /// Getter for the [Overflow] policy, if set
+    pub fn get_overflow(&self) -> Option<Overflow> {
+        self.overflow
+    }
+
+    /// Getter for the [MonthEnd] policy, if set
+    pub fn get_month_end(&self) -> Option<MonthEnd> {
+        self.month_end
+    }
+
+    /// Fill the policies left unset with the ones of `fallback`
+    pub(crate) fn or(self, fallback: Self) -> Self {
+        Self {
+            overflow: self.overflow.or(fallback.overflow),
+            month_end: self.month_end.or(fallback.month_end),
+        }
+    }
+
+    /// Fill the policies left unset with the context, then the global policy
+    pub(crate) fn resolve(self) -> Self {
+        self.or(context::current_policy())
+            .or(*BASE_POLICY.read().unwrap())
+    }
+}
+
+#[cfg(any(feature = "date", feature = "datetime"))]
+/// Add `months` to `date` following `month_end`
+///
+/// Return Ok(None) if the result is out of range
+pub(crate) fn add_months(
+    date: NaiveDate,
+    months: i64,
+    month_end: MonthEnd,
+) -> Result<Option<NaiveDate>, SpanError> {
+    let total = date.year() as i64 * 12 + date.month0() as i64 + months;
+    let Ok(year) = i32::try_from(total.div_euclid(12)) else {
+        return Ok(None);
+    };
+    let month = total.rem_euclid(12) as u32 + 1;
+    let Some(first) = NaiveDate::from_ymd_opt(year, month, 1) else {
+        return Ok(None);
+    };
+    let last = first.num_days_in_month() as u32;
+    let day = date.day();
+    if day <= last {
+        return Ok(first.with_day(day));
+    }
+    match month_end {
+        MonthEnd::Clamp => Ok(first.with_day(last)),
+        MonthEnd::Overflow => Ok(first
+            .with_day(last)
+            .and_then(|last_day| last_day.checked_add_days(Days::new((day - last) as u64)))),
+        MonthEnd::Error => Err(SpanError::InvalidDate(year, month, day)),
+    }
+}
+
+#[cfg(any(feature = "date", feature = "datetime"))]
+/// Completed `unit`s from `from` to `to` and the fraction of the next one, both negative when
+/// `to` is before `from`
+///
+/// Years and months are counted on the anniversaries of `from`, clamped to the end of shorter
+/// months, so January 31st to February 28th is one month but to February 27th is none.
+pub(crate) fn units_between(from: NaiveDateTime, to: NaiveDateTime, unit: Unit) -> (i64, f64) {
+    let (months, whole) = match unit {
+        Unit::Year => (12, whole_months(from, to) / 12),
+        Unit::Month => (1, whole_months(from, to)),
+        unit => {
+            let seconds = unit.as_seconds().unwrap_or(1);
+            let delta = to - from;
+            let whole = delta.num_seconds() / seconds;
+            let rest = (delta.num_seconds() % seconds) as f64
+                + delta.subsec_nanos() as f64 / 1_000_000_000.0;
+            return (whole, rest / seconds as f64);
+        }
+    };
+    let direction = if to < from { -1 } else { 1 };
+    let shift = |units: i64| {
+        add_months(from.date(), units * months, MonthEnd::Clamp)
+            .ok()
+            .flatten()
+            .map(|date| date.and_time(from.time()))
+    };
+    let fraction = match (shift(whole), shift(whole + direction)) {
+        (Some(anchor), Some(next)) => {
+            let elapsed = (to - anchor).as_seconds_f64();
+            let length = (next - anchor).as_seconds_f64();
+            elapsed / length * direction as f64
+        }
+        _ => 0.0,
+    };
+    (whole, fraction)
+}
+
+#[cfg(any(feature = "date", feature = "datetime"))]
+/// Completed months from `from` to `to`, negative when `to` is before `from`
+fn whole_months(from: NaiveDateTime, to: NaiveDateTime) -> i64 {
+    let shift = |months: i64| {
+        add_months(from.date(), months, MonthEnd::Clamp)
+            .ok()
+            .flatten()
+            .map(|date| date.and_time(from.time()))
+    };
+    let mut months = (to.year() as i64 * 12 + to.month0() as i64)
+        - (from.year() as i64 * 12 + from.month0() as i64);
+    match to < from {
+        false => {
+            while shift(months).is_none_or(|shifted| shifted > to) {
+                months -= 1;
+            }
+        }
+        true => {
+            while shift(months).is_none_or(|shifted| shifted < to) {
+                months += 1;
+            }
+        }
+    }
+    months
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+
+    #[cfg(any(feature = "date", feature = "datetime"))]
+    #[test]
+    fn add_months_month_end() -> Result<(), SpanError> {
+        assert_eq!(
+            add_months("2023-01-31".parse().unwrap(), 1, MonthEnd::Clamp)?,
+            Some("2023-02-28".parse().unwrap())
+        );
+        assert_eq!(
+            add_months("2023-01-31".parse().unwrap(), 1, MonthEnd::Overflow)?,
+            Some("2023-03-03".parse().unwrap())
+        );
+        assert_eq!(
+            add_months("2024-01-31".parse().unwrap(), 1, MonthEnd::Overflow)?,
+            Some("2024-03-02".parse().unwrap())
+        );
+        assert_eq!(
+            add_months("2023-01-31".parse().unwrap(), 1, MonthEnd::Error),
+            Err(SpanError::InvalidDate(2023, 2, 31))
+        );
+        assert_eq!(
+            add_months("2023-01-15".parse().unwrap(), 1, MonthEnd::Error)?,
+            Some("2023-02-15".parse().unwrap())
+        );
+        Ok(())
+    }
+
+    #[cfg(any(feature = "date", feature = "datetime"))]
+    #[test]
+    fn add_months_negative() -> Result<(), SpanError> {
+        assert_eq!(
+            add_months("2024-03-31".parse().unwrap(), -13, MonthEnd::Clamp)?,
+            Some("2023-02-28".parse().unwrap())
+        );
+        assert_eq!(
+            add_months("2024-01-15".parse().unwrap(), -1, MonthEnd::Clamp)?,
+            Some("2023-12-15".parse().unwrap())
+        );
+        Ok(())
+    }
+
+    #[cfg(any(feature = "date", feature = "datetime"))]
+    #[test]
+    fn add_months_out_of_range() -> Result<(), SpanError> {
+        assert_eq!(add_months(NaiveDate::MAX, 1, MonthEnd::Clamp)?, None);
+        assert_eq!(
+            add_months("2023-01-01".parse().unwrap(), i64::MAX / 2, MonthEnd::Clamp)?,
+            None
+        );
+        Ok(())
+    }
+
+    #[cfg(any(feature = "date", feature = "datetime"))]
+    #[test]
+    fn rounding_apply() {
+        assert_eq!(Rounding::Trunc.apply(1, 0.9), 1);
+        assert_eq!(Rounding::Round.apply(1, 0.5), 2);
+        assert_eq!(Rounding::Round.apply(-1, -0.4), -1);
+        assert_eq!(Rounding::Floor.apply(-1, -0.1), -2);
+        assert_eq!(Rounding::Floor.apply(1, 0.9), 1);
+        assert_eq!(Rounding::Ceil.apply(1, 0.1), 2);
+        assert_eq!(Rounding::Ceil.apply(-1, -0.9), -1);
+    }
+
+    #[cfg(any(feature = "date", feature = "datetime"))]
+    #[test]
+    fn units_between_months() {
+        assert_eq!(
+            units_between(
+                "2023-01-31T00:00:00".parse().unwrap(),
+                "2023-02-28T00:00:00".parse().unwrap(),
+                Unit::Month
+            ),
+            (1, 0.0)
+        );
+        assert_eq!(
+            units_between(
+                "2023-01-31T00:00:00".parse().unwrap(),
+                "2023-02-27T00:00:00".parse().unwrap(),
+                Unit::Month
+            )
+            .0,
+            0
+        );
+        assert_eq!(
+            units_between(
+                "2024-02-29T00:00:00".parse().unwrap(),
+                "2025-02-28T00:00:00".parse().unwrap(),
+                Unit::Year
+            ),
+            (1, 0.0)
+        );
+        assert_eq!(
+            units_between(
+                "2023-03-31T00:00:00".parse().unwrap(),
+                "2023-02-28T00:00:00".parse().unwrap(),
+                Unit::Month
+            ),
+            (-1, 0.0)
+        );
+        assert_eq!(
+            units_between(NaiveDateTime::MIN, NaiveDateTime::MAX, Unit::Year).1,
+            0.0
+        );
+    }
+
+    #[test]
+    fn policy_or() {
+        let policy = UpdatePolicy::new()
+            .overflow(Overflow::Saturating)
+            .or(UpdatePolicy::new()
+                .overflow(Overflow::Checked)
+                .month_end(MonthEnd::Error));
+        assert_eq!(policy.get_overflow(), Some(Overflow::Saturating));
+        assert_eq!(policy.get_month_end(), Some(MonthEnd::Error));
+    }
+}
@@ -0,0 +1,458 @@
+//! Time-series bucketing
+//!
+//! Buckets are consecutive ranges of a [BucketWidth], a number of [DateTimeUnit], aligned on an
+//! origin: 5-minute buckets from midnight, hourly buckets, or calendar months from the first of
+//! a month. Months and years follow the calendar, clamped to the end of shorter months, while
+//! the other units have a fixed length.
+//!
+//! # Example
+//! ```rust,ignore
+//! let width = BucketWidth::from_str("5m")?;
+//! let origin = DateTime::new(2023, 10, 9)?;
+//! let bucket = bucket_of(&DateTime::build("2023-10-09 10:07:30")?, width, &origin)?;
+//! assert_eq!(bucket.to_string(), "2023-10-09 10:05:00");
+//!
+//! let points = [(DateTime::build("2023-10-09 10:01:00")?, 1), (DateTime::build("2023-10-09 10:12:00")?, 2)];
+//! let groups = group_by_bucket(points, width, &origin)?;
+//! // The bucket of 10:05 is missing until filled
+//! let filled = fill_gaps(groups, width, &origin, |_| Vec::new());
+//! assert_eq!(filled.len(), 3);
+//! ```
+
+use std::{collections::BTreeMap, fmt::Display, str::FromStr};
+
+use chrono::{Datelike, NaiveDateTime, TimeDelta};
+
+use crate::{
+    arithmetic::{MonthEnd, add_months},
+    datetime::{DateTime, DateTimeUnit},
+    error::{DateTimeError, ErrorContext, SpanError},
+};
+
+/// Width of a bucket, a number of [DateTimeUnit]
+///
+/// Parsed and displayed as a number followed by a unit, e.g. `"5m"`, `"1h"`, `"1d"` or `"3M"`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct BucketWidth {
+    count: u32,
+    unit: DateTimeUnit,
+}
+
+impl BucketWidth {
+    /// Create a width of `count` `unit`s
+    ///
+    /// # Errors
+    /// Return an Err(_) if `count` is zero
+    pub fn new(count: u32, unit: DateTimeUnit) -> Result<Self, SpanError> {
+        if count == 0 {
+            return Err(SpanError::InvalidBucketWidth(format!("{count}{unit}")));
+        }
+        Ok(Self { count, unit })
+    }
+
+    /// Getter for the number of units
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+
+    /// Getter for the unit
+    pub fn unit(&self) -> DateTimeUnit {
+        self.unit
+    }
+
+    /// Months in the width, [None] for a fixed-length unit
+    fn months(&self) -> Option<i64> {
+        match self.unit {
+            DateTimeUnit::Year => Some(self.count as i64 * 12),
+            DateTimeUnit::Month => Some(self.count as i64),
+            _ => None,
+        }
+    }
+
+    /// Start of the bucket `index` buckets after the one starting at `origin`
+    fn start(&self, origin: NaiveDateTime, index: i64) -> Option<NaiveDateTime> {
+        match (self.months(), self.unit.as_seconds()) {
+            (Some(months), _) => {
+                add_months(origin.date(), index.checked_mul(months)?, MonthEnd::Clamp)
+                    .ok()
+                    .flatten()
+                    .map(|date| date.and_time(origin.time()))
+            }
+            (None, Some(seconds)) => {
+                let seconds = index.checked_mul(self.count as i64 * seconds)?;
+                origin.checked_add_signed(TimeDelta::try_seconds(seconds)?)
+            }
+            (None, None) => None,
+        }
+    }
+
+    /// Index from the bucket starting at `origin` of the bucket containing `datetime`
+    fn index(&self, origin: NaiveDateTime, datetime: NaiveDateTime) -> i64 {
+        let Some(months) = self.months() else {
+            let seconds = self.unit.as_seconds().unwrap_or(1) * self.count as i64;
+            let delta = datetime - origin;
+            // Whole seconds rounded down, so that an instant just before `origin` is before it
+            let elapsed = delta.num_seconds() - i64::from(delta.subsec_nanos() < 0);
+            return elapsed.div_euclid(seconds);
+        };
+        let elapsed = (datetime.year() as i64 * 12 + datetime.month0() as i64)
+            - (origin.year() as i64 * 12 + origin.month0() as i64);
+        let mut index = elapsed.div_euclid(months);
+        // The day and time of `origin` in the month may move the bucket by one
+        while self
+            .start(origin, index)
+            .is_some_and(|start| start > datetime)
+        {
+            index -= 1;
+        }
+        while self
+            .start(origin, index + 1)
+            .is_some_and(|start| start <= datetime)
+        {
+            index += 1;
+        }
+        index
+    }
+}
+
+impl Display for BucketWidth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let unit = match self.unit {
+            DateTimeUnit::Year => "y",
+            DateTimeUnit::Month => "M",
+            DateTimeUnit::Day => "d",
+            DateTimeUnit::Hour => "h",
+            DateTimeUnit::Minute => "m",
+            DateTimeUnit::Second => "s",
+        };
+        write!(f, "{}{unit}", self.count)
+    }
+}
+
+impl FromStr for BucketWidth {
+    type Err = SpanError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || SpanError::InvalidBucketWidth(s.to_string());
+        let split = s.find(|c: char| !c.is_ascii_digit()).ok_or_else(invalid)?;
+        let (count, unit) = s.split_at(split);
+        let count = match count {
+            "" => 1,
+            count => count.parse().map_err(|_| invalid())?,
+        };
+        let unit = DateTimeUnit::from_str(unit.trim()).map_err(|_| invalid())?;
+        Self::new(count, unit)
+    }
+}
+
+/// Start of the bucket of `width` containing `datetime`, buckets being aligned on `origin`,
+/// keeping the format and locale of `datetime`
+///
+/// # Example
+/// ```rust,ignore
+/// let origin = DateTime::new(2023, 1, 31)?;
+/// let width = BucketWidth::new(1, DateTimeUnit::Month)?;
+/// let bucket = bucket_of(&DateTime::build("2023-03-15 12:00:00")?, width, &origin)?;
+/// assert_eq!(bucket.to_string(), "2023-02-28 00:00:00");
+/// ```
+///
+/// # Errors
+/// Return an Err(_) if the start of the bucket is out of range
+pub fn bucket_of(
+    datetime: &DateTime,
+    width: BucketWidth,
+    origin: &DateTime,
+) -> Result<DateTime, SpanError> {
+    let index = width.index(origin.datetime(), datetime.datetime());
+    width
+        .start(origin.datetime(), index)
+        .map(|start| datetime.with_naive(start))
+        .ok_or_else(|| SpanError::InvalidUpdate {
+            input: datetime.to_string(),
+            unit: width.unit.into(),
+            value: width.count.into(),
+        })
+        .err_ctx(DateTimeError)
+}
+
+/// Iterator over the starts of consecutive buckets, see [buckets]
+#[derive(Debug, Clone)]
+pub struct Buckets {
+    width: BucketWidth,
+    origin: DateTime,
+    index: i64,
+    end: NaiveDateTime,
+}
+
+impl Iterator for Buckets {
+    type Item = DateTime;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let start = self.width.start(self.origin.datetime(), self.index)?;
+        if start >= self.end {
+            return None;
+        }
+        self.index += 1;
+        Some(self.origin.with_naive(start))
+    }
+}
+
+/// Starts of the buckets of `width` aligned on `origin` covering `start` included to `end`
+/// excluded, with the format and locale of `origin`
+///
+/// # Example
+/// ```rust,ignore
+/// let origin = DateTime::new(2023, 10, 9)?;
+/// let hours = buckets(&DateTime::build("2023-10-09 10:30:00")?, &DateTime::build("2023-10-09 12:00:00")?, BucketWidth::from_str("1h")?, &origin);
+/// assert_eq!(hours.map(|start| start.to_string()).collect::<Vec<_>>(), ["2023-10-09 10:00:00", "2023-10-09 11:00:00"]);
+/// ```
+pub fn buckets(start: &DateTime, end: &DateTime, width: BucketWidth, origin: &DateTime) -> Buckets {
+    Buckets {
+        width,
+        origin: origin.clone(),
+        index: width.index(origin.datetime(), start.datetime()),
+        end: end.datetime(),
+    }
+}
+
+/// Group `values` by the start of the bucket of `width` aligned on `origin` containing their
+/// [DateTime], keeping their order within a bucket
+///
+/// Buckets are keyed by their [NaiveDateTime] start, so values of the same instant are in the
+/// same bucket whatever their format.
+///
+/// # Errors
+/// Return an Err(_) if the start of a bucket is out of range
+pub fn group_by_bucket<T>(
+    values: impl IntoIterator<Item = (DateTime, T)>,
+    width: BucketWidth,
+    origin: &DateTime,
+) -> Result<BTreeMap<NaiveDateTime, Vec<T>>, SpanError> {
+    let mut groups: BTreeMap<NaiveDateTime, Vec<T>> = BTreeMap::new();
+    for (datetime, value) in values {
+        groups
+            .entry(bucket_of(&datetime, width, origin)?.datetime())
+            .or_default()
+            .push(value);
+    }
+    Ok(groups)
+}
+
+/// Add the buckets missing between the first and the last bucket of `groups`, with the value
+/// given by `fill` for their start
+///
+/// Buckets are those of `width` aligned on `origin`, keyed by their start as returned by
+/// [group_by_bucket].
+pub fn fill_gaps<V>(
+    mut groups: BTreeMap<NaiveDateTime, V>,
+    width: BucketWidth,
+    origin: &DateTime,
+    mut fill: impl FnMut(NaiveDateTime) -> V,
+) -> BTreeMap<NaiveDateTime, V> {
+    let (Some((first, _)), Some((last, _))) = (groups.first_key_value(), groups.last_key_value())
+    else {
+        return groups;
+    };
+    let origin = origin.datetime();
+    let (first, last) = (width.index(origin, *first), width.index(origin, *last));
+    for start in (first..=last).filter_map(|index| width.start(origin, index)) {
+        groups.entry(start).or_insert_with(|| fill(start));
+    }
+    groups
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use crate::span::Span;
+
+    #[test]
+    fn bucket_width_parse() -> Result<(), SpanError> {
+        assert_eq!(
+            BucketWidth::from_str("5m")?,
+            BucketWidth::new(5, DateTimeUnit::Minute)?
+        );
+        assert_eq!(
+            BucketWidth::from_str("1M")?,
+            BucketWidth::new(1, DateTimeUnit::Month)?
+        );
+        assert_eq!(
+            BucketWidth::from_str("15 minutes")?,
+            BucketWidth::new(15, DateTimeUnit::Minute)?
+        );
+        assert_eq!(
+            BucketWidth::from_str("h")?,
+            BucketWidth::new(1, DateTimeUnit::Hour)?
+        );
+        assert_eq!(BucketWidth::from_str("3M")?.to_string(), "3M");
+        assert_eq!(BucketWidth::from_str("2 days")?.to_string(), "2d");
+        for invalid in ["", "5", "0h", "5 weeks", "-1h"] {
+            assert_eq!(
+                BucketWidth::from_str(invalid).unwrap_err().code(),
+                "invalid_bucket_width"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn bucket_of_fixed() -> Result<(), SpanError> {
+        let origin = DateTime::new(2023, 10, 9)?;
+        let bucket = |datetime: &str, width: &str| {
+            bucket_of(&DateTime::try_from(datetime)?, width.parse()?, &origin)
+                .map(|bucket| bucket.to_string())
+        };
+        assert_eq!(bucket("2023-10-09 10:07:30", "5m")?, "2023-10-09 10:05:00");
+        assert_eq!(bucket("2023-10-09 10:05:00", "5m")?, "2023-10-09 10:05:00");
+        // Before the origin
+        assert_eq!(bucket("2023-10-08 23:59:59", "15m")?, "2023-10-08 23:45:00");
+        assert_eq!(bucket("2023-10-12 18:00:00", "2d")?, "2023-10-11 00:00:00");
+        // Same as truncate for a single unit aligned on midnight
+        let datetime = DateTime::try_from("2023-10-12 18:27:03")?;
+        assert_eq!(
+            bucket_of(&datetime, BucketWidth::from_str("1h")?, &origin)?,
+            datetime.truncate(DateTimeUnit::Hour)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn bucket_of_months() -> Result<(), SpanError> {
+        let origin = DateTime::new(2023, 1, 31)?;
+        let bucket = |datetime: &str, width: &str| {
+            bucket_of(&DateTime::try_from(datetime)?, width.parse()?, &origin)
+                .map(|bucket| bucket.to_string())
+        };
+        assert_eq!(bucket("2023-03-15 12:00:00", "1M")?, "2023-02-28 00:00:00");
+        assert_eq!(bucket("2023-03-31 00:00:00", "1M")?, "2023-03-31 00:00:00");
+        assert_eq!(bucket("2023-01-30 23:59:59", "1M")?, "2022-12-31 00:00:00");
+        assert_eq!(bucket("2023-08-01 00:00:00", "3M")?, "2023-07-31 00:00:00");
+        assert_eq!(bucket("2021-06-01 00:00:00", "1y")?, "2021-01-31 00:00:00");
+        Ok(())
+    }
+
+    #[test]
+    fn bucket_iterator() -> Result<(), SpanError> {
+        let origin = DateTime::new(2023, 10, 9)?;
+        let starts = buckets(
+            &DateTime::try_from("2023-10-09 10:30:00")?,
+            &DateTime::try_from("2023-10-09 13:00:00")?,
+            BucketWidth::from_str("1h")?,
+            &origin,
+        )
+        .map(|start| start.to_string())
+        .collect::<Vec<_>>();
+        assert_eq!(
+            starts,
+            [
+                "2023-10-09 10:00:00",
+                "2023-10-09 11:00:00",
+                "2023-10-09 12:00:00"
+            ]
+        );
+        let empty = buckets(
+            &DateTime::try_from("2023-10-09 13:00:00")?,
+            &DateTime::try_from("2023-10-09 10:00:00")?,
+            BucketWidth::from_str("1h")?,
+            &origin,
+        );
+        assert_eq!(empty.count(), 0);
+        // Months keep the day of the origin when possible
+        let months = buckets(
+            &DateTime::new(2023, 1, 31)?,
+            &DateTime::new(2023, 5, 1)?,
+            BucketWidth::from_str("1M")?,
+            &DateTime::new(2023, 1, 31)?,
+        )
+        .map(|start| start.to_string())
+        .collect::<Vec<_>>();
+        assert_eq!(
+            months,
+            [
+                "2023-01-31 00:00:00",
+                "2023-02-28 00:00:00",
+                "2023-03-31 00:00:00",
+                "2023-04-30 00:00:00"
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn bucket_out_of_range() -> Result<(), SpanError> {
+        let origin = DateTime::new(2023, 10, 9)?.with_time(12, 0, 0)?;
+        let err = bucket_of(
+            &DateTime::from(NaiveDateTime::MIN),
+            BucketWidth::from_str("1d")?,
+            &origin,
+        )
+        .unwrap_err();
+        assert_eq!(err.code(), "invalid_update");
+        Ok(())
+    }
+
+    fn listed<V: Clone>(groups: &BTreeMap<NaiveDateTime, V>) -> Vec<(String, V)> {
+        groups
+            .iter()
+            .map(|(start, value)| (start.to_string(), value.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn bucket_group_and_fill() -> Result<(), SpanError> {
+        let origin = DateTime::new(2023, 10, 9)?;
+        let width = BucketWidth::from_str("5m")?;
+        let points = [
+            (DateTime::try_from("2023-10-09 10:01:00")?, 1),
+            (DateTime::try_from("2023-10-09 10:12:00")?, 2),
+            (
+                DateTime::try_from("2023-10-09 10:03:00")?.format("%d/%m/%Y %H:%M"),
+                3,
+            ),
+            (DateTime::try_from("2023-10-09 10:24:59")?, 4),
+        ];
+        let groups = group_by_bucket(points, width, &origin)?;
+        // Whatever their format, values of the same bucket are grouped together
+        assert_eq!(
+            listed(&groups),
+            [
+                ("2023-10-09 10:00:00".to_string(), vec![1, 3]),
+                ("2023-10-09 10:10:00".to_string(), vec![2]),
+                ("2023-10-09 10:20:00".to_string(), vec![4]),
+            ]
+        );
+        let filled = fill_gaps(groups, width, &origin, |_| Vec::new());
+        assert_eq!(
+            listed(&filled),
+            [
+                ("2023-10-09 10:00:00".to_string(), vec![1, 3]),
+                ("2023-10-09 10:05:00".to_string(), vec![]),
+                ("2023-10-09 10:10:00".to_string(), vec![2]),
+                ("2023-10-09 10:15:00".to_string(), vec![]),
+                ("2023-10-09 10:20:00".to_string(), vec![4]),
+            ]
+        );
+        assert!(fill_gaps(BTreeMap::<NaiveDateTime, i32>::new(), width, &origin, |_| 0).is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn bucket_fill_months() -> Result<(), SpanError> {
+        let origin = DateTime::new(2023, 1, 31)?;
+        let sums = BTreeMap::from([
+            (DateTime::new(2023, 1, 31)?.datetime(), 10),
+            (DateTime::new(2023, 4, 30)?.datetime(), 5),
+        ]);
+        let filled = fill_gaps(sums, BucketWidth::from_str("1M")?, &origin, |_| 0);
+        assert_eq!(
+            listed(&filled),
+            [
+                ("2023-01-31 00:00:00".to_string(), 10),
+                ("2023-02-28 00:00:00".to_string(), 0),
+                ("2023-03-31 00:00:00".to_string(), 0),
+                ("2023-04-30 00:00:00".to_string(), 5),
+            ]
+        );
+        Ok(())
+    }
+}
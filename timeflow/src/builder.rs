@@ -1,24 +1,79 @@
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+
+use crate::arithmetic::{BASE_POLICY, MonthEnd, Overflow, UpdatePolicy};
+use crate::context::Context;
 #[cfg(feature = "date")]
 use crate::date::BASE_DATE_FORMAT;
 #[cfg(feature = "datetime")]
 use crate::datetime::BASE_DATETIME_FORMAT;
+#[cfg(feature = "date")]
+use crate::error::DateError;
+#[cfg(feature = "datetime")]
+use crate::error::DateTimeError;
+#[cfg(feature = "time")]
+use crate::error::TimeError;
+use crate::error::{ErrorContext, SpanError};
+#[cfg(any(feature = "date", feature = "datetime"))]
+use crate::format::DEFAULT_DATE_FORMAT;
+#[cfg(any(feature = "time", feature = "datetime"))]
+use crate::format::DEFAULT_TIME_FORMAT;
+use crate::format::{Format, FormatKind};
+use crate::locale::{BASE_LOCALE, Locale};
 #[cfg(feature = "time")]
 use crate::time::BASE_TIME_FORMAT;
 
+/// Environment variable read by [SpanBuilder::from_env] for the date format
+pub const ENV_DATE_FORMAT: &str = "TIMEFLOW_DATE_FORMAT";
+/// Environment variable read by [SpanBuilder::from_env] for the time format
+pub const ENV_TIME_FORMAT: &str = "TIMEFLOW_TIME_FORMAT";
+/// Environment variable read by [SpanBuilder::from_env] for the datetime format
+pub const ENV_DATETIME_FORMAT: &str = "TIMEFLOW_DATETIME_FORMAT";
+/// Environment variable read by [SpanBuilder::from_env] for the locale
+pub const ENV_LOCALE: &str = "TIMEFLOW_LOCALE";
+
 /// Builder to set the default date, time, and datetime format
 ///
+/// Formats can be `&'static str` or owned [String], so they can be loaded at runtime.
+/// The builder can also be deserialized (e.g. from a TOML or JSON configuration file),
+/// or read from the environment with [SpanBuilder::from_env].
+///
 /// # Attributes
-/// - date_format: Option<&'static str>
-/// - time_format: Option<&'static str>
-/// - datetime_format: Option<&'static str>
-#[derive(Debug, Clone, Default)]
+/// - date_format: Option<Cow<'static, str>>
+/// - time_format: Option<Cow<'static, str>>
+/// - datetime_format: Option<Cow<'static, str>>
+/// - locale: Option<[Locale]>
+/// - overflow: Option<[Overflow]>
+/// - month_end: Option<[MonthEnd]>
+///
+/// # Example
+/// ```rust,ignore
+/// // config.toml
+/// // [timeflow]
+/// // date_format = "%d/%m/%Y"
+/// // locale = "fr"
+/// let builder: SpanBuilder = toml::from_str(&config)?;
+/// builder.try_build()?;
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
 pub struct SpanBuilder {
     #[cfg(feature = "date")]
-    date_format: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    date_format: Option<Cow<'static, str>>,
     #[cfg(feature = "time")]
-    time_format: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    time_format: Option<Cow<'static, str>>,
     #[cfg(feature = "datetime")]
-    datetime_format: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    datetime_format: Option<Cow<'static, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    locale: Option<Locale>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    overflow: Option<Overflow>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    month_end: Option<MonthEnd>,
 }
 
 impl SpanBuilder {
@@ -30,51 +85,301 @@ impl SpanBuilder {
     ///    .date_format("%d %m %Y")
     ///    .time_format("T%H:%M:%SZ.000")
     ///    .datetime_format("%Y-%m-%d %H:%M:%S")
+    ///    .locale(Locale::Fr)
     ///    .build();
     /// ```
     pub fn builder() -> Self {
         Self::default()
     }
 
+    /// Create a SpanBuilder from the environment variables [ENV_DATE_FORMAT], [ENV_TIME_FORMAT],
+    /// [ENV_DATETIME_FORMAT] and [ENV_LOCALE]
+    ///
+    /// Missing variables are left unset, so the default format or locale is used.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// // TIMEFLOW_DATE_FORMAT="%d/%m/%Y" TIMEFLOW_LOCALE="fr-FR" cargo run
+    /// SpanBuilder::from_env()?.try_build()?;
+    /// ```
+    ///
+    /// # Errors
+    /// Return an Err(_) if [ENV_LOCALE] is not a supported [Locale]
+    pub fn from_env() -> Result<Self, SpanError> {
+        Self::from_lookup(|name| std::env::var(name).ok())
+    }
+
+    /// Create a SpanBuilder from the variables returned by `lookup`
+    fn from_lookup(lookup: impl Fn(&str) -> Option<String>) -> Result<Self, SpanError> {
+        let mut builder = Self::default();
+        #[cfg(feature = "date")]
+        if let Some(date_format) = lookup(ENV_DATE_FORMAT) {
+            builder.date_format(date_format);
+        }
+        #[cfg(feature = "time")]
+        if let Some(time_format) = lookup(ENV_TIME_FORMAT) {
+            builder.time_format(time_format);
+        }
+        #[cfg(feature = "datetime")]
+        if let Some(datetime_format) = lookup(ENV_DATETIME_FORMAT) {
+            builder.datetime_format(datetime_format);
+        }
+        if let Some(locale) = lookup(ENV_LOCALE) {
+            builder.locale(locale.parse()?);
+        }
+        Ok(builder)
+    }
+
     /// Setter for the date format
     #[cfg(feature = "date")]
-    pub fn date_format(&mut self, date_format: &'static str) -> &mut Self {
-        self.date_format = Some(date_format);
+    pub fn date_format(&mut self, date_format: impl Into<Cow<'static, str>>) -> &mut Self {
+        self.date_format = Some(date_format.into());
         self
     }
 
     /// Setter for the time format
     #[cfg(feature = "time")]
-    pub fn time_format(&mut self, time_format: &'static str) -> &mut Self {
-        self.time_format = Some(time_format);
+    pub fn time_format(&mut self, time_format: impl Into<Cow<'static, str>>) -> &mut Self {
+        self.time_format = Some(time_format.into());
         self
     }
 
     /// Setter for the datetime format
     #[cfg(feature = "datetime")]
-    pub fn datetime_format(&mut self, datetime_format: &'static str) -> &mut Self {
-        self.datetime_format = Some(datetime_format);
+    pub fn datetime_format(&mut self, datetime_format: impl Into<Cow<'static, str>>) -> &mut Self {
+        self.datetime_format = Some(datetime_format.into());
+        self
+    }
+
+    /// Setter for the locale used to display and parse month names, weekday names and AM/PM markers
+    pub fn locale(&mut self, locale: Locale) -> &mut Self {
+        self.locale = Some(locale);
+        self
+    }
+
+    /// Setter for the [Overflow] policy of `update`
+    pub fn overflow(&mut self, overflow: Overflow) -> &mut Self {
+        self.overflow = Some(overflow);
+        self
+    }
+
+    /// Setter for the [MonthEnd] policy of `update`
+    pub fn month_end(&mut self, month_end: MonthEnd) -> &mut Self {
+        self.month_end = Some(month_end);
         self
     }
 
-    /// Consume the builder and set the default date, time, and datetime format
+    /// [UpdatePolicy] with the policies set in the builder
+    fn policy(&self) -> UpdatePolicy {
+        let mut policy = UpdatePolicy::new();
+        if let Some(overflow) = self.overflow {
+            policy = policy.overflow(overflow);
+        }
+        if let Some(month_end) = self.month_end {
+            policy = policy.month_end(month_end);
+        }
+        policy
+    }
+
+    /// Consume the builder and set the default date, time, and datetime format, the default locale
+    /// and the default update policy
+    ///
+    /// Without datetime format, the default datetime format is the date and time formats joined by a space
     pub fn build(&self) {
         #[cfg(feature = "date")]
-        match self.date_format {
-            Some(date_format) => *BASE_DATE_FORMAT.write().unwrap() = date_format,
-            None => *BASE_DATE_FORMAT.write().unwrap() = "%Y-%m-%d",
+        {
+            let date_format = self.date_format.as_deref().unwrap_or(DEFAULT_DATE_FORMAT);
+            *BASE_DATE_FORMAT.write().unwrap() = Format::compile(date_format, FormatKind::Date);
+        }
+
+        #[cfg(feature = "time")]
+        {
+            let time_format = self.time_format.as_deref().unwrap_or(DEFAULT_TIME_FORMAT);
+            *BASE_TIME_FORMAT.write().unwrap() = Format::compile(time_format, FormatKind::Time);
+        }
+
+        #[cfg(feature = "datetime")]
+        {
+            let datetime_format = match &self.datetime_format {
+                Some(datetime_format) => datetime_format.to_string(),
+                None => self.joined_format(),
+            };
+            *BASE_DATETIME_FORMAT.write().unwrap() =
+                Format::compile(datetime_format, FormatKind::DateTime);
+        }
+
+        *BASE_LOCALE.write().unwrap() = self.locale.unwrap_or_default();
+        *BASE_POLICY.write().unwrap() = self.policy();
+    }
+
+    /// Date and time formats joined by a space
+    #[cfg(feature = "datetime")]
+    fn joined_format(&self) -> String {
+        #[cfg(feature = "date")]
+        let date_format = self.date_format.as_deref().unwrap_or(DEFAULT_DATE_FORMAT);
+        #[cfg(not(feature = "date"))]
+        let date_format = DEFAULT_DATE_FORMAT;
+        #[cfg(feature = "time")]
+        let time_format = self.time_format.as_deref().unwrap_or(DEFAULT_TIME_FORMAT);
+        #[cfg(not(feature = "time"))]
+        let time_format = DEFAULT_TIME_FORMAT;
+        format!("{date_format} {time_format}")
+    }
+
+    /// Validate the formats with [Format] then set them as default, like [SpanBuilder::build]
+    ///
+    /// # Errors
+    /// Return an Err(_) on the first invalid format, in which case no default is changed
+    pub fn try_build(&self) -> Result<(), SpanError> {
+        self.validate()?;
+        self.build();
+        Ok(())
+    }
+
+    /// Create a [Context] with the formats and locale of the builder, to be used with
+    /// [with_context](crate::context::with_context) instead of changing the global defaults
+    ///
+    /// Unlike [SpanBuilder::build], anything not set in the builder falls back to the global defaults.
+    /// Without datetime format, the datetime format is the date and time formats joined by a space
+    /// if any of them is set.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let context = SpanBuilder::builder().date_format("%d/%m/%Y").context();
+    /// let date = timeflow::with_context(&context, || Date::new(2023, 10, 9))?;
+    /// assert_eq!(date.to_string(), "09/10/2023".to_string());
+    /// ```
+    pub fn context(&self) -> Context {
+        let mut context = Context::new();
+        #[cfg(feature = "date")]
+        if let Some(date_format) = &self.date_format {
+            context.format(Format::compile(date_format.as_ref(), FormatKind::Date));
+        }
+
+        #[cfg(feature = "time")]
+        if let Some(time_format) = &self.time_format {
+            context.format(Format::compile(time_format.as_ref(), FormatKind::Time));
+        }
+
+        #[cfg(feature = "datetime")]
+        {
+            let datetime_format = match &self.datetime_format {
+                Some(datetime_format) => Some(datetime_format.to_string()),
+                None => (context.get_format(FormatKind::Date).is_some()
+                    || context.get_format(FormatKind::Time).is_some())
+                .then(|| self.joined_format()),
+            };
+            if let Some(datetime_format) = datetime_format {
+                context.format(Format::compile(datetime_format, FormatKind::DateTime));
+            }
+        }
+
+        if let Some(locale) = self.locale {
+            context.locale(locale);
+        }
+        context.policy(self.policy());
+        context
+    }
+
+    /// Validate the formats with [Format] then create a [Context], like [SpanBuilder::context]
+    ///
+    /// # Errors
+    /// Return an Err(_) on the first invalid format
+    pub fn try_context(&self) -> Result<Context, SpanError> {
+        self.validate()?;
+        Ok(self.context())
+    }
+
+    fn validate(&self) -> Result<(), SpanError> {
+        #[cfg(feature = "date")]
+        if let Some(date_format) = &self.date_format {
+            Format::date(date_format).err_ctx(DateError)?;
         }
 
         #[cfg(feature = "time")]
-        match self.time_format {
-            Some(time_format) => *BASE_TIME_FORMAT.write().unwrap() = time_format,
-            None => *BASE_TIME_FORMAT.write().unwrap() = "%H:%M:%S",
+        if let Some(time_format) = &self.time_format {
+            Format::time(time_format).err_ctx(TimeError)?;
         }
 
         #[cfg(feature = "datetime")]
-        match self.datetime_format {
-            Some(datetime_format) => *BASE_DATETIME_FORMAT.write().unwrap() = Some(datetime_format),
-            None => *BASE_DATETIME_FORMAT.write().unwrap() = None,
+        if let Some(datetime_format) = &self.datetime_format {
+            Format::datetime(datetime_format).err_ctx(DateTimeError)?;
         }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+
+    #[cfg(all(feature = "date", feature = "time", feature = "datetime"))]
+    #[test]
+    fn builder_owned_formats() {
+        let date_format = String::from("%d/%m/%Y");
+        let mut builder = SpanBuilder::builder();
+        builder.date_format(date_format).time_format("%Hh%M");
+        assert_eq!(builder.date_format.as_deref(), Some("%d/%m/%Y"));
+        assert_eq!(builder.joined_format(), "%d/%m/%Y %Hh%M".to_string());
+    }
+
+    #[cfg(all(feature = "date", feature = "time", feature = "datetime"))]
+    #[test]
+    fn builder_deserialize() -> Result<(), serde_json::Error> {
+        let builder: SpanBuilder =
+            serde_json::from_str(r#"{"date_format":"%d/%m/%Y","locale":"fr"}"#)?;
+        let mut expected = SpanBuilder::builder();
+        expected.date_format("%d/%m/%Y").locale(Locale::Fr);
+        assert_eq!(builder, expected);
+        assert!(serde_json::from_str::<SpanBuilder>(r#"{"date":"%d/%m/%Y"}"#).is_err());
+        Ok(())
+    }
+
+    #[cfg(all(feature = "date", feature = "time", feature = "datetime"))]
+    #[test]
+    fn builder_serialize() -> Result<(), serde_json::Error> {
+        let mut builder = SpanBuilder::builder();
+        builder.time_format("%H:%M");
+        assert_eq!(
+            serde_json::to_string(&builder)?,
+            r#"{"time_format":"%H:%M"}"#
+        );
+        Ok(())
+    }
+
+    #[cfg(all(feature = "date", feature = "time", feature = "datetime"))]
+    #[test]
+    fn builder_from_env() -> Result<(), SpanError> {
+        let vars = std::collections::HashMap::from([
+            (ENV_DATETIME_FORMAT, "%d/%m/%Y %H:%M"),
+            (ENV_LOCALE, "de-DE"),
+        ]);
+        let builder = SpanBuilder::from_lookup(|name| vars.get(name).map(|var| var.to_string()))?;
+        let mut expected = SpanBuilder::builder();
+        expected
+            .datetime_format("%d/%m/%Y %H:%M")
+            .locale(Locale::De);
+        assert_eq!(builder, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn builder_policy() -> Result<(), serde_json::Error> {
+        let builder: SpanBuilder = serde_json::from_str(r#"{"overflow":"saturating"}"#)?;
+        let mut expected = SpanBuilder::builder();
+        expected.overflow(Overflow::Saturating);
+        assert_eq!(builder, expected);
+        assert_eq!(
+            builder.policy(),
+            UpdatePolicy::new().overflow(Overflow::Saturating)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn builder_from_env_unknown_locale() {
+        let builder =
+            SpanBuilder::from_lookup(|name| (name == ENV_LOCALE).then(|| "pt-BR".to_string()));
+        assert_eq!(builder, Err(SpanError::UnknownLocale("pt-BR".to_string())));
     }
 }
@@ -0,0 +1,300 @@
+//! Business days and business-day rolling
+//!
+//! A [BusinessCalendar] knows the weekend days and the holidays, and rolls a [Date] falling on
+//! a non-business day following a [BusinessDayConvention].
+//!
+//! # Example
+//! ```rust,ignore
+//! let calendar = BusinessCalendar::new().holiday(NaiveDate::from_ymd_opt(2023, 12, 25).unwrap());
+//! let christmas = Date::new(2023, 12, 25)?;
+//! assert_eq!(calendar.adjust(&christmas, BusinessDayConvention::Following)?.to_string(), "2023-12-26");
+//! assert_eq!(calendar.add_business_days(&christmas, -1)?.to_string(), "2023-12-22");
+//! ```
+
+use std::collections::BTreeSet;
+
+use chrono::{Datelike, NaiveDate, Weekday};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    date::Date,
+    error::{DateError, ErrorContext, SpanError},
+    span::Unit,
+};
+
+/// How a date falling on a non-business day is moved
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BusinessDayConvention {
+    /// Keep the date, the default
+    #[default]
+    Unadjusted,
+    /// Move to the next business day
+    Following,
+    /// Move to the next business day, unless it is in the next month, then to the previous one
+    ModifiedFollowing,
+    /// Move to the previous business day
+    Preceding,
+    /// Move to the previous business day, unless it is in the previous month, then to the next
+    /// one
+    ModifiedPreceding,
+}
+
+/// Weekend days and holidays
+///
+/// The weekend is Saturday and Sunday by default.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BusinessCalendar {
+    weekend: Vec<Weekday>,
+    holidays: BTreeSet<NaiveDate>,
+}
+
+impl Default for BusinessCalendar {
+    fn default() -> Self {
+        Self {
+            weekend: vec![Weekday::Sat, Weekday::Sun],
+            holidays: BTreeSet::new(),
+        }
+    }
+}
+
+impl BusinessCalendar {
+    /// Create a calendar with a Saturday and Sunday weekend and without holidays
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Setter for the weekend days
+    pub fn weekend(mut self, weekend: impl IntoIterator<Item = Weekday>) -> Self {
+        self.weekend = weekend.into_iter().collect();
+        self
+    }
+
+    /// Add `date` to the holidays
+    pub fn holiday(mut self, date: NaiveDate) -> Self {
+        self.holidays.insert(date);
+        self
+    }
+
+    /// Add `dates` to the holidays
+    pub fn holidays(mut self, dates: impl IntoIterator<Item = NaiveDate>) -> Self {
+        self.holidays.extend(dates);
+        self
+    }
+
+    /// Return `true` if `date` is neither a weekend day nor a holiday
+    pub fn is_business_day(&self, date: &Date) -> bool {
+        self.is_business(date.date())
+    }
+
+    fn is_business(&self, date: NaiveDate) -> bool {
+        !self.weekend.contains(&date.weekday()) && !self.holidays.contains(&date)
+    }
+
+    /// Move `date` to a business day following `convention`, keeping its format and locale
+    ///
+    /// # Errors
+    /// Return an Err(_) if there is no business day in range
+    pub fn adjust(
+        &self,
+        date: &Date,
+        convention: BusinessDayConvention,
+    ) -> Result<Date, SpanError> {
+        let naive = date.date();
+        let adjusted = match convention {
+            BusinessDayConvention::Unadjusted => Some(naive),
+            BusinessDayConvention::Following => self.roll(naive, true),
+            BusinessDayConvention::Preceding => self.roll(naive, false),
+            BusinessDayConvention::ModifiedFollowing => self
+                .roll(naive, true)
+                .filter(|rolled| rolled.month() == naive.month())
+                .or_else(|| self.roll(naive, false)),
+            BusinessDayConvention::ModifiedPreceding => self
+                .roll(naive, false)
+                .filter(|rolled| rolled.month() == naive.month())
+                .or_else(|| self.roll(naive, true)),
+        };
+        with_date(date, adjusted, 0)
+    }
+
+    /// Add `days` business days to `date`, or remove them when negative, keeping its format and
+    /// locale
+    ///
+    /// Counting starts from `date` even if it is not a business day, so adding one business
+    /// day to a Saturday gives the Monday.
+    ///
+    /// # Errors
+    /// Return an Err(_) if there is no business day in range
+    pub fn add_business_days(&self, date: &Date, days: i32) -> Result<Date, SpanError> {
+        let mut naive = Some(date.date());
+        for _ in 0..days.unsigned_abs() {
+            naive = naive
+                .and_then(|naive| step(naive, days > 0))
+                .and_then(|naive| self.roll(naive, days > 0));
+        }
+        with_date(date, naive, days.into())
+    }
+
+    /// First business day from `date` included, forward or backward
+    fn roll(&self, mut date: NaiveDate, forward: bool) -> Option<NaiveDate> {
+        // Each week without a business day has a holiday, unless every day is a weekend day
+        for _ in 0..(self.holidays.len() + 1) * 7 {
+            if self.is_business(date) {
+                return Some(date);
+            }
+            date = step(date, forward)?;
+        }
+        None
+    }
+}
+
+fn step(date: NaiveDate, forward: bool) -> Option<NaiveDate> {
+    match forward {
+        true => date.succ_opt(),
+        false => date.pred_opt(),
+    }
+}
+
+/// [Date] at `naive` with the format and locale of `date`, or an error for a move of `days`
+fn with_date(date: &Date, naive: Option<NaiveDate>, days: i64) -> Result<Date, SpanError> {
+    let Some(naive) = naive else {
+        return Err(SpanError::InvalidUpdate {
+            input: date.to_string(),
+            unit: Unit::Day,
+            value: days,
+        })
+        .err_ctx(DateError);
+    };
+    let mut date = date.clone();
+    date.date = naive;
+    Ok(date)
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+
+    #[test]
+    fn business_adjust() -> Result<(), SpanError> {
+        let calendar = BusinessCalendar::new().holiday("2023-12-29".parse().unwrap());
+        let adjust = |date: Date, convention| {
+            calendar
+                .adjust(&date, convention)
+                .map(|date| date.to_string())
+        };
+        // Saturday
+        let saturday = Date::new(2023, 9, 30)?;
+        assert_eq!(
+            adjust(saturday.clone(), BusinessDayConvention::Unadjusted)?,
+            "2023-09-30"
+        );
+        assert_eq!(
+            adjust(saturday.clone(), BusinessDayConvention::Following)?,
+            "2023-10-02"
+        );
+        assert_eq!(
+            adjust(saturday.clone(), BusinessDayConvention::ModifiedFollowing)?,
+            "2023-09-29"
+        );
+        assert_eq!(
+            adjust(saturday, BusinessDayConvention::Preceding)?,
+            "2023-09-29"
+        );
+        // Sunday after a holiday on Friday
+        let sunday = Date::new(2023, 12, 31)?;
+        assert_eq!(
+            adjust(sunday.clone(), BusinessDayConvention::Preceding)?,
+            "2023-12-28"
+        );
+        assert_eq!(
+            adjust(
+                Date::new(2023, 4, 1)?,
+                BusinessDayConvention::ModifiedPreceding
+            )?,
+            "2023-04-03"
+        );
+        assert!(!calendar.is_business_day(&Date::new(2023, 12, 29)?));
+        assert!(calendar.is_business_day(&Date::new(2023, 12, 28)?));
+        Ok(())
+    }
+
+    #[test]
+    fn business_add_days() -> Result<(), SpanError> {
+        let calendar = BusinessCalendar::new()
+            .holidays(["2023-12-25".parse().unwrap(), "2023-12-26".parse().unwrap()]);
+        let friday = Date::new(2023, 12, 22)?;
+        assert_eq!(
+            calendar.add_business_days(&friday, 1)?.to_string(),
+            "2023-12-27"
+        );
+        assert_eq!(
+            calendar.add_business_days(&friday, 5)?.to_string(),
+            "2024-01-02"
+        );
+        assert_eq!(
+            calendar.add_business_days(&friday, -5)?.to_string(),
+            "2023-12-15"
+        );
+        assert_eq!(calendar.add_business_days(&friday, 0)?, friday);
+        let saturday = Date::new(2023, 12, 23)?;
+        assert_eq!(calendar.add_business_days(&saturday, -1)?, friday);
+        Ok(())
+    }
+
+    #[test]
+    fn business_no_business_day() -> Result<(), SpanError> {
+        let calendar = BusinessCalendar::new().weekend([
+            Weekday::Mon,
+            Weekday::Tue,
+            Weekday::Wed,
+            Weekday::Thu,
+            Weekday::Fri,
+            Weekday::Sat,
+            Weekday::Sun,
+        ]);
+        let err = calendar
+            .adjust(&Date::new(2023, 12, 22)?, BusinessDayConvention::Following)
+            .unwrap_err();
+        assert_eq!(err.code(), "invalid_update");
+        // Friday the only business day, a holiday three weeks in a row
+        let calendar = BusinessCalendar::new()
+            .weekend([
+                Weekday::Mon,
+                Weekday::Tue,
+                Weekday::Wed,
+                Weekday::Thu,
+                Weekday::Sat,
+                Weekday::Sun,
+            ])
+            .holidays([
+                "2023-12-01".parse().unwrap(),
+                "2023-12-08".parse().unwrap(),
+                "2023-12-15".parse().unwrap(),
+            ]);
+        assert_eq!(
+            calendar
+                .adjust(&Date::new(2023, 11, 25)?, BusinessDayConvention::Following)?
+                .to_string(),
+            "2023-12-22"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn business_calendar_deserialize() -> Result<(), serde_json::Error> {
+        let calendar: BusinessCalendar =
+            serde_json::from_str(r#"{"weekend": ["Fri", "Sat"], "holidays": ["2023-12-25"]}"#)?;
+        assert_eq!(
+            calendar,
+            BusinessCalendar::new()
+                .weekend([Weekday::Fri, Weekday::Sat])
+                .holiday("2023-12-25".parse().unwrap())
+        );
+        assert_eq!(
+            serde_json::from_str::<BusinessCalendar>("{}")?,
+            BusinessCalendar::new()
+        );
+        Ok(())
+    }
+}
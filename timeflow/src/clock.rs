@@ -0,0 +1,233 @@
+use std::{
+    fmt::Debug,
+    sync::{Arc, LazyLock, Mutex, RwLock},
+};
+
+use chrono::{Local, NaiveDateTime, TimeDelta, TimeZone, Utc};
+
+use crate::context;
+
+static BASE_CLOCK: LazyLock<RwLock<Arc<dyn Clock>>> =
+    LazyLock::new(|| RwLock::new(Arc::new(SystemClock)));
+
+/// Source of the current local date and time
+///
+/// Every [Span::now](crate::span::Span::now) and [Span::is_in_future](crate::span::Span::is_in_future)
+/// reads the clock of the innermost [Context](crate::context::Context), or the global clock
+/// set with [set_clock], which is [SystemClock] by default.
+///
+/// # Example
+/// ```rust,ignore
+/// let mut context = Context::new();
+/// context.clock(FixedClock::new(*DateTime::new(2024, 1, 1)?));
+/// let today = timeflow::with_context(&context, Date::now)?;
+/// assert_eq!(today.to_string(), "2024-01-01".to_string());
+/// ```
+pub trait Clock: Debug + Send + Sync {
+    /// Current local date and time
+    fn now(&self) -> NaiveDateTime;
+}
+
+/// [Clock] reading the system local time
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> NaiveDateTime {
+        Local::now().naive_local()
+    }
+}
+
+/// [Clock] always returning the same date and time
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FixedClock(NaiveDateTime);
+
+impl FixedClock {
+    /// Create a clock stopped at `now`
+    pub fn new(now: NaiveDateTime) -> Self {
+        Self(now)
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> NaiveDateTime {
+        self.0
+    }
+}
+
+/// [Clock] shifted from another clock by a fixed offset
+#[derive(Debug, Clone)]
+pub struct OffsetClock {
+    base: Arc<dyn Clock>,
+    offset: TimeDelta,
+}
+
+impl OffsetClock {
+    /// Create a clock shifted from [SystemClock] by `offset`
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// // Pretend to be one day ahead
+    /// let clock = OffsetClock::new(TimeDelta::days(1));
+    /// ```
+    pub fn new(offset: TimeDelta) -> Self {
+        Self::with_base(SystemClock, offset)
+    }
+
+    /// Create a clock shifted from `base` by `offset`
+    pub fn with_base(base: impl Clock + 'static, offset: TimeDelta) -> Self {
+        Self {
+            base: Arc::new(base),
+            offset,
+        }
+    }
+}
+
+impl Clock for OffsetClock {
+    fn now(&self) -> NaiveDateTime {
+        let now = self.base.now();
+        now.checked_add_signed(self.offset).unwrap_or(now)
+    }
+}
+
+/// [Clock] only moving when told to
+///
+/// Clones share the same time, so a test can keep a handle and advance the clock
+/// installed in a [Context](crate::context::Context) or with [set_clock].
+///
+/// # Example
+/// ```rust,ignore
+/// let clock = ManualClock::new(*DateTime::new(2024, 1, 1)?);
+/// set_clock(clock.clone());
+/// clock.advance(TimeDelta::hours(2));
+/// assert_eq!(DateTime::now()?.to_string(), "2024-01-01 02:00:00".to_string());
+/// ```
+#[derive(Debug, Clone)]
+pub struct ManualClock(Arc<Mutex<NaiveDateTime>>);
+
+impl ManualClock {
+    /// Create a clock stopped at `now`
+    pub fn new(now: NaiveDateTime) -> Self {
+        Self(Arc::new(Mutex::new(now)))
+    }
+
+    /// Move the clock to `now`
+    pub fn set(&self, now: NaiveDateTime) {
+        *self.0.lock().unwrap() = now;
+    }
+
+    /// Move the clock by `delta`, which can be negative
+    ///
+    /// The clock is left unchanged if the new time is out of range
+    pub fn advance(&self, delta: TimeDelta) {
+        let mut now = self.0.lock().unwrap();
+        if let Some(next) = now.checked_add_signed(delta) {
+            *now = next;
+        }
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> NaiveDateTime {
+        *self.0.lock().unwrap()
+    }
+}
+
+/// Set the global [Clock], used outside of any [Context](crate::context::Context) with a clock
+pub fn set_clock(clock: impl Clock + 'static) {
+    replace_clock(Arc::new(clock));
+}
+
+/// Set the global [Clock] and return the previous one
+pub(crate) fn replace_clock(clock: Arc<dyn Clock>) -> Arc<dyn Clock> {
+    std::mem::replace(&mut *BASE_CLOCK.write().unwrap(), clock)
+}
+
+/// Set the global [Clock] back to [SystemClock]
+pub fn reset_clock() {
+    set_clock(SystemClock);
+}
+
+/// Current local date and time from the [Clock] in use
+pub fn now() -> NaiveDateTime {
+    match context::current_clock() {
+        Some(clock) => clock.now(),
+        None => BASE_CLOCK.read().unwrap().now(),
+    }
+}
+
+/// Current UTC date and time from the [Clock] in use
+pub(crate) fn now_utc() -> chrono::DateTime<Utc> {
+    let now = now();
+    match Local.from_local_datetime(&now).earliest() {
+        Some(local) => local.with_timezone(&Utc),
+        None => now.and_utc(),
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use chrono::NaiveDate;
+
+    use super::*;
+    use crate::context::{Context, with_context};
+
+    /// [Context] with a [FixedClock] at `now`, written like `"2023-12-31T23:59:59"`
+    pub(crate) fn fixed_context(now: &str) -> Context {
+        let mut context = Context::new();
+        context.clock(FixedClock::new(now.parse().unwrap()));
+        context
+    }
+
+    fn new_year() -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2024, 1, 1)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn clock_fixed() {
+        let mut context = Context::new();
+        context.clock(FixedClock::new(new_year()));
+        assert_eq!(with_context(&context, now), new_year());
+    }
+
+    #[test]
+    fn clock_offset() {
+        let clock = OffsetClock::with_base(FixedClock::new(new_year()), TimeDelta::days(-1));
+        assert_eq!(clock.now().to_string(), "2023-12-31 00:00:00");
+    }
+
+    #[test]
+    fn clock_manual() {
+        let clock = ManualClock::new(new_year());
+        let mut context = Context::new();
+        context.clock(clock.clone());
+        clock.advance(TimeDelta::minutes(90));
+        assert_eq!(
+            with_context(&context, now).to_string(),
+            "2024-01-01 01:30:00"
+        );
+        clock.set(new_year());
+        assert_eq!(with_context(&context, now), new_year());
+        clock.advance(TimeDelta::MAX);
+        assert_eq!(clock.now(), new_year());
+    }
+
+    #[test]
+    fn clock_context_over_global() {
+        let mut outer = Context::new();
+        outer.clock(FixedClock::new(new_year()));
+        let inner = Context::new();
+        let now = with_context(&outer, || with_context(&inner, now));
+        assert_eq!(now, new_year());
+    }
+
+    #[test]
+    fn clock_system() {
+        let before = Local::now().naive_local();
+        let now = SystemClock.now();
+        assert!(now >= before);
+    }
+}
@@ -0,0 +1,269 @@
+use std::{cell::RefCell, marker::PhantomData, sync::Arc};
+
+use crate::{
+    arithmetic::UpdatePolicy,
+    clock::Clock,
+    format::{Format, FormatKind},
+    locale::Locale,
+};
+
+thread_local! {
+    static CONTEXTS: RefCell<Vec<Context>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Formats, locale, clock and update policy overriding the global defaults on the current thread
+///
+/// A context is entered with [with_context] or [Context::enter]. While it is active, the
+/// default format of the types, the default locale and the [Clock] are read from the context,
+/// and anything it does not set falls back to the global defaults set by
+/// [SpanBuilder::build](crate::builder::SpanBuilder::build) and [set_clock](crate::clock::set_clock).
+///
+/// Contexts can be nested, the innermost one that sets a value wins.
+///
+/// The context is thread-local: it is not seen by other threads, and in an async runtime it
+/// must not be held across an `.await`, since the task may resume on another thread.
+///
+/// # Example
+/// ```rust,ignore
+/// let mut tenant = Context::new();
+/// tenant.format(Format::date("%d/%m/%Y")?).locale(Locale::Fr);
+/// let date = timeflow::with_context(&tenant, || Date::new(2023, 10, 9))?;
+/// assert_eq!(date.to_string(), "09/10/2023".to_string());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Context {
+    date_format: Option<Format>,
+    time_format: Option<Format>,
+    datetime_format: Option<Format>,
+    locale: Option<Locale>,
+    clock: Option<Arc<dyn Clock>>,
+    policy: UpdatePolicy,
+}
+
+impl Context {
+    /// Create an empty context, falling back to the global defaults
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Setter for the default format of the [FormatKind] of `format`
+    pub fn format(&mut self, format: Format) -> &mut Self {
+        let kind = format.kind();
+        *self.slot(kind) = Some(format);
+        self
+    }
+
+    /// Setter for the default locale
+    pub fn locale(&mut self, locale: Locale) -> &mut Self {
+        self.locale = Some(locale);
+        self
+    }
+
+    /// Setter for the [Clock] used by `now()` and `is_in_future()`
+    pub fn clock(&mut self, clock: impl Clock + 'static) -> &mut Self {
+        self.clock = Some(Arc::new(clock));
+        self
+    }
+
+    /// Setter for the [UpdatePolicy] of `update`, merged with the enclosing contexts
+    pub fn policy(&mut self, policy: UpdatePolicy) -> &mut Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Getter for the default format of the given `kind`, if set
+    pub fn get_format(&self, kind: FormatKind) -> Option<&Format> {
+        match kind {
+            FormatKind::Date => self.date_format.as_ref(),
+            FormatKind::Time => self.time_format.as_ref(),
+            FormatKind::DateTime => self.datetime_format.as_ref(),
+        }
+    }
+
+    /// Getter for the default locale, if set
+    pub fn get_locale(&self) -> Option<Locale> {
+        self.locale
+    }
+
+    fn slot(&mut self, kind: FormatKind) -> &mut Option<Format> {
+        match kind {
+            FormatKind::Date => &mut self.date_format,
+            FormatKind::Time => &mut self.time_format,
+            FormatKind::DateTime => &mut self.datetime_format,
+        }
+    }
+
+    /// Enter the context on the current thread until the returned guard is dropped
+    ///
+    /// Dropping a guard leaves its context and every context entered after it, even when the
+    /// guards are dropped out of order.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let _guard = tenant.enter();
+    /// let date = Date::new(2023, 10, 9)?;
+    /// ```
+    pub fn enter(&self) -> ContextGuard {
+        let depth = CONTEXTS.with_borrow_mut(|contexts| {
+            contexts.push(self.clone());
+            contexts.len() - 1
+        });
+        ContextGuard {
+            depth,
+            _not_send: PhantomData,
+        }
+    }
+}
+
+/// Guard returned by [Context::enter], leaving the context when dropped
+#[must_use = "the context is left as soon as the guard is dropped"]
+#[derive(Debug)]
+pub struct ContextGuard {
+    /// Number of contexts entered before this one
+    depth: usize,
+    _not_send: PhantomData<*const ()>,
+}
+
+impl Drop for ContextGuard {
+    fn drop(&mut self) {
+        CONTEXTS.with_borrow_mut(|contexts| contexts.truncate(self.depth));
+    }
+}
+
+/// Run `f` with `context` entered on the current thread
+///
+/// The context is left when `f` returns, even if it panics.
+///
+/// # Example
+/// ```rust,ignore
+/// let mut context = Context::new();
+/// context.format(Format::time("%Hh%M")?);
+/// let time = timeflow::with_context(&context, || Time::new(12, 30, 0))?;
+/// assert_eq!(time.to_string(), "12h30".to_string());
+/// ```
+pub fn with_context<R>(context: &Context, f: impl FnOnce() -> R) -> R {
+    let _guard = context.enter();
+    f()
+}
+
+/// Format of the given `kind` set by the innermost active context
+pub(crate) fn current_format(kind: FormatKind) -> Option<Format> {
+    CONTEXTS.with_borrow(|contexts| {
+        contexts
+            .iter()
+            .rev()
+            .find_map(|context| context.get_format(kind).cloned())
+    })
+}
+
+/// Locale set by the innermost active context
+pub(crate) fn current_locale() -> Option<Locale> {
+    CONTEXTS.with_borrow(|contexts| contexts.iter().rev().find_map(|context| context.locale))
+}
+
+/// Clock set by the innermost active context
+pub(crate) fn current_clock() -> Option<Arc<dyn Clock>> {
+    CONTEXTS.with_borrow(|contexts| {
+        contexts
+            .iter()
+            .rev()
+            .find_map(|context| context.clock.clone())
+    })
+}
+
+/// Policy set by the active contexts, the innermost one first
+pub(crate) fn current_policy() -> UpdatePolicy {
+    CONTEXTS.with_borrow(|contexts| {
+        contexts
+            .iter()
+            .rev()
+            .fold(UpdatePolicy::new(), |policy, context| {
+                policy.or(context.policy)
+            })
+    })
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use crate::error::SpanError;
+
+    #[test]
+    fn context_format_by_kind() -> Result<(), SpanError> {
+        let mut context = Context::new();
+        context
+            .format(Format::date("%d/%m/%Y")?)
+            .format(Format::time("%Hh%M")?);
+        assert_eq!(
+            context.get_format(FormatKind::Date).map(Format::pattern),
+            Some("%d/%m/%Y")
+        );
+        assert_eq!(
+            context.get_format(FormatKind::Time).map(Format::pattern),
+            Some("%Hh%M")
+        );
+        assert_eq!(context.get_format(FormatKind::DateTime), None);
+        Ok(())
+    }
+
+    #[test]
+    fn context_nested() -> Result<(), SpanError> {
+        let mut outer = Context::new();
+        outer.format(Format::date("%d/%m/%Y")?).locale(Locale::Fr);
+        let mut inner = Context::new();
+        inner.locale(Locale::De);
+
+        assert_eq!(current_locale(), None);
+        with_context(&outer, || {
+            with_context(&inner, || {
+                assert_eq!(current_locale(), Some(Locale::De));
+                assert_eq!(
+                    current_format(FormatKind::Date).map(|format| format.to_string()),
+                    Some("%d/%m/%Y".to_string())
+                );
+            });
+            assert_eq!(current_locale(), Some(Locale::Fr));
+        });
+        assert_eq!(current_locale(), None);
+        assert_eq!(current_format(FormatKind::Date), None);
+        Ok(())
+    }
+
+    #[test]
+    fn context_guards_dropped_out_of_order() {
+        let mut first = Context::new();
+        first.locale(Locale::Fr);
+        let mut second = Context::new();
+        second.locale(Locale::De);
+
+        let first_guard = first.enter();
+        let second_guard = second.enter();
+        drop(first_guard);
+        assert_eq!(current_locale(), None);
+        drop(second_guard);
+        assert_eq!(current_locale(), None);
+        let _guard = second.enter();
+        assert_eq!(current_locale(), Some(Locale::De));
+    }
+
+    #[test]
+    fn context_left_on_panic() {
+        let mut context = Context::new();
+        context.locale(Locale::Es);
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            with_context(&context, || panic!("tenant"))
+        }));
+        assert!(result.is_err());
+        assert_eq!(current_locale(), None);
+    }
+
+    #[test]
+    fn context_thread_local() {
+        let mut context = Context::new();
+        context.locale(Locale::Fr);
+        let _guard = context.enter();
+        let other = std::thread::spawn(current_locale).join().unwrap();
+        assert_eq!(other, None);
+        assert_eq!(current_locale(), Some(Locale::Fr));
+    }
+}
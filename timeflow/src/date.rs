@@ -1,28 +1,93 @@
 use std::{
-    ops::{Deref, DerefMut},
+    ops::{Add, AddAssign, Deref, DerefMut, Sub, SubAssign},
     sync::{LazyLock, RwLock},
 };
 
-use chrono::{Datelike, Days, Duration, Local, Months, NaiveDate, NaiveDateTime, Utc};
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime, TimeDelta, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::{
     BaseFormat, GetInner,
+    arithmetic::{MonthEnd, Overflow, Rounding, UpdatePolicy, add_months, units_between},
+    clock,
     error::{DateError, ErrorContext, SpanError},
-    span::Span,
+    format::{DEFAULT_DATE_FORMAT, Format, FormatKind, expand},
+    locale::{BASE_LOCALE, Locale},
+    period::Period,
+    span::{Span, Unit, unit_for},
 };
 
-pub(crate) static BASE_DATE_FORMAT: BaseFormat<&'static str> =
-    LazyLock::new(|| RwLock::new("%Y-%m-%d"));
+pub(crate) static BASE_DATE_FORMAT: BaseFormat<Format> =
+    LazyLock::new(|| RwLock::new(Format::compile(DEFAULT_DATE_FORMAT, FormatKind::Date)));
 
-/// Unit to update [Date]
+/// Unit to update [Date], ordered from the largest to the smallest
+///
+/// Parsed and displayed like [Unit], e.g. `"d"`, `"day"` or `"days"` for [DateUnit::Day]
 #[derive(Debug, PartialEq, PartialOrd, Eq, Ord, Clone, Copy, Serialize, Deserialize)]
+#[serde(try_from = "String")]
 pub enum DateUnit {
     Year,
     Month,
     Day,
 }
 
+impl From<DateUnit> for Unit {
+    fn from(unit: DateUnit) -> Self {
+        match unit {
+            DateUnit::Year => Unit::Year,
+            DateUnit::Month => Unit::Month,
+            DateUnit::Day => Unit::Day,
+        }
+    }
+}
+
+impl DateUnit {
+    /// Every unit of a [Date], from the largest to the smallest
+    pub const ALL: [DateUnit; 3] = [DateUnit::Year, DateUnit::Month, DateUnit::Day];
+
+    /// Iterate over every unit of a [Date], from the largest to the smallest
+    pub fn iter() -> impl DoubleEndedIterator<Item = DateUnit> {
+        Self::ALL.into_iter()
+    }
+
+    /// Number of seconds in the unit, [None] for years and months whose length varies
+    pub fn as_seconds(&self) -> Option<i64> {
+        Unit::from(*self).as_seconds()
+    }
+}
+
+impl std::fmt::Display for DateUnit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Unit::from(*self).fmt(f)
+    }
+}
+
+impl TryFrom<Unit> for DateUnit {
+    type Error = SpanError;
+    fn try_from(unit: Unit) -> Result<Self, Self::Error> {
+        unit_for(unit, FormatKind::Date, |unit| match unit {
+            Unit::Year => Some(DateUnit::Year),
+            Unit::Month => Some(DateUnit::Month),
+            Unit::Day => Some(DateUnit::Day),
+            _ => None,
+        })
+    }
+}
+
+impl std::str::FromStr for DateUnit {
+    type Err = SpanError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<Unit>()?.try_into()
+    }
+}
+
+impl TryFrom<String> for DateUnit {
+    type Error = SpanError;
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
 /// Structure to handle date management
 ///
 /// Use [BASE_DATE_FORMAT](static@BASE_DATE_FORMAT) as default format for date
@@ -34,25 +99,35 @@ pub struct Date {
     pub(crate) date: NaiveDate,
     #[serde(skip)]
     #[serde(default = "base_date_format")]
-    pub(crate) format: String,
+    pub(crate) format: Format,
+    #[serde(skip)]
+    #[serde(default = "crate::locale::base_locale")]
+    pub(crate) locale: Locale,
 }
 
-fn base_date_format() -> String {
-    BASE_DATE_FORMAT.get().to_string()
+fn base_date_format() -> Format {
+    BASE_DATE_FORMAT.get()
 }
 
 impl Default for Date {
     fn default() -> Self {
         Self {
             date: NaiveDate::default(),
-            format: BASE_DATE_FORMAT.get().to_string(),
+            format: BASE_DATE_FORMAT.get(),
+            locale: BASE_LOCALE.get(),
         }
     }
 }
 
 impl std::fmt::Display for Date {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "{}", self.date.format(&self.format))
+        match self.format.items(self.locale) {
+            Some(items) => write!(f, "{}", self.date.format_with_items(items.iter())),
+            None => {
+                let format = expand(self.format.pattern(), self.locale, Some(self.date), None);
+                write!(f, "{}", self.date.format(&format))
+            }
+        }
     }
 }
 
@@ -75,9 +150,7 @@ impl Date {
     pub fn date(&self) -> NaiveDate {
         self.date
     }
-}
 
-impl Span<DateUnit, i32> for Date {
     /// Create a new variable [Date] from year, month and day
     ///
     /// Use [BASE_DATE_FORMAT](static@BASE_DATE_FORMAT) as default format for date
@@ -90,28 +163,317 @@ impl Span<DateUnit, i32> for Date {
     ///
     /// # Errors
     ///
-    /// Return an Err(_) if `time` is not formated with `format`
-    fn new(year: i32, month: u32, day: u32) -> Result<Self, SpanError> {
+    /// Return an Err(_) if the date does not exist
+    pub fn new(year: i32, month: u32, day: u32) -> Result<Self, SpanError> {
         let Some(date) = NaiveDate::from_ymd_opt(year, month, day) else {
             return Err(SpanError::InvalidDate(year, month, day)).err_ctx(DateError);
         };
         Ok(Self {
             date,
-            format: BASE_DATE_FORMAT.get().to_string(),
+            format: BASE_DATE_FORMAT.get(),
+            locale: BASE_LOCALE.get(),
+        })
+    }
+
+    /// Add the whole days of a [TimeDelta] to the [Date], following the [UpdatePolicy] in use
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let date = Date::new(2023, 10, 9)?.add_delta(TimeDelta::hours(49))?;
+    /// assert_eq!(date.to_string(), "2023-10-11".to_string());
+    /// ```
+    pub fn add_delta(&self, delta: TimeDelta) -> Result<Self, SpanError> {
+        let days = delta.num_days().clamp(i32::MIN.into(), i32::MAX.into());
+        self.update(DateUnit::Day, days as i32)
+    }
+
+    /// Add a [Period] to the [Date], years and months first, following the [UpdatePolicy] in use
+    ///
+    /// Hours, minutes and seconds only count as whole days, like [Date::add_delta]
+    pub fn add_period(&self, period: Period) -> Result<Self, SpanError> {
+        let months = period
+            .total_months()
+            .clamp(i32::MIN.into(), i32::MAX.into());
+        self.update(DateUnit::Month, months as i32)?
+            .add_delta(period.time_delta())
+    }
+
+    /// Completed years, months and days from the [Date] to `on`, following the [MonthEnd]
+    /// policy in use for anniversaries missing from a month, such as February 29th
+    ///
+    /// The age is negative when `on` is before the [Date], counted from `on`.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let birth = Date::new(2000, 12, 31)?;
+    /// assert_eq!(birth.age_on(&Date::new(2001, 1, 1)?)?, Period::new().days(1));
+    /// assert_eq!(birth.age_on(&Date::new(2023, 12, 31)?)?, Period::new().years(23));
+    /// ```
+    ///
+    /// # Errors
+    /// Return an Err(_) if an anniversary is missing from a month with [MonthEnd::Error]
+    pub fn age_on(&self, on: &Date) -> Result<Period, SpanError> {
+        self.age_on_with(on, month_end())
+    }
+
+    /// Completed years, months and days from the [Date] to `on`, like [Date::age_on]
+    ///
+    /// With [MonthEnd::Clamp], someone born on February 29th has a year more on February 28th
+    /// of a common year, and with [MonthEnd::Overflow] on March 1st.
+    pub fn age_on_with(&self, on: &Date, month_end: MonthEnd) -> Result<Period, SpanError> {
+        if on.date < self.date {
+            return on.age_on_with(self, month_end).map(|age| -age);
+        }
+        let mut months = (on.year() as i64 * 12 + on.month0() as i64)
+            - (self.year() as i64 * 12 + self.month0() as i64);
+        let anniversary = loop {
+            match add_months(self.date, months, month_end).err_ctx(DateError)? {
+                Some(anniversary) if anniversary <= on.date => break anniversary,
+                _ => months -= 1,
+            }
+        };
+        Ok(Period::new()
+            .years((months / 12) as i32)
+            .months((months % 12) as i32)
+            .days((on.date - anniversary).num_days() as i32))
+    }
+
+    /// First yearly anniversary of the [Date] strictly after `after`, following the [MonthEnd]
+    /// policy in use for February 29th
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let birth = Date::new(2000, 2, 29)?;
+    /// let next = birth.next_anniversary(&Date::new(2023, 1, 1)?)?;
+    /// assert_eq!(next.to_string(), "2023-02-28".to_string());
+    /// ```
+    ///
+    /// # Errors
+    /// Return an Err(_) if the anniversary is out of range or missing with [MonthEnd::Error]
+    pub fn next_anniversary(&self, after: &Date) -> Result<Self, SpanError> {
+        self.next_anniversary_with(after, month_end())
+    }
+
+    /// First yearly anniversary of the [Date] strictly after `after`, like
+    /// [Date::next_anniversary]
+    pub fn next_anniversary_with(
+        &self,
+        after: &Date,
+        month_end: MonthEnd,
+    ) -> Result<Self, SpanError> {
+        let mut years = (after.year() as i64 - self.year() as i64).max(1);
+        let anniversary = loop {
+            let anniversary = add_months(self.date, years * 12, month_end).err_ctx(DateError)?;
+            match anniversary {
+                Some(anniversary) if anniversary <= after.date => years += 1,
+                anniversary => break anniversary,
+            }
+        };
+        self.with_overflow(anniversary, None, Unit::Year, years)
+    }
+
+    /// Signed number of [DateUnit] from the [Date] to `to`, following `rounding`
+    ///
+    /// Unlike [Span::unit_elapsed], years and months are completed units counted on the
+    /// anniversaries of the [Date], clamped to the end of shorter months, and the result is
+    /// negative when `to` is before the [Date].
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let start = Date::new(2023, 1, 31)?;
+    /// let end = Date::new(2023, 2, 1)?;
+    /// assert_eq!(start.difference(&end, DateUnit::Month, Rounding::Trunc), 0);
+    /// assert_eq!(end.difference(&start, DateUnit::Day, Rounding::Trunc), -1);
+    /// assert_eq!(Date::new(2023, 1, 15)?.months_between(&Date::new(2023, 3, 1)?), 1.5);
+    /// ```
+    pub fn difference(&self, to: &Date, unit: DateUnit, rounding: Rounding) -> i64 {
+        let (whole, fraction) = units_between(
+            self.date.and_time(NaiveTime::MIN),
+            to.date.and_time(NaiveTime::MIN),
+            unit.into(),
+        );
+        rounding.apply(whole, fraction)
+    }
+
+    /// Signed number of [DateUnit] from the [Date] to `to`, with the fraction of the last one
+    pub fn fractional_difference(&self, to: &Date, unit: DateUnit) -> f64 {
+        let (whole, fraction) = units_between(
+            self.date.and_time(NaiveTime::MIN),
+            to.date.and_time(NaiveTime::MIN),
+            unit.into(),
+        );
+        whole as f64 + fraction
+    }
+
+    /// Signed number of months from the [Date] to `to`, with the fraction of the last one
+    pub fn months_between(&self, to: &Date) -> f64 {
+        self.fractional_difference(to, DateUnit::Month)
+    }
+
+    /// Wrap `date` in a [Date] with the format of `self`, or apply `overflow` if out of range
+    fn with_overflow(
+        &self,
+        date: Option<NaiveDate>,
+        overflow: Option<Overflow>,
+        unit: Unit,
+        value: i64,
+    ) -> Result<Self, SpanError> {
+        let date = match (date, overflow) {
+            (Some(date), _) => date,
+            (None, Some(Overflow::Saturating)) if value > 0 => NaiveDate::MAX,
+            (None, Some(Overflow::Saturating)) => NaiveDate::MIN,
+            (None, _) => {
+                return Err(SpanError::InvalidUpdate {
+                    input: self.to_string(),
+                    unit,
+                    value,
+                })
+                .err_ctx(DateError);
+            }
+        };
+        Ok(Self {
+            date,
+            format: self.format.clone(),
+            locale: self.locale,
         })
     }
+}
+
+/// [MonthEnd] policy in use, [MonthEnd::Clamp] by default
+fn month_end() -> MonthEnd {
+    UpdatePolicy::new()
+        .resolve()
+        .get_month_end()
+        .unwrap_or(MonthEnd::Clamp)
+}
+
+/// Add the whole days of a [TimeDelta], like [Date::add_delta]
+///
+/// # Panics
+/// Panic if the result is out of range and the [Overflow] policy is not saturating
+impl Add<TimeDelta> for Date {
+    type Output = Self;
+    fn add(self, rhs: TimeDelta) -> Self::Output {
+        self.add_delta(rhs).unwrap_or_else(|err| panic!("{err}"))
+    }
+}
+
+impl Sub<TimeDelta> for Date {
+    type Output = Self;
+    fn sub(self, rhs: TimeDelta) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl AddAssign<TimeDelta> for Date {
+    fn add_assign(&mut self, rhs: TimeDelta) {
+        *self = self.clone() + rhs;
+    }
+}
+
+impl SubAssign<TimeDelta> for Date {
+    fn sub_assign(&mut self, rhs: TimeDelta) {
+        *self = self.clone() - rhs;
+    }
+}
+
+/// Add a [Period], like [Date::add_period]
+///
+/// # Panics
+/// Panic if the result is out of range and the [Overflow] policy is not saturating,
+/// or if the day is missing from the month and the [MonthEnd] policy is [MonthEnd::Error]
+impl Add<Period> for Date {
+    type Output = Self;
+    fn add(self, rhs: Period) -> Self::Output {
+        self.add_period(rhs).unwrap_or_else(|err| panic!("{err}"))
+    }
+}
+
+impl Sub<Period> for Date {
+    type Output = Self;
+    fn sub(self, rhs: Period) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl AddAssign<Period> for Date {
+    fn add_assign(&mut self, rhs: Period) {
+        *self = self.clone() + rhs;
+    }
+}
+
+impl SubAssign<Period> for Date {
+    fn sub_assign(&mut self, rhs: Period) {
+        *self = self.clone() - rhs;
+    }
+}
+
+/// [TimeDelta] between two [Date], like [Span::elapsed]
+impl Sub for Date {
+    type Output = TimeDelta;
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.elapsed(&rhs)
+    }
+}
+
+impl Sub for &Date {
+    type Output = TimeDelta;
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.elapsed(rhs)
+    }
+}
+
+impl Span for Date {
+    type Unit = DateUnit;
+    type Components = (i32, u32, u32);
+
+    /// Create a [Date] from year, month and day, like [Date::new]
+    fn from_components((year, month, day): Self::Components) -> Result<Self, SpanError> {
+        Self::new(year, month, day)
+    }
+
+    /// Year, month and day of the [Date]
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// assert_eq!(Date::new(2024, 9, 27)?.components(), (2024, 9, 27));
+    /// ```
+    fn components(&self) -> Self::Components {
+        (self.date.year(), self.date.month(), self.date.day())
+    }
 
     /// Setter for the format
     ///
-    ///  See the [chrono::format::strftime] for the supported escape sequences of `format`.
+    ///  See the [chrono::format::strftime] for the supported escape sequences of `format`,
+    ///  and [crate::format] for the timeflow extensions.
     fn format(mut self, format: impl ToString) -> Self {
-        self.format = format.to_string();
+        self.format = Format::compile(format, FormatKind::Date);
         self
     }
 
+    /// Setter for a [Format] compiled beforehand, shared instead of being copied
+    fn with_format(mut self, format: Format) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Setter for the format, validated with [Format::date]
+    ///
+    /// # Errors
+    /// Return an Err(_) if `format` contains a specifier that is unknown or not allowed for a [Date]
+    fn try_format(self, format: impl ToString) -> Result<Self, SpanError> {
+        let format = Format::date(format).err_ctx(DateError)?;
+        Ok(self.with_format(format))
+    }
+
     /// Set the format to [BASE_DATE_FORMAT](static@BASE_DATE_FORMAT)
     fn default_format(mut self) -> Self {
-        self.format = BASE_DATE_FORMAT.get().to_string();
+        self.format = BASE_DATE_FORMAT.get();
+        self
+    }
+
+    fn locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
         self
     }
 
@@ -128,35 +490,26 @@ impl Span<DateUnit, i32> for Date {
     /// assert_eq!(date.to_string(), "2022-10-09".to_string());
     /// ```
     ///
+    /// With [MonthEnd::Overflow], January 31st plus one month is March 3rd instead of February 28th.
+    /// With [Overflow::Saturating], out of range dates stop at the first or last date supported.
+    ///
     /// # Errors
     /// Return an Err(_) if the operation is not possible or if [chrono] fails
-    fn update(&self, unit: DateUnit, value: i32) -> Result<Self, SpanError> {
+    fn update_with(
+        &self,
+        unit: DateUnit,
+        value: i32,
+        policy: UpdatePolicy,
+    ) -> Result<Self, SpanError> {
+        let policy = policy.resolve();
+        let month_end = policy.get_month_end().unwrap_or(MonthEnd::Clamp);
         let date = match unit {
-            DateUnit::Year if value > 0 => {
-                self.date.checked_add_months(Months::new(value as u32 * 12))
-            }
-            DateUnit::Year => self
-                .date
-                .checked_sub_months(Months::new(value.unsigned_abs() * 12)),
-            DateUnit::Month if value > 0 => self.date.checked_add_months(Months::new(value as u32)),
-            DateUnit::Month => self
-                .date
-                .checked_sub_months(Months::new(value.unsigned_abs())),
-            DateUnit::Day if value > 0 => self.date.checked_add_days(Days::new(value as u64)),
-            DateUnit::Day => self
-                .date
-                .checked_sub_days(Days::new(value.unsigned_abs() as u64)),
-        };
-        match date {
-            Some(date) => Ok(Self {
-                date,
-                format: self.format.clone(),
-            }),
-            None => Err(SpanError::InvalidUpdate(format!(
-                "Cannot Add/Remove {value} {unit:?} to/from {self}"
-            )))
-            .err_ctx(DateError),
+            DateUnit::Year => add_months(self.date, value as i64 * 12, month_end),
+            DateUnit::Month => add_months(self.date, value as i64, month_end),
+            DateUnit::Day => Ok(self.date.checked_add_signed(Duration::days(value as i64))),
         }
+        .err_ctx(DateError)?;
+        self.with_overflow(date, policy.get_overflow(), unit.into(), value.into())
     }
 
     /// Go to the next [DateUnit] from [Date]
@@ -192,9 +545,9 @@ impl Span<DateUnit, i32> for Date {
         }
     }
 
-    /// Return the current [Date] from the system
+    /// Return the current [Date] from the [Clock](crate::clock::Clock) in use
     fn now() -> Result<Self, SpanError> {
-        let now = Local::now();
+        let now = clock::now();
         Self::new(now.year(), now.month(), now.day())
     }
 
@@ -226,7 +579,11 @@ impl Span<DateUnit, i32> for Date {
         self.date.signed_duration_since(lhs.date)
     }
 
-    /// Number of [DateUnit] between two [Date]
+    /// Number of [DateUnit] between two [Date], negative when `rhs` is after the [Date]
+    ///
+    /// Years and months are the difference of the calendar fields, not completed units: from
+    /// 2023-01-31 to 2023-02-01 is one month. Use [Date::difference] or [Date::months_between]
+    /// to count completed units.
     ///
     /// # Example
     /// ```rust,ignore
@@ -266,32 +623,33 @@ impl Span<DateUnit, i32> for Date {
     /// ```
     fn clear_unit(&self, unit: DateUnit) -> Result<Self, SpanError> {
         let date = match unit {
-            DateUnit::Year => self.date.with_year(1970).ok_or(SpanError::ClearUnit(
-                "Error while setting year to 1970".to_string(),
-            )),
-            DateUnit::Month => self.date.with_month(1).ok_or(SpanError::ClearUnit(
-                "Error while setting month to 1".to_string(),
-            )),
-            DateUnit::Day => self.date.with_day(1).ok_or(SpanError::ClearUnit(
-                "Error while setting day to 1".to_string(),
-            )),
+            DateUnit::Year => self.date.with_year(1970),
+            DateUnit::Month => self.date.with_month(1),
+            DateUnit::Day => self.date.with_day(1),
         }
+        .ok_or_else(|| SpanError::ClearUnit {
+            input: self.to_string(),
+            unit: unit.into(),
+        })
         .err_ctx(DateError)?;
         Ok(Self {
             date,
             format: self.format.clone(),
+            locale: self.locale,
         })
     }
 
     fn get_format(&self) -> String {
-        self.format.clone()
+        self.format.pattern().to_string()
+    }
+
+    fn get_locale(&self) -> Locale {
+        self.locale
     }
 
-    fn deserialize_with_format<'de, D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: serde::Deserializer<'de>,
-        Self: TryFrom<(String, String)>,
-    {
+    fn deserialize_with_format<'de, D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
         #[derive(Deserialize)]
         struct Visitor {
             date: String,
@@ -299,8 +657,7 @@ impl Span<DateUnit, i32> for Date {
         }
 
         let visitor: Visitor = Deserialize::deserialize(deserializer)?;
-        Self::try_from((visitor.date, visitor.format))
-            .map_err(|_| serde::de::Error::custom("Invalid date"))
+        Self::try_from((visitor.date, visitor.format)).map_err(serde::de::Error::custom)
     }
 }
 
@@ -308,7 +665,8 @@ impl From<NaiveDateTime> for Date {
     fn from(value: NaiveDateTime) -> Self {
         Self {
             date: value.date(),
-            format: BASE_DATE_FORMAT.get().to_string(),
+            format: BASE_DATE_FORMAT.get(),
+            locale: BASE_LOCALE.get(),
         }
     }
 }
@@ -317,7 +675,8 @@ impl From<NaiveDate> for Date {
     fn from(value: NaiveDate) -> Self {
         Self {
             date: value,
-            format: BASE_DATE_FORMAT.get().to_string(),
+            format: BASE_DATE_FORMAT.get(),
+            locale: BASE_LOCALE.get(),
         }
     }
 }
@@ -325,22 +684,58 @@ impl From<NaiveDate> for Date {
 impl TryFrom<(String, String)> for Date {
     type Error = SpanError;
     fn try_from((date, format): (String, String)) -> Result<Self, Self::Error> {
-        let date = NaiveDate::parse_from_str(&date, &format)
-            .map_err(SpanError::ParseFromStr)
-            .err_ctx(DateError)?;
-        Ok(Self { date, format })
+        Self::try_from((date.as_str(), format.as_str()))
     }
 }
 
 impl TryFrom<(&str, &str)> for Date {
     type Error = SpanError;
     fn try_from((date, format): (&str, &str)) -> Result<Self, Self::Error> {
-        let date = NaiveDate::parse_from_str(date, format)
-            .map_err(SpanError::ParseFromStr)
+        Self::try_from((date, format, BASE_LOCALE.get()))
+    }
+}
+
+/// Parse a [Date] whose month names, weekday names and AM/PM markers are written in `locale`
+///
+/// # Example
+/// ```rust,ignore
+/// let date = Date::try_from(("vendredi 1 mars 2024", "%A %-d %B %Y", Locale::Fr))?;
+/// assert_eq!(date.to_string(), "vendredi 1 mars 2024".to_string());
+/// ```
+impl TryFrom<(&str, &str, Locale)> for Date {
+    type Error = SpanError;
+    fn try_from((date, format, locale): (&str, &str, Locale)) -> Result<Self, Self::Error> {
+        let format = Format::compile(format, FormatKind::Date);
+        Self::try_from((date, &format, locale))
+    }
+}
+
+/// Parse a [Date] with a [Format] compiled beforehand
+///
+/// The [Format] is shared with the parsed [Date], the pattern is neither copied nor parsed again.
+///
+/// # Example
+/// ```rust,ignore
+/// let format = Format::date("%d/%m/%Y")?;
+/// let values = lines.iter().map(|line| Date::try_from((line.as_str(), &format)));
+/// ```
+impl TryFrom<(&str, &Format)> for Date {
+    type Error = SpanError;
+    fn try_from((date, format): (&str, &Format)) -> Result<Self, Self::Error> {
+        Self::try_from((date, format, BASE_LOCALE.get()))
+    }
+}
+
+impl TryFrom<(&str, &Format, Locale)> for Date {
+    type Error = SpanError;
+    fn try_from((date, format, locale): (&str, &Format, Locale)) -> Result<Self, Self::Error> {
+        let date = format
+            .parse_with(date, locale, |parsed| parsed.to_naive_date())
             .err_ctx(DateError)?;
         Ok(Self {
             date,
-            format: format.to_string(),
+            format: format.clone(),
+            locale,
         })
     }
 }
@@ -348,22 +743,14 @@ impl TryFrom<(&str, &str)> for Date {
 impl TryFrom<String> for Date {
     type Error = SpanError;
     fn try_from(date: String) -> Result<Self, Self::Error> {
-        let date = NaiveDate::parse_from_str(&date, BASE_DATE_FORMAT.get())?;
-        Ok(Self {
-            date,
-            format: BASE_DATE_FORMAT.get().to_string(),
-        })
+        Self::try_from(date.as_str())
     }
 }
 
 impl TryFrom<&str> for Date {
     type Error = SpanError;
     fn try_from(date: &str) -> Result<Self, Self::Error> {
-        let date = NaiveDate::parse_from_str(date, BASE_DATE_FORMAT.get())?;
-        Ok(Self {
-            date,
-            format: BASE_DATE_FORMAT.get().to_string(),
-        })
+        Self::try_from((date, &BASE_DATE_FORMAT.get()))
     }
 }
 
@@ -378,7 +765,7 @@ impl TryFrom<&Date> for chrono::DateTime<Utc> {
     type Error = SpanError;
     fn try_from(value: &Date) -> Result<Self, Self::Error> {
         let date = value.date;
-        match Utc::now()
+        match clock::now_utc()
             .with_year(date.year())
             .and_then(|utc| utc.with_day(date.day()))
             .and_then(|utc| utc.with_month(date.month()))
@@ -394,6 +781,11 @@ pub mod test {
     use chrono::TimeDelta;
 
     use super::*;
+    use crate::{
+        clock::test::fixed_context,
+        context::{Context, with_context},
+        locale::FormatLength,
+    };
 
     #[test]
     fn date_add_overflow() -> Result<(), SpanError> {
@@ -401,14 +793,284 @@ pub mod test {
         let new_date = date.update(DateUnit::Day, i32::MAX);
         assert_eq!(
             new_date,
-            Err(SpanError::InvalidUpdate(
-                "Cannot Add/Remove 2147483647 Day to/from 2023-10-09".to_string()
-            ))
+            Err(SpanError::InvalidUpdate {
+                input: "2023-10-09".to_string(),
+                unit: Unit::Day,
+                value: i32::MAX.into(),
+            })
             .err_ctx(DateError)
         );
         Ok(())
     }
 
+    #[test]
+    fn date_update_saturating() -> Result<(), SpanError> {
+        let policy = UpdatePolicy::new().overflow(Overflow::Saturating);
+        let date = Date::new(2023, 10, 9)?;
+        assert_eq!(
+            *date.update_with(DateUnit::Day, i32::MAX, policy)?,
+            NaiveDate::MAX
+        );
+        assert_eq!(
+            *date.update_with(DateUnit::Day, i32::MIN, policy)?,
+            NaiveDate::MIN
+        );
+        assert_eq!(
+            *date.update_with(DateUnit::Year, i32::MIN, policy)?,
+            NaiveDate::MIN
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn date_update_month_end() -> Result<(), SpanError> {
+        let date = Date::new(2023, 1, 31)?;
+        assert_eq!(date.update(DateUnit::Month, 1)?.to_string(), "2023-02-28");
+        let policy = UpdatePolicy::new().month_end(MonthEnd::Overflow);
+        assert_eq!(
+            date.update_with(DateUnit::Month, 1, policy)?.to_string(),
+            "2023-03-03"
+        );
+        let policy = UpdatePolicy::new().month_end(MonthEnd::Error);
+        assert_eq!(
+            date.update_with(DateUnit::Month, 1, policy),
+            Err(SpanError::InvalidDate(2023, 2, 31)).err_ctx(DateError)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn date_age_on() -> Result<(), SpanError> {
+        let birth = Date::new(2000, 12, 31)?;
+        assert_eq!(
+            birth.age_on(&Date::new(2001, 1, 1)?)?,
+            Period::new().days(1)
+        );
+        assert_eq!(
+            birth.age_on(&Date::new(2001, 12, 30)?)?,
+            Period::new().months(11).days(30)
+        );
+        assert_eq!(
+            birth.age_on(&Date::new(2023, 12, 31)?)?,
+            Period::new().years(23)
+        );
+        assert_eq!(
+            birth.age_on(&Date::new(2024, 3, 1)?)?,
+            Period::new().years(23).months(2).days(1)
+        );
+        assert_eq!(
+            birth.age_on(&Date::new(2000, 12, 1)?)?,
+            -Period::new().days(30)
+        );
+        assert!(birth.age_on(&birth)?.is_zero());
+        Ok(())
+    }
+
+    #[test]
+    fn date_age_on_leap_day() -> Result<(), SpanError> {
+        let birth = Date::new(2000, 2, 29)?;
+        let feb_28 = Date::new(2023, 2, 28)?;
+        let mar_1 = Date::new(2023, 3, 1)?;
+        assert_eq!(birth.age_on(&feb_28)?, Period::new().years(23));
+        assert_eq!(
+            birth.age_on_with(&feb_28, MonthEnd::Overflow)?,
+            Period::new().years(22).months(11).days(30)
+        );
+        assert_eq!(
+            birth.age_on_with(&mar_1, MonthEnd::Overflow)?,
+            Period::new().years(23)
+        );
+        assert_eq!(
+            birth.age_on_with(&mar_1, MonthEnd::Error),
+            Err(SpanError::InvalidDate(2023, 2, 29)).err_ctx(DateError)
+        );
+        assert_eq!(
+            birth.age_on_with(&Date::new(2024, 2, 29)?, MonthEnd::Error)?,
+            Period::new().years(24)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn date_next_anniversary() -> Result<(), SpanError> {
+        let birth = Date::new(2000, 2, 29)?;
+        let next = |after: Date, month_end| {
+            birth
+                .next_anniversary_with(&after, month_end)
+                .map(|date| date.to_string())
+        };
+        assert_eq!(
+            birth.next_anniversary(&Date::new(2023, 1, 1)?)?.to_string(),
+            "2023-02-28"
+        );
+        assert_eq!(
+            next(Date::new(2023, 2, 28)?, MonthEnd::Clamp)?,
+            "2024-02-29"
+        );
+        assert_eq!(
+            next(Date::new(2023, 2, 28)?, MonthEnd::Overflow)?,
+            "2023-03-01"
+        );
+        assert_eq!(
+            next(Date::new(2023, 3, 1)?, MonthEnd::Overflow)?,
+            "2024-02-29"
+        );
+        assert_eq!(next(Date::new(1990, 1, 1)?, MonthEnd::Clamp)?, "2001-02-28");
+        assert!(next(Date::new(2023, 1, 1)?, MonthEnd::Error).is_err());
+        let mut context = Context::new();
+        context.policy(UpdatePolicy::new().month_end(MonthEnd::Overflow));
+        let next = with_context(&context, || birth.next_anniversary(&Date::new(2023, 1, 1)?))?;
+        assert_eq!(next.to_string(), "2023-03-01");
+        Ok(())
+    }
+
+    #[test]
+    fn date_difference() -> Result<(), SpanError> {
+        let start = Date::new(2023, 1, 31)?;
+        let end = Date::new(2023, 2, 1)?;
+        assert_eq!(start.difference(&end, DateUnit::Month, Rounding::Trunc), 0);
+        assert_eq!(start.difference(&end, DateUnit::Month, Rounding::Ceil), 1);
+        assert_eq!(end.difference(&start, DateUnit::Month, Rounding::Floor), -1);
+        assert_eq!(end.difference(&start, DateUnit::Day, Rounding::Trunc), -1);
+        // Clamped anniversaries
+        let feb_28 = Date::new(2023, 2, 28)?;
+        assert_eq!(
+            start.difference(&feb_28, DateUnit::Month, Rounding::Trunc),
+            1
+        );
+        assert_eq!(
+            feb_28.difference(&start, DateUnit::Month, Rounding::Trunc),
+            0
+        );
+        let birth = Date::new(2000, 12, 31)?;
+        let new_year = Date::new(2001, 1, 1)?;
+        assert_eq!(
+            birth.difference(&new_year, DateUnit::Year, Rounding::Trunc),
+            0
+        );
+        assert_eq!(
+            new_year.difference(&birth, DateUnit::Year, Rounding::Round),
+            0
+        );
+        assert_eq!(
+            birth.difference(&Date::new(2024, 7, 1)?, DateUnit::Year, Rounding::Round),
+            24
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn date_months_between() -> Result<(), SpanError> {
+        let start = Date::new(2023, 1, 15)?;
+        assert_eq!(start.months_between(&Date::new(2023, 3, 1)?), 1.5);
+        // Counted back from March 1st, on February 1st then January 1st
+        assert_eq!(
+            Date::new(2023, 3, 1)?.months_between(&start),
+            -1.0 - 17.0 / 31.0
+        );
+        assert_eq!(start.months_between(&start), 0.0);
+        assert_eq!(
+            start.fractional_difference(&Date::new(2024, 1, 15)?, DateUnit::Year),
+            1.0
+        );
+        assert_eq!(
+            start.fractional_difference(&Date::new(2023, 1, 16)?, DateUnit::Day),
+            1.0
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn date_update_policy_from_context() -> Result<(), SpanError> {
+        let mut context = Context::new();
+        context.policy(UpdatePolicy::new().month_end(MonthEnd::Overflow));
+        let date = Date::new(2024, 2, 29)?;
+        let next_year = with_context(&context, || date.update(DateUnit::Year, 1))?;
+        assert_eq!(next_year.to_string(), "2025-03-01");
+        let policy = UpdatePolicy::new().month_end(MonthEnd::Clamp);
+        let next_year = with_context(&context, || date.update_with(DateUnit::Year, 1, policy))?;
+        assert_eq!(next_year.to_string(), "2025-02-28");
+        Ok(())
+    }
+
+    #[test]
+    fn date_ops_time_delta() -> Result<(), SpanError> {
+        let date = Date::new(2023, 10, 9)?.format("%d/%m/%Y");
+        let mut next = date.clone() + TimeDelta::hours(49);
+        assert_eq!(next.to_string(), "11/10/2023");
+        next -= TimeDelta::days(11);
+        assert_eq!(next.to_string(), "30/09/2023");
+        assert_eq!(&date - &next, TimeDelta::days(9));
+        assert_eq!(next - date, TimeDelta::days(-9));
+        Ok(())
+    }
+
+    #[test]
+    fn date_ops_period() -> Result<(), SpanError> {
+        let date = Date::new(2024, 1, 31)?;
+        assert_eq!(
+            (date.clone() + Period::new().months(1)).to_string(),
+            "2024-02-29"
+        );
+        let mut date = date + Period::new().years(1).months(1).days(1);
+        assert_eq!(date.to_string(), "2025-03-01");
+        date -= Period::new().weeks(1);
+        assert_eq!(date.to_string(), "2025-02-22");
+        date += Period::new().hours(47);
+        assert_eq!(date.to_string(), "2025-02-23");
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic(expected = "InvalidUpdate")]
+    fn date_ops_overflow() {
+        let _ = Date::new(2023, 10, 9).unwrap() + TimeDelta::MAX;
+    }
+
+    #[test]
+    fn date_add_period_policy() -> Result<(), SpanError> {
+        let mut context = Context::new();
+        context.policy(UpdatePolicy::new().overflow(Overflow::Saturating));
+        let date = with_context(&context, || {
+            Date::new(2023, 10, 9)?.add_delta(TimeDelta::MAX)
+        })?;
+        assert_eq!(*date, NaiveDate::MAX);
+        Ok(())
+    }
+
+    #[test]
+    fn date_components() -> Result<(), SpanError> {
+        let date = Date::from_components((2024, 2, 29))?;
+        assert_eq!(date.components(), (2024, 2, 29));
+        assert_eq!(
+            Date::from_components((2023, 2, 29)),
+            Err(SpanError::InvalidDate(2023, 2, 29)).err_ctx(DateError)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn date_unit_parse_and_display() -> Result<(), SpanError> {
+        assert_eq!("days".parse::<DateUnit>()?, DateUnit::Day);
+        assert_eq!("y".parse::<DateUnit>()?, DateUnit::Year);
+        assert_eq!(DateUnit::Month.to_string(), "Month");
+        assert_eq!(
+            "sec".parse::<DateUnit>(),
+            Err(SpanError::UnitNotAllowed {
+                unit: Unit::Second,
+                kind: FormatKind::Date,
+            })
+        );
+        assert_eq!(
+            DateUnit::iter().collect::<Vec<_>>(),
+            vec![DateUnit::Year, DateUnit::Month, DateUnit::Day]
+        );
+        assert_eq!(DateUnit::Day.as_seconds(), Some(86_400));
+        let units: Vec<DateUnit> = serde_json::from_str(r#"["Year", "mo", "d"]"#).unwrap();
+        assert_eq!(units, DateUnit::ALL.to_vec());
+        Ok(())
+    }
+
     #[test]
     fn date_add_one_year() -> Result<(), SpanError> {
         let date = Date::new(2023, 10, 9)?;
@@ -474,7 +1136,7 @@ pub mod test {
             panic!("Error while deserializing date");
         };
         assert_eq!(date.to_string(), "2023-10-09".to_string());
-        assert_eq!(date.format, BASE_DATE_FORMAT.get().to_string());
+        assert_eq!(date.format, BASE_DATE_FORMAT.get());
         Ok(())
     }
 
@@ -497,10 +1159,25 @@ pub mod test {
             panic!("Error while deserializing date");
         };
         assert_eq!(date.to_string(), "09/10/2023".to_string());
-        assert_eq!(date.format, "%d/%m/%Y".to_string());
+        assert_eq!(date.format.pattern(), "%d/%m/%Y");
         Ok(())
     }
 
+    #[test]
+    fn date_deserialize_format_error() {
+        #[derive(Debug, Deserialize)]
+        struct CustomFmt(
+            #[allow(dead_code)]
+            #[serde(deserialize_with = "Date::deserialize_with_format")]
+            Date,
+        );
+        let serialized = "{\"date\":\"09/13/2023\",\"format\":\"%d/%m/%Y\"}".to_string();
+        let err = serde_json::from_str::<CustomFmt>(&serialized).unwrap_err();
+        assert!(err.to_string().starts_with(
+            "Date ➤  ParseFromStr: input is out of range at byte 3 of \"09/13/2023\" with format \"%d/%m/%Y\""
+        ));
+    }
+
     #[test]
     fn date_serialize_in_struct() -> Result<(), SpanError> {
         #[derive(Serialize)]
@@ -531,7 +1208,7 @@ pub mod test {
             panic!("Error while deserializing date");
         };
         assert_eq!(test.begin_at.to_string(), "2023-10-09".to_string());
-        assert_eq!(test.begin_at.format, BASE_DATE_FORMAT.get().to_string());
+        assert_eq!(test.begin_at.format, BASE_DATE_FORMAT.get());
         Ok(())
     }
 
@@ -601,26 +1278,49 @@ pub mod test {
     }
 
     #[test]
-    fn is_in_future_yesterday() -> Result<(), SpanError> {
-        let mut date = Date::now()?;
-        date = date.update(DateUnit::Day, -1)?;
-        assert!(!date.is_in_future()?);
+    fn now_from_clock() -> Result<(), SpanError> {
+        let date = with_context(&fixed_context("2023-12-31T23:59:59"), Date::now)?;
+        assert_eq!(date.to_string(), "2023-12-31".to_string());
         Ok(())
     }
 
     #[test]
-    fn is_in_future_tomorrow() -> Result<(), SpanError> {
-        let mut date = Date::now()?;
-        date = date.update(DateUnit::Day, 1)?;
-        assert!(date.is_in_future()?);
+    fn utc_from_clock() -> Result<(), SpanError> {
+        let date = Date::new(2024, 2, 29)?;
+        let utc = with_context(&fixed_context("2023-12-31T23:59:59"), || {
+            chrono::DateTime::<Utc>::try_from(&date)
+        })?;
+        assert_eq!(utc.date_naive(), *date);
         Ok(())
     }
 
+    #[test]
+    fn is_in_future_yesterday() -> Result<(), SpanError> {
+        with_context(&fixed_context("2023-12-31T23:59:59"), || {
+            let mut date = Date::now()?;
+            date = date.update(DateUnit::Day, -1)?;
+            assert!(!date.is_in_future()?);
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn is_in_future_tomorrow() -> Result<(), SpanError> {
+        with_context(&fixed_context("2023-12-31T23:59:59"), || {
+            let mut date = Date::now()?;
+            date = date.update(DateUnit::Day, 1)?;
+            assert!(date.is_in_future()?);
+            Ok(())
+        })
+    }
+
     #[test]
     fn is_in_future_now() -> Result<(), SpanError> {
-        let date = Date::now()?;
-        assert!(!date.is_in_future()?);
-        Ok(())
+        with_context(&fixed_context("2023-12-31T23:59:59"), || {
+            let date = Date::now()?;
+            assert!(!date.is_in_future()?);
+            Ok(())
+        })
     }
 
     #[test]
@@ -691,6 +1391,66 @@ pub mod test {
         assert_eq!(days_in_between, 58);
         Ok(())
     }
+
+    #[test]
+    fn date_display_locale() -> Result<(), SpanError> {
+        let date = Date::new(2024, 3, 1)?.format("%A %-d %B %Y");
+        assert_eq!(date.to_string(), "Friday 1 March 2024".to_string());
+        let date = date.locale(Locale::Fr);
+        assert_eq!(date.to_string(), "vendredi 1 mars 2024".to_string());
+        let date = date
+            .locale(Locale::De)
+            .format(Locale::De.date_format(FormatLength::Full));
+        assert_eq!(date.to_string(), "Freitag, 1. März 2024".to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn date_display_extensions() -> Result<(), SpanError> {
+        let date = Date::new(2024, 3, 21)?.format("%A, the %{ordinal_text} of %B (Q%{quarter})");
+        assert_eq!(
+            date.to_string(),
+            "Thursday, the twenty-first of March (Q1)".to_string()
+        );
+        let date = date.format("%{ordinal} %B").locale(Locale::Fr);
+        assert_eq!(date.to_string(), "21 mars".to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn date_try_format() -> Result<(), SpanError> {
+        let date = Date::new(2023, 10, 9)?.try_format("%d/%m/%Y")?;
+        assert_eq!(date.to_string(), "09/10/2023".to_string());
+        let error = Date::new(2023, 10, 9)?.try_format("%d/%m/%Y %H:%M");
+        assert_eq!(
+            error.map_err(|e| e.to_string()),
+            Err(
+                "Date ➤  InvalidFormat: `%H` at byte 9 of \"%d/%m/%Y %H:%M\" is not allowed in a Date format"
+                    .to_string()
+            )
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn date_with_shared_format() -> Result<(), SpanError> {
+        let format = Format::date("%d/%m/%Y")?;
+        let date = Date::try_from(("09/10/2023", &format))?;
+        let other = Date::new(2024, 1, 2)?.with_format(format.clone());
+        assert_eq!(date.to_string(), "09/10/2023".to_string());
+        assert_eq!(other.to_string(), "02/01/2024".to_string());
+        assert_eq!(date.format, other.format);
+        Ok(())
+    }
+
+    #[test]
+    fn date_parse_locale() -> Result<(), SpanError> {
+        let date = Date::try_from(("12 dic 2024", "%d %b %Y", Locale::Es))?;
+        assert_eq!(date.date(), NaiveDate::from_ymd_opt(2024, 12, 12).unwrap());
+        assert_eq!(date.get_locale(), Locale::Es);
+        assert_eq!(date.to_string(), "12 dic 2024".to_string());
+        Ok(())
+    }
 }
 
 #[cfg(all(feature = "date", feature = "datetime"))]
@@ -711,7 +1471,8 @@ mod datetime_into_date {
         fn from(value: crate::datetime::DateTime) -> Self {
             Self {
                 date: value.date(),
-                format: crate::date::BASE_DATE_FORMAT.get().to_string(),
+                format: crate::date::BASE_DATE_FORMAT.get(),
+                locale: value.locale,
             }
         }
     }
@@ -729,18 +1490,19 @@ mod datetime_into_date {
         }
 
         #[test]
-        #[ignore]
         fn datetime_into_date_wrong_format() -> Result<(), crate::error::SpanError> {
-            crate::builder::SpanBuilder::builder()
+            let context = crate::builder::SpanBuilder::builder()
                 .datetime_format("%Y-%m-%d %H:%M:%S")
                 .date_format("%d/%m/%Y")
-                .build();
-            let datetime = crate::datetime::DateTime::new(2023, 10, 9)?.with_time(12, 0, 0)?;
-            let date = crate::date::Date::from(datetime);
-            assert_eq!(date.to_string(), "09/10/2023".to_string());
-            let next = date.next(crate::date::DateUnit::Day)?;
-            assert_eq!(next.to_string(), "10/10/2023".to_string());
-            Ok(())
+                .context();
+            crate::context::with_context(&context, || {
+                let datetime = crate::datetime::DateTime::new(2023, 10, 9)?.with_time(12, 0, 0)?;
+                let date = crate::date::Date::from(datetime);
+                assert_eq!(date.to_string(), "09/10/2023".to_string());
+                let next = date.next(crate::date::DateUnit::Day)?;
+                assert_eq!(next.to_string(), "10/10/2023".to_string());
+                Ok(())
+            })
         }
     }
 }
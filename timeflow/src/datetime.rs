@@ -1,23 +1,35 @@
 use std::{
-    ops::{Deref, DerefMut},
+    ops::{Add, AddAssign, Deref, DerefMut, Sub, SubAssign},
     sync::{LazyLock, RwLock},
 };
 
-use chrono::{Datelike, Days, Duration, Local, Months, NaiveDateTime, Timelike, Utc};
+use chrono::{Datelike, Duration, NaiveDateTime, TimeDelta, Timelike, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::{
     BaseFormat, GetInner,
+    arithmetic::{MonthEnd, Overflow, Rounding, UpdatePolicy, add_months, units_between},
+    clock,
     error::{DateTimeError, ErrorContext, SpanError},
-    span::Span,
+    format::{DEFAULT_DATETIME_FORMAT, Format, FormatKind, expand},
+    locale::{BASE_LOCALE, Locale},
+    period::Period,
+    span::{Span, Unit},
     timestamp::{TimestampMicro, TimestampMilli, TimestampNano},
 };
 
-pub(crate) static BASE_DATETIME_FORMAT: BaseFormat<Option<&'static str>> =
-    LazyLock::new(|| RwLock::new(None));
+pub(crate) static BASE_DATETIME_FORMAT: BaseFormat<Format> = LazyLock::new(|| {
+    RwLock::new(Format::compile(
+        DEFAULT_DATETIME_FORMAT,
+        FormatKind::DateTime,
+    ))
+});
 
-/// Unit to update [DateTime]
+/// Unit to update [DateTime], ordered from the largest to the smallest
+///
+/// Parsed and displayed like [Unit], e.g. `"h"`, `"hour"` or `"hours"` for [DateTimeUnit::Hour]
 #[derive(Debug, PartialEq, PartialOrd, Eq, Ord, Clone, Copy, Serialize, Deserialize)]
+#[serde(try_from = "String")]
 pub enum DateTimeUnit {
     Year,
     Month,
@@ -27,6 +39,112 @@ pub enum DateTimeUnit {
     Second,
 }
 
+impl From<DateTimeUnit> for Unit {
+    fn from(unit: DateTimeUnit) -> Self {
+        match unit {
+            DateTimeUnit::Year => Unit::Year,
+            DateTimeUnit::Month => Unit::Month,
+            DateTimeUnit::Day => Unit::Day,
+            DateTimeUnit::Hour => Unit::Hour,
+            DateTimeUnit::Minute => Unit::Minute,
+            DateTimeUnit::Second => Unit::Second,
+        }
+    }
+}
+
+impl DateTimeUnit {
+    /// Every unit of a [DateTime], from the largest to the smallest
+    pub const ALL: [DateTimeUnit; 6] = [
+        DateTimeUnit::Year,
+        DateTimeUnit::Month,
+        DateTimeUnit::Day,
+        DateTimeUnit::Hour,
+        DateTimeUnit::Minute,
+        DateTimeUnit::Second,
+    ];
+
+    /// Iterate over every unit of a [DateTime], from the largest to the smallest
+    pub fn iter() -> impl DoubleEndedIterator<Item = DateTimeUnit> {
+        Self::ALL.into_iter()
+    }
+
+    /// Number of seconds in the unit, [None] for years and months whose length varies
+    pub fn as_seconds(&self) -> Option<i64> {
+        Unit::from(*self).as_seconds()
+    }
+}
+
+impl std::fmt::Display for DateTimeUnit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Unit::from(*self).fmt(f)
+    }
+}
+
+impl From<Unit> for DateTimeUnit {
+    fn from(unit: Unit) -> Self {
+        match unit {
+            Unit::Year => DateTimeUnit::Year,
+            Unit::Month => DateTimeUnit::Month,
+            Unit::Day => DateTimeUnit::Day,
+            Unit::Hour => DateTimeUnit::Hour,
+            Unit::Minute => DateTimeUnit::Minute,
+            Unit::Second => DateTimeUnit::Second,
+        }
+    }
+}
+
+impl std::str::FromStr for DateTimeUnit {
+    type Err = SpanError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(s.parse::<Unit>()?.into())
+    }
+}
+
+impl TryFrom<String> for DateTimeUnit {
+    type Error = SpanError;
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+#[cfg(feature = "date")]
+impl From<crate::date::DateUnit> for DateTimeUnit {
+    fn from(unit: crate::date::DateUnit) -> Self {
+        Unit::from(unit).into()
+    }
+}
+
+#[cfg(feature = "date")]
+impl TryFrom<DateTimeUnit> for crate::date::DateUnit {
+    type Error = SpanError;
+    /// Convert a [DateTimeUnit] to a [DateUnit](crate::date::DateUnit)
+    ///
+    /// # Errors
+    /// Return a [SpanError::UnitNotAllowed] for hours, minutes and seconds
+    fn try_from(unit: DateTimeUnit) -> Result<Self, Self::Error> {
+        Unit::from(unit).try_into()
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<crate::time::TimeUnit> for DateTimeUnit {
+    fn from(unit: crate::time::TimeUnit) -> Self {
+        Unit::from(unit).into()
+    }
+}
+
+#[cfg(feature = "time")]
+impl TryFrom<DateTimeUnit> for crate::time::TimeUnit {
+    type Error = SpanError;
+    /// Convert a [DateTimeUnit] to a [TimeUnit](crate::time::TimeUnit)
+    ///
+    /// # Errors
+    /// Return a [SpanError::UnitNotAllowed] for years, months and days
+    fn try_from(unit: DateTimeUnit) -> Result<Self, Self::Error> {
+        Unit::from(unit).try_into()
+    }
+}
+
 /// Structure to handle datetime management
 ///
 /// Use [BASE_DATETIME_FORMAT](static@BASE_DATETIME_FORMAT) as default format for datetime
@@ -38,25 +156,40 @@ pub struct DateTime {
     pub(crate) datetime: NaiveDateTime,
     #[serde(skip)]
     #[serde(default = "base_datetime_format")]
-    pub(crate) format: String,
+    pub(crate) format: Format,
+    #[serde(skip)]
+    #[serde(default = "crate::locale::base_locale")]
+    pub(crate) locale: Locale,
 }
 
-fn base_datetime_format() -> String {
-    BASE_DATETIME_FORMAT.get().to_string()
+fn base_datetime_format() -> Format {
+    BASE_DATETIME_FORMAT.get()
 }
 
 impl Default for DateTime {
     fn default() -> Self {
         Self {
             datetime: NaiveDateTime::default(),
-            format: BASE_DATETIME_FORMAT.get().to_string(),
+            format: BASE_DATETIME_FORMAT.get(),
+            locale: BASE_LOCALE.get(),
         }
     }
 }
 
 impl std::fmt::Display for DateTime {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "{}", self.datetime.format(&self.format))
+        match self.format.items(self.locale) {
+            Some(items) => write!(f, "{}", self.datetime.format_with_items(items.iter())),
+            None => {
+                let format = expand(
+                    self.format.pattern(),
+                    self.locale,
+                    Some(self.datetime.date()),
+                    Some(self.datetime.time()),
+                );
+                write!(f, "{}", self.datetime.format(&format))
+            }
+        }
     }
 }
 
@@ -80,6 +213,33 @@ impl DateTime {
         self.datetime
     }
 
+    /// Create a new variable [DateTime] from year, month and day
+    ///
+    /// Default time is set to 00:00:00
+    ///
+    /// Use [BASE_DATETIME_FORMAT](static@BASE_DATETIME_FORMAT) as default format
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let datetime = DateTime::new(2024, 9, 27)?;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Return an Err(_) if the date does not exist
+    pub fn new(year: i32, month: u32, day: u32) -> Result<Self, SpanError> {
+        let date = chrono::NaiveDate::from_ymd_opt(year, month, day)
+            .ok_or(SpanError::InvalidDate(year, month, day))
+            .err_ctx(DateTimeError)?;
+        let datetime = NaiveDateTime::new(date, chrono::NaiveTime::default());
+        Ok(Self {
+            datetime,
+            format: BASE_DATETIME_FORMAT.get(),
+            locale: BASE_LOCALE.get(),
+        })
+    }
+
     /// Return the timestamp from the [DateTime]
     pub fn timestamp(&self) -> i64 {
         self.datetime.and_utc().timestamp()
@@ -96,7 +256,8 @@ impl DateTime {
         let datetime = NaiveDateTime::new(self.datetime.date(), chrono::NaiveTime::default());
         Self {
             datetime,
-            format: BASE_DATETIME_FORMAT.get().to_string(),
+            format: BASE_DATETIME_FORMAT.get(),
+            locale: self.locale,
         }
     }
 
@@ -118,46 +279,261 @@ impl DateTime {
         self.datetime = self.datetime.date().and_time(time);
         Ok(self)
     }
-}
 
-impl Span<DateTimeUnit, i32> for DateTime {
-    /// Create a new variable [DateTime] from year, month and day
-    ///
-    /// Default time is set to 00:00:00
-    ///
-    /// Use [BASE_DATETIME_FORMAT](static@BASE_DATETIME_FORMAT) as default format
+    /// Add a [TimeDelta] to the [DateTime], following the [UpdatePolicy] in use
     ///
     /// # Example
+    /// ```rust,ignore
+    /// let datetime = DateTime::new(2023, 10, 9)?.add_delta(TimeDelta::minutes(90))?;
+    /// assert_eq!(datetime.to_string(), "2023-10-09 01:30:00".to_string());
+    /// ```
+    pub fn add_delta(&self, delta: TimeDelta) -> Result<Self, SpanError> {
+        let overflow = UpdatePolicy::new().resolve().get_overflow();
+        let datetime = self.datetime.checked_add_signed(delta);
+        self.with_overflow(datetime, overflow, Unit::Second, delta.num_seconds())
+    }
+
+    /// Add a [Period] to the [DateTime], years and months first, following the [UpdatePolicy]
+    /// in use
     ///
+    /// # Example
     /// ```rust,ignore
-    /// let datetime = DateTime::new(2024, 9, 27)?;
+    /// let period = Period::new().months(1).hours(12);
+    /// let datetime = DateTime::new(2023, 1, 31)?.add_period(period)?;
+    /// assert_eq!(datetime.to_string(), "2023-02-28 12:00:00".to_string());
     /// ```
+    pub fn add_period(&self, period: Period) -> Result<Self, SpanError> {
+        let months = period
+            .total_months()
+            .clamp(i32::MIN.into(), i32::MAX.into());
+        self.update(DateTimeUnit::Month, months as i32)?
+            .add_delta(period.time_delta())
+    }
+
+    /// Signed number of [DateTimeUnit] from the [DateTime] to `to`, following `rounding`
     ///
-    /// # Errors
+    /// Unlike [Span::unit_elapsed], years and months are completed units counted on the
+    /// anniversaries of the [DateTime], clamped to the end of shorter months, and the result is
+    /// negative when `to` is before the [DateTime].
     ///
-    /// Return an Err(_) if `datetime` is not formated with `format`
-    fn new(year: i32, month: u32, day: u32) -> Result<Self, SpanError> {
-        let date = chrono::NaiveDate::from_ymd_opt(year, month, day)
-            .ok_or(SpanError::InvalidDate(year, month, day))
-            .err_ctx(DateTimeError)?;
-        let datetime = NaiveDateTime::new(date, chrono::NaiveTime::default());
+    /// # Example
+    /// ```rust,ignore
+    /// let start = DateTime::new(2023, 1, 31)?.with_time(12, 0, 0)?;
+    /// let end = DateTime::new(2023, 2, 28)?;
+    /// assert_eq!(start.difference(&end, DateTimeUnit::Month, Rounding::Trunc), 0);
+    /// assert_eq!(start.difference(&end, DateTimeUnit::Month, Rounding::Round), 1);
+    /// assert_eq!(end.difference(&start, DateTimeUnit::Hour, Rounding::Trunc), -660);
+    /// ```
+    pub fn difference(&self, to: &DateTime, unit: DateTimeUnit, rounding: Rounding) -> i64 {
+        let (whole, fraction) = units_between(self.datetime, to.datetime, unit.into());
+        rounding.apply(whole, fraction)
+    }
+
+    /// Signed number of [DateTimeUnit] from the [DateTime] to `to`, with the fraction of the last one
+    pub fn fractional_difference(&self, to: &DateTime, unit: DateTimeUnit) -> f64 {
+        let (whole, fraction) = units_between(self.datetime, to.datetime, unit.into());
+        whole as f64 + fraction
+    }
+
+    /// Signed number of months from the [DateTime] to `to`, with the fraction of the last one
+    pub fn months_between(&self, to: &DateTime) -> f64 {
+        self.fractional_difference(to, DateTimeUnit::Month)
+    }
+
+    /// Start of the [DateTimeUnit] containing the [DateTime], clearing every smaller unit with
+    /// [Span::clear_unit] and the fraction of second
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let datetime = DateTime::build("2023-05-17 09:05:12")?;
+    /// assert_eq!(datetime.truncate(DateTimeUnit::Month)?.to_string(), "2023-05-01 00:00:00");
+    /// assert_eq!(datetime.truncate(DateTimeUnit::Hour)?.to_string(), "2023-05-17 09:00:00");
+    /// ```
+    pub fn truncate(&self, unit: DateTimeUnit) -> Result<Self, SpanError> {
+        let mut datetime =
+            self.with_naive(self.datetime.with_nanosecond(0).unwrap_or(self.datetime));
+        for smaller in DateTimeUnit::iter().filter(|smaller| *smaller > unit) {
+            datetime = datetime.clear_unit(smaller)?;
+        }
+        Ok(datetime)
+    }
+
+    /// [DateTime] at `datetime` with the format and locale of `self`
+    pub(crate) fn with_naive(&self, datetime: NaiveDateTime) -> Self {
+        Self {
+            datetime,
+            format: self.format.clone(),
+            locale: self.locale,
+        }
+    }
+
+    /// Wrap `datetime` in a [DateTime] with the format of `self`, or apply `overflow` if out
+    /// of range
+    fn with_overflow(
+        &self,
+        datetime: Option<NaiveDateTime>,
+        overflow: Option<Overflow>,
+        unit: Unit,
+        value: i64,
+    ) -> Result<Self, SpanError> {
+        let datetime = match (datetime, overflow) {
+            (Some(datetime), _) => datetime,
+            (None, Some(Overflow::Saturating)) if value > 0 => NaiveDateTime::MAX,
+            (None, Some(Overflow::Saturating)) => NaiveDateTime::MIN,
+            (None, _) => {
+                return Err(SpanError::InvalidUpdate {
+                    input: self.to_string(),
+                    unit,
+                    value,
+                })
+                .err_ctx(DateTimeError);
+            }
+        };
         Ok(Self {
             datetime,
-            format: BASE_DATETIME_FORMAT.get().to_string(),
+            format: self.format.clone(),
+            locale: self.locale,
         })
     }
+}
+
+/// Add a [TimeDelta], like [DateTime::add_delta]
+///
+/// # Panics
+/// Panic if the result is out of range and the [Overflow] policy is not saturating
+impl Add<TimeDelta> for DateTime {
+    type Output = Self;
+    fn add(self, rhs: TimeDelta) -> Self::Output {
+        self.add_delta(rhs).unwrap_or_else(|err| panic!("{err}"))
+    }
+}
+
+impl Sub<TimeDelta> for DateTime {
+    type Output = Self;
+    fn sub(self, rhs: TimeDelta) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl AddAssign<TimeDelta> for DateTime {
+    fn add_assign(&mut self, rhs: TimeDelta) {
+        *self = self.clone() + rhs;
+    }
+}
+
+impl SubAssign<TimeDelta> for DateTime {
+    fn sub_assign(&mut self, rhs: TimeDelta) {
+        *self = self.clone() - rhs;
+    }
+}
+
+/// Add a [Period], like [DateTime::add_period]
+///
+/// # Panics
+/// Panic if the result is out of range and the [Overflow] policy is not saturating,
+/// or if the day is missing from the month and the [MonthEnd] policy is [MonthEnd::Error]
+impl Add<Period> for DateTime {
+    type Output = Self;
+    fn add(self, rhs: Period) -> Self::Output {
+        self.add_period(rhs).unwrap_or_else(|err| panic!("{err}"))
+    }
+}
+
+impl Sub<Period> for DateTime {
+    type Output = Self;
+    fn sub(self, rhs: Period) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl AddAssign<Period> for DateTime {
+    fn add_assign(&mut self, rhs: Period) {
+        *self = self.clone() + rhs;
+    }
+}
+
+impl SubAssign<Period> for DateTime {
+    fn sub_assign(&mut self, rhs: Period) {
+        *self = self.clone() - rhs;
+    }
+}
+
+/// [TimeDelta] between two [DateTime], like [Span::elapsed]
+impl Sub for DateTime {
+    type Output = TimeDelta;
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.elapsed(&rhs)
+    }
+}
+
+impl Sub for &DateTime {
+    type Output = TimeDelta;
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.elapsed(rhs)
+    }
+}
+
+impl Span for DateTime {
+    type Unit = DateTimeUnit;
+    type Components = (i32, u32, u32, u32, u32, u32);
+
+    /// Create a [DateTime] from year, month, day, hour, minute and second
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let datetime = DateTime::from_components((2024, 9, 27, 12, 30, 0))?;
+    /// assert_eq!(datetime.to_string(), "2024-09-27 12:30:00".to_string());
+    /// ```
+    fn from_components(
+        (year, month, day, hour, minute, second): Self::Components,
+    ) -> Result<Self, SpanError> {
+        Self::new(year, month, day)?.with_time(hour, minute, second)
+    }
+
+    /// Year, month, day, hour, minute and second of the [DateTime]
+    fn components(&self) -> Self::Components {
+        (
+            self.datetime.year(),
+            self.datetime.month(),
+            self.datetime.day(),
+            self.datetime.hour(),
+            self.datetime.minute(),
+            self.datetime.second(),
+        )
+    }
 
     /// Setter for the format
     ///
-    ///  See the [chrono::format::strftime] for the supported escape sequences of `format`.
+    ///  See the [chrono::format::strftime] for the supported escape sequences of `format`,
+    ///  and [crate::format] for the timeflow extensions.
     fn format(mut self, format: impl ToString) -> Self {
-        self.format = format.to_string();
+        self.format = Format::compile(format, FormatKind::DateTime);
+        self
+    }
+
+    /// Setter for a [Format] compiled beforehand, shared instead of being copied
+    fn with_format(mut self, format: Format) -> Self {
+        self.format = format;
         self
     }
 
+    /// Setter for the format, validated with [Format::datetime]
+    ///
+    /// # Errors
+    /// Return an Err(_) if `format` contains a specifier that is unknown or not allowed for a [DateTime]
+    fn try_format(self, format: impl ToString) -> Result<Self, SpanError> {
+        let format = Format::datetime(format).err_ctx(DateTimeError)?;
+        Ok(self.with_format(format))
+    }
+
     /// Set the format to [BASE_DATETIME_FORMAT](static@BASE_DATETIME_FORMAT)
     fn default_format(mut self) -> Self {
-        self.format = BASE_DATETIME_FORMAT.get().to_string();
+        self.format = BASE_DATETIME_FORMAT.get();
+        self
+    }
+
+    fn locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
         self
     }
 
@@ -174,48 +550,34 @@ impl Span<DateTimeUnit, i32> for DateTime {
     /// assert_eq!(datetime.to_string(), "2024-10-09 00:05:00".to_string());
     /// ```
     ///
+    /// With [MonthEnd::Overflow], January 31st plus one month is March 3rd instead of February 28th.
+    /// With [Overflow::Saturating], out of range datetimes stop at the first or last datetime supported.
+    ///
     /// # Errors
     /// The function will return an Err(_) if the operation is not possible or [chrono] fails to update the datetime
-    fn update(&self, unit: DateTimeUnit, value: i32) -> Result<Self, SpanError> {
-        let datetime = match unit {
-            DateTimeUnit::Year if value > 0 => self
-                .datetime
-                .checked_add_months(Months::new(value as u32 * 12)),
-            DateTimeUnit::Year => self
-                .datetime
-                .checked_sub_months(Months::new(value.unsigned_abs() * 12)),
-            DateTimeUnit::Month if value > 0 => {
-                self.datetime.checked_add_months(Months::new(value as u32))
-            }
-            DateTimeUnit::Month => self
-                .datetime
-                .checked_sub_months(Months::new(value.unsigned_abs())),
-            DateTimeUnit::Day if value > 0 => {
-                self.datetime.checked_add_days(Days::new(value as u64))
-            }
-            DateTimeUnit::Day => self
-                .datetime
-                .checked_sub_days(Days::new(value.unsigned_abs() as u64)),
-            DateTimeUnit::Hour => {
-                Duration::try_hours(value as i64).map(|hours| self.datetime + hours)
-            }
-            DateTimeUnit::Minute => {
-                Duration::try_minutes(value as i64).map(|minutes| self.datetime + minutes)
-            }
-            DateTimeUnit::Second => {
-                Duration::try_seconds(value as i64).map(|seconds| self.datetime + seconds)
-            }
+    fn update_with(
+        &self,
+        unit: DateTimeUnit,
+        value: i32,
+        policy: UpdatePolicy,
+    ) -> Result<Self, SpanError> {
+        let policy = policy.resolve();
+        let month_end = policy.get_month_end().unwrap_or(MonthEnd::Clamp);
+        let months = |months: i64| {
+            add_months(self.datetime.date(), months, month_end)
+                .map(|date| date.map(|date| date.and_time(self.datetime.time())))
         };
-        match datetime {
-            Some(datetime) => Ok(Self {
-                datetime,
-                format: self.format.clone(),
-            }),
-            None => Err(SpanError::InvalidUpdate(format!(
-                "Cannot Add/Remove {value} {unit:?} to/from {self}"
-            )))
-            .err_ctx(DateTimeError),
+        let delta = |delta: Duration| Ok(self.datetime.checked_add_signed(delta));
+        let datetime = match unit {
+            DateTimeUnit::Year => months(value as i64 * 12),
+            DateTimeUnit::Month => months(value as i64),
+            DateTimeUnit::Day => delta(Duration::days(value as i64)),
+            DateTimeUnit::Hour => delta(Duration::hours(value as i64)),
+            DateTimeUnit::Minute => delta(Duration::minutes(value as i64)),
+            DateTimeUnit::Second => delta(Duration::seconds(value as i64)),
         }
+        .err_ctx(DateTimeError)?;
+        self.with_overflow(datetime, policy.get_overflow(), unit.into(), value.into())
     }
 
     /// Go to the next [DateTimeUnit] from [DateTime]
@@ -257,9 +619,9 @@ impl Span<DateTimeUnit, i32> for DateTime {
         }
     }
 
-    /// Return the current [DateTime] from the system
+    /// Return the current [DateTime] from the [Clock](crate::clock::Clock) in use
     fn now() -> Result<Self, SpanError> {
-        let datetime = Local::now();
+        let datetime = clock::now();
         Self::new(datetime.year(), datetime.month(), datetime.day())?.with_time(
             datetime.hour(),
             datetime.minute(),
@@ -280,13 +642,7 @@ impl Span<DateTimeUnit, i32> for DateTime {
     /// assert!(datetime.is_in_future()?);
     /// ```
     fn is_in_future(&self) -> Result<bool, SpanError> {
-        let datetime = Local::now();
-        let now = Self::new(datetime.year(), datetime.month(), datetime.day())?.with_time(
-            datetime.hour(),
-            datetime.minute(),
-            datetime.second(),
-        )?;
-        Ok(self.datetime > now.datetime)
+        Ok(self.datetime > Self::now()?.datetime)
     }
 
     /// Elapsed [Duration] between two [DateTime]
@@ -303,6 +659,11 @@ impl Span<DateTimeUnit, i32> for DateTime {
 
     /// Number of [DateTimeUnit] between two [DateTime]
     ///
+    /// Years and months are the difference of the calendar fields, not completed units: from
+    /// 2023-01-31 to 2023-02-01 is one month. The result is unsigned, the same whatever the
+    /// order of the two [DateTime]. Use [DateTime::difference] or [DateTime::months_between]
+    /// to count completed, signed units.
+    ///
     /// # Example
     /// ```rust,ignore
     /// let lhs = DateTime::new(2023, 10, 9)?.with_time(1, 1, 1)?;
@@ -369,41 +730,36 @@ impl Span<DateTimeUnit, i32> for DateTime {
     /// ```
     fn clear_unit(&self, unit: DateTimeUnit) -> Result<Self, SpanError> {
         let datetime = match unit {
-            DateTimeUnit::Year => self.datetime.with_year(1970).ok_or(SpanError::ClearUnit(
-                "Error while setting year to 1970".to_string(),
-            )),
-            DateTimeUnit::Month => self.datetime.with_month(1).ok_or(SpanError::ClearUnit(
-                "Error while setting month to 1".to_string(),
-            )),
-            DateTimeUnit::Day => self.datetime.with_day(1).ok_or(SpanError::ClearUnit(
-                "Error while setting day to 1".to_string(),
-            )),
-            DateTimeUnit::Hour => self.datetime.with_hour(0).ok_or(SpanError::ClearUnit(
-                "Error while setting hour to 0".to_string(),
-            )),
-            DateTimeUnit::Minute => self.datetime.with_minute(0).ok_or(SpanError::ClearUnit(
-                "Error while setting minute to 0".to_string(),
-            )),
-            DateTimeUnit::Second => self.datetime.with_second(0).ok_or(SpanError::ClearUnit(
-                "Error while setting second to 0".to_string(),
-            )),
+            DateTimeUnit::Year => self.datetime.with_year(1970),
+            DateTimeUnit::Month => self.datetime.with_month(1),
+            DateTimeUnit::Day => self.datetime.with_day(1),
+            DateTimeUnit::Hour => self.datetime.with_hour(0),
+            DateTimeUnit::Minute => self.datetime.with_minute(0),
+            DateTimeUnit::Second => self.datetime.with_second(0),
         }
+        .ok_or_else(|| SpanError::ClearUnit {
+            input: self.to_string(),
+            unit: unit.into(),
+        })
         .err_ctx(DateTimeError)?;
         Ok(Self {
             datetime,
             format: self.format.clone(),
+            locale: self.locale,
         })
     }
 
     fn get_format(&self) -> String {
-        self.format.clone()
+        self.format.pattern().to_string()
+    }
+
+    fn get_locale(&self) -> Locale {
+        self.locale
     }
 
-    fn deserialize_with_format<'de, D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: serde::Deserializer<'de>,
-        Self: TryFrom<(String, String)>,
-    {
+    fn deserialize_with_format<'de, D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
         #[derive(Deserialize)]
         struct Visitor {
             datetime: String,
@@ -411,8 +767,7 @@ impl Span<DateTimeUnit, i32> for DateTime {
         }
 
         let visitor: Visitor = Deserialize::deserialize(deserializer)?;
-        Self::try_from((visitor.datetime, visitor.format))
-            .map_err(|_| serde::de::Error::custom("Invalid datetime"))
+        Self::try_from((visitor.datetime, visitor.format)).map_err(serde::de::Error::custom)
     }
 }
 
@@ -420,7 +775,8 @@ impl From<NaiveDateTime> for DateTime {
     fn from(datetime: NaiveDateTime) -> Self {
         Self {
             datetime,
-            format: BASE_DATETIME_FORMAT.get().to_string(),
+            format: BASE_DATETIME_FORMAT.get(),
+            locale: BASE_LOCALE.get(),
         }
     }
 }
@@ -429,14 +785,12 @@ impl TryFrom<TimestampMilli> for crate::datetime::DateTime {
     type Error = crate::error::SpanError;
 
     fn try_from(timestamp: TimestampMilli) -> Result<Self, Self::Error> {
-        let datetime = chrono::DateTime::from_timestamp(*timestamp, 0).ok_or(
-            crate::error::SpanError::ParseFromTimestamp(
-                "Error while parsing timestamp".to_string(),
-            ),
-        )?;
+        let datetime = chrono::DateTime::from_timestamp(*timestamp, 0)
+            .ok_or(crate::error::SpanError::ParseFromTimestamp { value: *timestamp })?;
         Ok(Self {
             datetime: datetime.naive_utc(),
             format: crate::datetime::BASE_DATETIME_FORMAT.get(),
+            locale: BASE_LOCALE.get(),
         })
     }
 }
@@ -445,14 +799,12 @@ impl TryFrom<TimestampMicro> for crate::datetime::DateTime {
     type Error = crate::error::SpanError;
 
     fn try_from(timestamp: TimestampMicro) -> Result<Self, Self::Error> {
-        let datetime = chrono::DateTime::from_timestamp(*timestamp / 1_000, 0).ok_or(
-            crate::error::SpanError::ParseFromTimestamp(
-                "Error while parsing timestamp".to_string(),
-            ),
-        )?;
+        let datetime = chrono::DateTime::from_timestamp(*timestamp / 1_000, 0)
+            .ok_or(crate::error::SpanError::ParseFromTimestamp { value: *timestamp })?;
         Ok(Self {
             datetime: datetime.naive_utc(),
             format: crate::datetime::BASE_DATETIME_FORMAT.get(),
+            locale: BASE_LOCALE.get(),
         })
     }
 }
@@ -461,14 +813,12 @@ impl TryFrom<TimestampNano> for crate::datetime::DateTime {
     type Error = crate::error::SpanError;
 
     fn try_from(timestamp: TimestampNano) -> Result<Self, Self::Error> {
-        let datetime = chrono::DateTime::from_timestamp(*timestamp / 1_000_000, 0).ok_or(
-            crate::error::SpanError::ParseFromTimestamp(
-                "Error while parsing timestamp".to_string(),
-            ),
-        )?;
+        let datetime = chrono::DateTime::from_timestamp(*timestamp / 1_000_000, 0)
+            .ok_or(crate::error::SpanError::ParseFromTimestamp { value: *timestamp })?;
         Ok(Self {
             datetime: datetime.naive_utc(),
             format: crate::datetime::BASE_DATETIME_FORMAT.get(),
+            locale: BASE_LOCALE.get(),
         })
     }
 }
@@ -476,22 +826,60 @@ impl TryFrom<TimestampNano> for crate::datetime::DateTime {
 impl TryFrom<(String, String)> for DateTime {
     type Error = SpanError;
     fn try_from((datetime, format): (String, String)) -> Result<Self, Self::Error> {
-        let datetime = chrono::NaiveDateTime::parse_from_str(&datetime, &format)
-            .map_err(SpanError::ParseFromStr)
-            .err_ctx(DateTimeError)?;
-        Ok(Self { datetime, format })
+        Self::try_from((datetime.as_str(), format.as_str()))
     }
 }
 
 impl TryFrom<(&str, &str)> for DateTime {
     type Error = SpanError;
     fn try_from((datetime, format): (&str, &str)) -> Result<Self, Self::Error> {
-        let datetime = chrono::NaiveDateTime::parse_from_str(datetime, format)
-            .map_err(SpanError::ParseFromStr)
+        Self::try_from((datetime, format, BASE_LOCALE.get()))
+    }
+}
+
+/// Parse a [DateTime] whose month names, weekday names and AM/PM markers are written in `locale`
+///
+/// # Example
+/// ```rust,ignore
+/// let datetime = DateTime::try_from(("1. März 2024 um 08:30:00", "%-d. %B %Y um %H:%M:%S", Locale::De))?;
+/// assert_eq!(datetime.default_format().to_string(), "2024-03-01 08:30:00".to_string());
+/// ```
+impl TryFrom<(&str, &str, Locale)> for DateTime {
+    type Error = SpanError;
+    fn try_from((datetime, format, locale): (&str, &str, Locale)) -> Result<Self, Self::Error> {
+        let format = Format::compile(format, FormatKind::DateTime);
+        Self::try_from((datetime, &format, locale))
+    }
+}
+
+/// Parse a [DateTime] with a [Format] compiled beforehand
+///
+/// The [Format] is shared with the parsed [DateTime], the pattern is neither copied nor parsed again.
+///
+/// # Example
+/// ```rust,ignore
+/// let format = Format::datetime("%d/%m/%Y %H:%M:%S")?;
+/// let values = lines.iter().map(|line| DateTime::try_from((line.as_str(), &format)));
+/// ```
+impl TryFrom<(&str, &Format)> for DateTime {
+    type Error = SpanError;
+    fn try_from((datetime, format): (&str, &Format)) -> Result<Self, Self::Error> {
+        Self::try_from((datetime, format, BASE_LOCALE.get()))
+    }
+}
+
+impl TryFrom<(&str, &Format, Locale)> for DateTime {
+    type Error = SpanError;
+    fn try_from((datetime, format, locale): (&str, &Format, Locale)) -> Result<Self, Self::Error> {
+        let datetime = format
+            .parse_with(datetime, locale, |parsed| {
+                parsed.to_naive_datetime_with_offset(0)
+            })
             .err_ctx(DateTimeError)?;
         Ok(Self {
             datetime,
-            format: format.to_string(),
+            format: format.clone(),
+            locale,
         })
     }
 }
@@ -499,13 +887,7 @@ impl TryFrom<(&str, &str)> for DateTime {
 impl TryFrom<&str> for DateTime {
     type Error = SpanError;
     fn try_from(datetime: &str) -> Result<Self, Self::Error> {
-        let datetime = chrono::NaiveDateTime::parse_from_str(datetime, &BASE_DATETIME_FORMAT.get())
-            .map_err(SpanError::ParseFromStr)
-            .err_ctx(DateTimeError)?;
-        Ok(Self {
-            datetime,
-            format: BASE_DATETIME_FORMAT.get().to_string(),
-        })
+        Self::try_from((datetime, &BASE_DATETIME_FORMAT.get()))
     }
 }
 
@@ -519,7 +901,7 @@ impl TryFrom<&DateTime> for chrono::DateTime<Utc> {
     type Error = SpanError;
     fn try_from(value: &DateTime) -> Result<Self, Self::Error> {
         let date = value.datetime;
-        match Utc::now()
+        match clock::now_utc()
             .with_year(date.year())
             .and_then(|utc| utc.with_month(date.month()))
             .and_then(|utc| utc.with_day(date.day()))
@@ -538,6 +920,11 @@ pub mod test {
     use chrono::TimeDelta;
 
     use super::*;
+    use crate::{
+        clock::test::fixed_context,
+        context::{Context, with_context},
+        locale::FormatLength,
+    };
 
     #[test]
     fn datetime_add_overflow() -> Result<(), SpanError> {
@@ -545,14 +932,132 @@ pub mod test {
         let new_datetime = datetime.update(DateTimeUnit::Day, i32::MAX);
         assert_eq!(
             new_datetime,
-            Err(SpanError::InvalidUpdate(
-                "Cannot Add/Remove 2147483647 Day to/from 2023-10-09 00:00:00".to_string()
-            ))
+            Err(SpanError::InvalidUpdate {
+                input: "2023-10-09 00:00:00".to_string(),
+                unit: Unit::Day,
+                value: i32::MAX.into(),
+            })
             .err_ctx(DateTimeError)
         );
         Ok(())
     }
 
+    #[test]
+    fn datetime_update_saturating() -> Result<(), SpanError> {
+        let policy = UpdatePolicy::new().overflow(Overflow::Saturating);
+        let datetime = DateTime::new(2023, 10, 9)?;
+        assert_eq!(
+            *datetime.update_with(DateTimeUnit::Day, i32::MAX, policy)?,
+            NaiveDateTime::MAX
+        );
+        assert_eq!(
+            *datetime.update_with(DateTimeUnit::Day, i32::MIN, policy)?,
+            NaiveDateTime::MIN
+        );
+        // In range, so a plain subtraction
+        assert_eq!(
+            *datetime.update_with(DateTimeUnit::Second, i32::MIN, policy)?,
+            *datetime - TimeDelta::seconds(-(i32::MIN as i64))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn datetime_update_month_end() -> Result<(), SpanError> {
+        let datetime = DateTime::new(2023, 3, 31)?.with_time(12, 0, 0)?;
+        let policy = UpdatePolicy::new().month_end(MonthEnd::Overflow);
+        assert_eq!(
+            datetime
+                .update_with(DateTimeUnit::Month, -1, policy)?
+                .to_string(),
+            "2023-03-03 12:00:00"
+        );
+        assert_eq!(
+            datetime.update(DateTimeUnit::Month, -1)?.to_string(),
+            "2023-02-28 12:00:00"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn datetime_ops_time_delta() -> Result<(), SpanError> {
+        let datetime = DateTime::new(2023, 10, 9)?.format("%d/%m/%Y %Hh%M");
+        let mut next = datetime.clone() + TimeDelta::minutes(90);
+        assert_eq!(next.to_string(), "09/10/2023 01h30");
+        next -= TimeDelta::hours(2);
+        assert_eq!(next.to_string(), "08/10/2023 23h30");
+        assert_eq!(&datetime - &next, TimeDelta::minutes(30));
+        assert_eq!(next - datetime, TimeDelta::minutes(-30));
+        Ok(())
+    }
+
+    #[test]
+    fn datetime_ops_period() -> Result<(), SpanError> {
+        let datetime = DateTime::new(2023, 1, 31)?.with_time(12, 0, 0)?;
+        let mut datetime = datetime + Period::new().months(1).hours(12);
+        assert_eq!(datetime.to_string(), "2023-03-01 00:00:00");
+        datetime += Period::new().years(-1).seconds(-1);
+        assert_eq!(datetime.to_string(), "2022-02-28 23:59:59");
+        datetime -= Period::new().days(1);
+        assert_eq!(datetime.to_string(), "2022-02-27 23:59:59");
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid date: 2023-2-31")]
+    fn datetime_ops_month_end_error() {
+        let mut context = Context::new();
+        context.policy(UpdatePolicy::new().month_end(MonthEnd::Error));
+        let datetime = DateTime::new(2023, 1, 31).unwrap();
+        let _ = with_context(&context, || datetime + Period::new().months(1));
+    }
+
+    #[test]
+    fn datetime_components() -> Result<(), SpanError> {
+        let datetime = DateTime::from_components((2024, 9, 27, 12, 30, 5))?;
+        assert_eq!(datetime.to_string(), "2024-09-27 12:30:05");
+        assert_eq!(datetime.components(), (2024, 9, 27, 12, 30, 5));
+        assert_eq!(
+            DateTime::from_components((2024, 9, 27, 12, 60, 0)),
+            Err(SpanError::InvalidTime(12, 60, 0)).err_ctx(DateTimeError)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn datetime_unit_parse_and_display() -> Result<(), SpanError> {
+        assert_eq!("mins".parse::<DateTimeUnit>()?, DateTimeUnit::Minute);
+        assert_eq!("M".parse::<DateTimeUnit>()?, DateTimeUnit::Month);
+        assert_eq!(DateTimeUnit::Hour.to_string(), "Hour");
+        assert_eq!(DateTimeUnit::Hour.as_seconds(), Some(3_600));
+        assert_eq!(DateTimeUnit::Year.as_seconds(), None);
+        assert_eq!(DateTimeUnit::iter().count(), 6);
+        assert!(DateTimeUnit::ALL.is_sorted());
+        Ok(())
+    }
+
+    #[cfg(all(feature = "date", feature = "time"))]
+    #[test]
+    fn datetime_unit_conversions() {
+        use crate::{date::DateUnit, format::FormatKind, time::TimeUnit};
+
+        assert_eq!(DateTimeUnit::from(DateUnit::Day), DateTimeUnit::Day);
+        assert_eq!(DateTimeUnit::from(TimeUnit::Second), DateTimeUnit::Second);
+        assert_eq!(DateUnit::try_from(DateTimeUnit::Month), Ok(DateUnit::Month));
+        assert_eq!(TimeUnit::try_from(DateTimeUnit::Hour), Ok(TimeUnit::Hour));
+        assert_eq!(
+            DateUnit::try_from(DateTimeUnit::Hour),
+            Err(SpanError::UnitNotAllowed {
+                unit: Unit::Hour,
+                kind: FormatKind::Date,
+            })
+        );
+        assert_eq!(
+            TimeUnit::try_from(DateTimeUnit::Day).map_err(|err| err.to_string()),
+            Err("UnitNotAllowed: Day is not a Time unit".to_string())
+        );
+    }
+
     #[test]
     fn datetime_add_one_year() -> Result<(), SpanError> {
         let datetime = DateTime::new(2023, 10, 9)?;
@@ -669,7 +1174,7 @@ pub mod test {
             panic!("Error while deserializing datetime");
         };
         assert_eq!(datetime.to_string(), "2023-10-09 00:00:00".to_string());
-        assert_eq!(datetime.format, BASE_DATETIME_FORMAT.get().to_string());
+        assert_eq!(datetime.format, BASE_DATETIME_FORMAT.get());
         Ok(())
     }
 
@@ -732,7 +1237,7 @@ pub mod test {
             panic!("Error while deserializing datetime");
         };
         assert_eq!(test.begin_at.to_string(), "2023-10-09 00:00:00".to_string());
-        assert_eq!(test.begin_at.format, BASE_DATETIME_FORMAT.get().to_string());
+        assert_eq!(test.begin_at.format, BASE_DATETIME_FORMAT.get());
         Ok(())
     }
 
@@ -813,26 +1318,39 @@ pub mod test {
     }
 
     #[test]
-    fn is_in_future_yesterday() -> Result<(), SpanError> {
-        let mut datetime = DateTime::now()?;
-        datetime = datetime.update(DateTimeUnit::Day, -1)?;
-        assert!(!datetime.is_in_future()?);
+    fn now_from_clock() -> Result<(), SpanError> {
+        let datetime = with_context(&fixed_context("2023-12-31T23:59:59"), DateTime::now)?;
+        assert_eq!(datetime.to_string(), "2023-12-31 23:59:59".to_string());
         Ok(())
     }
 
+    #[test]
+    fn is_in_future_yesterday() -> Result<(), SpanError> {
+        with_context(&fixed_context("2023-12-31T23:59:59"), || {
+            let mut datetime = DateTime::now()?;
+            datetime = datetime.update(DateTimeUnit::Day, -1)?;
+            assert!(!datetime.is_in_future()?);
+            Ok(())
+        })
+    }
+
     #[test]
     fn is_in_future_tomorrow() -> Result<(), SpanError> {
-        let mut datetime = DateTime::now()?;
-        datetime = datetime.update(DateTimeUnit::Day, 1)?;
-        assert!(datetime.is_in_future()?);
-        Ok(())
+        with_context(&fixed_context("2023-12-31T23:59:59"), || {
+            let mut datetime = DateTime::now()?;
+            datetime = datetime.update(DateTimeUnit::Day, 1)?;
+            assert!(datetime.is_in_future()?);
+            Ok(())
+        })
     }
 
     #[test]
     fn is_in_future_now() -> Result<(), SpanError> {
-        let datetime = DateTime::now()?;
-        assert!(!datetime.is_in_future()?);
-        Ok(())
+        with_context(&fixed_context("2023-12-31T23:59:59"), || {
+            let datetime = DateTime::now()?;
+            assert!(!datetime.is_in_future()?);
+            Ok(())
+        })
     }
 
     #[test]
@@ -869,6 +1387,77 @@ pub mod test {
         Ok(())
     }
 
+    #[test]
+    fn datetime_difference() -> Result<(), SpanError> {
+        let start = DateTime::new(2023, 1, 31)?.with_time(12, 0, 0)?;
+        let end = DateTime::new(2023, 2, 28)?;
+        assert_eq!(
+            start.difference(&end, DateTimeUnit::Month, Rounding::Trunc),
+            0
+        );
+        assert_eq!(
+            start.difference(&end, DateTimeUnit::Month, Rounding::Round),
+            1
+        );
+        assert_eq!(
+            end.difference(&start, DateTimeUnit::Hour, Rounding::Trunc),
+            -660
+        );
+        assert_eq!(
+            end.difference(&start, DateTimeUnit::Day, Rounding::Floor),
+            -28
+        );
+        assert_eq!(
+            end.difference(&start, DateTimeUnit::Day, Rounding::Ceil),
+            -27
+        );
+        assert_eq!(
+            end.difference(&start, DateTimeUnit::Day, Rounding::Round),
+            -28
+        );
+        // unit_elapsed only compares the month fields, without direction
+        let feb_1 = DateTime::new(2023, 2, 1)?;
+        assert_eq!(start.unit_elapsed(&feb_1, DateTimeUnit::Month)?, 1);
+        assert_eq!(
+            start.difference(&feb_1, DateTimeUnit::Month, Rounding::Trunc),
+            0
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn datetime_months_between() -> Result<(), SpanError> {
+        let start = DateTime::new(2023, 2, 1)?;
+        let end = DateTime::new(2023, 2, 15)?;
+        assert_eq!(start.months_between(&end), 0.5);
+        assert_eq!(end.months_between(&start), -14.0 / 31.0);
+        assert_eq!(
+            start.fractional_difference(&start.clone().with_time(6, 0, 0)?, DateTimeUnit::Day),
+            0.25
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn datetime_truncate() -> Result<(), SpanError> {
+        let datetime = DateTime::new(2023, 3, 31)?.with_time(9, 5, 12)?;
+        let truncated = DateTimeUnit::iter()
+            .map(|unit| datetime.truncate(unit).map(|datetime| datetime.to_string()))
+            .collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(
+            truncated,
+            [
+                "2023-01-01 00:00:00",
+                "2023-03-01 00:00:00",
+                "2023-03-31 00:00:00",
+                "2023-03-31 09:00:00",
+                "2023-03-31 09:05:00",
+                "2023-03-31 09:05:12"
+            ]
+        );
+        Ok(())
+    }
+
     #[test]
     fn unit_elapsed() -> Result<(), SpanError> {
         let datetime = DateTime::new(2023, 10, 9)?.with_time(1, 1, 1)?;
@@ -976,6 +1565,38 @@ pub mod test {
         assert_eq!(datetime.to_string(), "2024-12-31 22:10:10");
         Ok(())
     }
+
+    #[test]
+    fn datetime_display_locale() -> Result<(), SpanError> {
+        let datetime = DateTime::new(2024, 8, 15)?
+            .with_time(9, 30, 0)?
+            .locale(Locale::Fr)
+            .format(Locale::Fr.datetime_format(FormatLength::Long));
+        assert_eq!(datetime.to_string(), "15 août 2024 à 09:30:00".to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn datetime_try_format() -> Result<(), SpanError> {
+        let datetime = DateTime::new(2023, 10, 9)?.try_format("%d/%m/%Y %Hh")?;
+        assert_eq!(datetime.to_string(), "09/10/2023 00h".to_string());
+        assert!(DateTime::new(2023, 10, 9)?.try_format("%Y %z").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn datetime_parse_locale() -> Result<(), SpanError> {
+        let datetime = DateTime::try_from((
+            "Sonntag, 1. Dezember 2024 um 08:30:00",
+            "%A, %-d. %B %Y um %H:%M:%S",
+            Locale::De,
+        ))?;
+        assert_eq!(
+            datetime.default_format().to_string(),
+            "2024-12-01 08:30:00".to_string()
+        );
+        Ok(())
+    }
 }
 
 #[cfg(all(feature = "date", feature = "datetime"))]
@@ -997,14 +1618,14 @@ mod date_into_datetime {
             let datetime = chrono::NaiveDateTime::new(value.date(), chrono::NaiveTime::default());
             Self {
                 datetime,
-                format: crate::datetime::BASE_DATETIME_FORMAT.get().to_string(),
+                format: crate::datetime::BASE_DATETIME_FORMAT.get(),
+                locale: value.locale,
             }
         }
     }
 
     #[cfg(test)]
     mod test {
-        use crate::span::Span;
 
         #[test]
         fn date_into_datetime() -> Result<(), crate::error::SpanError> {
@@ -1015,16 +1636,17 @@ mod date_into_datetime {
         }
 
         #[test]
-        #[ignore]
         fn date_into_datetime_wrong_format() -> Result<(), crate::error::SpanError> {
-            crate::builder::SpanBuilder::builder()
+            let context = crate::builder::SpanBuilder::builder()
                 .datetime_format("%Y-%m-%d %H:%M:%S")
                 .date_format("%d/%m/%Y")
-                .build();
-            let date = crate::date::Date::new(2023, 10, 9)?;
-            let datetime = crate::datetime::DateTime::from(date);
-            assert_eq!(datetime.to_string(), "2023-10-09 00:00:00".to_string());
-            Ok(())
+                .context();
+            crate::context::with_context(&context, || {
+                let date = crate::date::Date::new(2023, 10, 9)?;
+                let datetime = crate::datetime::DateTime::from(date);
+                assert_eq!(datetime.to_string(), "2023-10-09 00:00:00".to_string());
+                Ok(())
+            })
         }
     }
 }
@@ -1048,14 +1670,14 @@ mod time_into_datetime {
             let datetime = chrono::NaiveDateTime::new(chrono::NaiveDate::default(), value.time());
             Self {
                 datetime,
-                format: crate::datetime::BASE_DATETIME_FORMAT.get().to_string(),
+                format: crate::datetime::BASE_DATETIME_FORMAT.get(),
+                locale: value.locale,
             }
         }
     }
 
     #[cfg(test)]
     mod test {
-        use crate::span::Span;
 
         #[test]
         fn time_into_datetime() -> Result<(), crate::error::SpanError> {
@@ -1066,15 +1688,284 @@ mod time_into_datetime {
         }
 
         #[test]
-        #[ignore]
         fn time_into_datetime_wrong_format() -> Result<(), crate::error::SpanError> {
-            crate::builder::SpanBuilder::builder()
+            let context = crate::builder::SpanBuilder::builder()
                 .datetime_format("%Y-%m-%d %H_%M_%S")
                 .time_format("%H:%M:%S")
-                .build();
-            let time = crate::time::Time::new(13, 27, 57)?;
-            let datetime = crate::datetime::DateTime::from(time);
-            assert_eq!(datetime.to_string(), "1970-01-01 13_27_57".to_string());
+                .context();
+            crate::context::with_context(&context, || {
+                let time = crate::time::Time::new(13, 27, 57)?;
+                let datetime = crate::datetime::DateTime::from(time);
+                assert_eq!(datetime.to_string(), "1970-01-01 13_27_57".to_string());
+                Ok(())
+            })
+        }
+    }
+}
+
+#[cfg(all(feature = "date", feature = "time", feature = "datetime"))]
+mod combine_date_and_time {
+    use crate::{
+        GetInner,
+        date::{BASE_DATE_FORMAT, Date},
+        datetime::{BASE_DATETIME_FORMAT, DateTime},
+        format::{Format, FormatMode},
+        time::{BASE_TIME_FORMAT, Time},
+    };
+
+    impl Date {
+        /// Combine the [Date] with a [Time] into a [DateTime], joining their formats
+        ///
+        /// # Example
+        /// ```rust,ignore
+        /// let date = Date::new(2023, 10, 9)?.format("%d/%m/%Y");
+        /// let datetime = date.at(&Time::new(12, 30, 0)?.format("%Hh%M"));
+        /// assert_eq!(datetime.to_string(), "09/10/2023 12h30".to_string());
+        /// ```
+        pub fn at(&self, time: &Time) -> DateTime {
+            self.at_with(time, FormatMode::default())
+        }
+
+        /// Combine the [Date] with a [Time] into a [DateTime], with the format given by `mode`
+        ///
+        /// The locale of the [Date] is kept
+        pub fn at_with(&self, time: &Time, mode: FormatMode) -> DateTime {
+            let format = match mode {
+                FormatMode::Keep | FormatMode::Derive => Format::join(&self.format, &time.format),
+                FormatMode::Default => BASE_DATETIME_FORMAT.get(),
+            };
+            DateTime {
+                datetime: self.date.and_time(time.time),
+                format,
+                locale: self.locale,
+            }
+        }
+    }
+
+    impl DateTime {
+        /// Split the [DateTime] into a [Date] and a [Time], splitting its format
+        ///
+        /// Fall back to the default formats if the format cannot be split with [Format::split]
+        ///
+        /// # Example
+        /// ```rust,ignore
+        /// let datetime = DateTime::new(2023, 10, 9)?.format("%d/%m/%Y at %Hh%M");
+        /// let (date, time) = datetime.split();
+        /// assert_eq!(date.to_string(), "09/10/2023".to_string());
+        /// assert_eq!(time.to_string(), "00h00".to_string());
+        /// ```
+        pub fn split(&self) -> (Date, Time) {
+            self.split_with(FormatMode::default())
+        }
+
+        /// Split the [DateTime] into a [Date] and a [Time], with the formats given by `mode`
+        ///
+        /// The locale of the [DateTime] is kept
+        pub fn split_with(&self, mode: FormatMode) -> (Date, Time) {
+            let formats = match mode {
+                FormatMode::Keep | FormatMode::Derive => self.format.split(),
+                FormatMode::Default => None,
+            };
+            let (date_format, time_format) =
+                formats.unwrap_or_else(|| (BASE_DATE_FORMAT.get(), BASE_TIME_FORMAT.get()));
+            let date = Date {
+                date: self.datetime.date(),
+                format: date_format,
+                locale: self.locale,
+            };
+            let time = Time {
+                time: self.datetime.time(),
+                format: time_format,
+                locale: self.locale,
+            };
+            (date, time)
+        }
+    }
+
+    #[cfg(test)]
+    mod test {
+        use crate::{error::SpanError, format::FormatMode, prelude::*};
+
+        #[test]
+        fn date_at() -> Result<(), SpanError> {
+            let date = Date::new(2023, 10, 9)?
+                .format("%d/%m/%Y")
+                .locale(Locale::Fr);
+            let time = Time::new(12, 30, 0)?.format("%Hh%M");
+            let datetime = date.at(&time);
+            assert_eq!(datetime.to_string(), "09/10/2023 12h30");
+            assert_eq!(datetime.get_format(), "%d/%m/%Y %Hh%M");
+            assert_eq!(datetime.get_locale(), Locale::Fr);
+            let datetime = date.at_with(&time, FormatMode::Default);
+            assert_eq!(datetime.to_string(), "2023-10-09 12:30:00");
+            Ok(())
+        }
+
+        #[test]
+        fn datetime_split() -> Result<(), SpanError> {
+            let datetime = DateTime::new(2023, 10, 9)?
+                .with_time(8, 5, 0)?
+                .format("%A %d %B, %Hh%M");
+            let (date, time) = datetime.split();
+            assert_eq!(date.to_string(), "Monday 09 October");
+            assert_eq!(time.to_string(), "08h05");
+            let (date, time) = datetime.split_with(FormatMode::Default);
+            assert_eq!(date.to_string(), "2023-10-09");
+            assert_eq!(time.to_string(), "08:05:00");
+            Ok(())
+        }
+
+        #[test]
+        fn datetime_split_fallback() -> Result<(), SpanError> {
+            let datetime = DateTime::new(2023, 10, 9)?.format("%c");
+            let (date, time) = datetime.split();
+            assert_eq!(date.get_format(), "%Y-%m-%d");
+            assert_eq!(time.get_format(), "%H:%M:%S");
+            Ok(())
+        }
+
+        #[test]
+        fn split_then_at() -> Result<(), SpanError> {
+            let datetime = DateTime::new(2023, 10, 9)?
+                .with_time(8, 5, 0)?
+                .format("%d/%m/%Y %H:%M");
+            let (date, time) = datetime.split();
+            assert_eq!(date.at(&time), datetime);
+            Ok(())
+        }
+    }
+}
+
+#[cfg(all(feature = "date", feature = "datetime"))]
+mod with_date {
+    use crate::{
+        GetInner,
+        date::Date,
+        datetime::{BASE_DATETIME_FORMAT, DateTime},
+        format::{Format, FormatMode},
+    };
+
+    impl DateTime {
+        /// Replace the date of the [DateTime], with the default [FormatMode]
+        ///
+        /// # Example
+        /// ```rust,ignore
+        /// let datetime = DateTime::new(2023, 10, 9)?.with_time(12, 0, 0)?;
+        /// let datetime = datetime.with_date(&Date::new(2024, 2, 29)?);
+        /// assert_eq!(datetime.to_string(), "2024-02-29 12:00:00".to_string());
+        /// ```
+        pub fn with_date(&self, date: &Date) -> Self {
+            self.with_date_with(date, FormatMode::default())
+        }
+
+        /// Replace the date of the [DateTime], with the format given by `mode`
+        ///
+        /// [FormatMode::Derive] replaces the date part of the format with the format of `date`
+        pub fn with_date_with(&self, date: &Date, mode: FormatMode) -> Self {
+            let format = match mode {
+                FormatMode::Keep => self.format.clone(),
+                FormatMode::Derive => match self.format.split() {
+                    Some((_, time_format)) => Format::join(&date.format, &time_format),
+                    None => self.format.clone(),
+                },
+                FormatMode::Default => BASE_DATETIME_FORMAT.get(),
+            };
+            Self {
+                datetime: date.date().and_time(self.datetime.time()),
+                format,
+                locale: self.locale,
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod test {
+        use crate::{error::SpanError, format::FormatMode, prelude::*};
+
+        #[test]
+        fn datetime_with_date() -> Result<(), SpanError> {
+            let datetime = DateTime::new(2023, 10, 9)?
+                .with_time(12, 0, 0)?
+                .format("%Y-%m-%dT%H:%M");
+            let date = Date::new(2024, 2, 29)?.format("%d/%m/%Y");
+            assert_eq!(datetime.with_date(&date).to_string(), "29/02/2024 12:00");
+            assert_eq!(
+                datetime.with_date_with(&date, FormatMode::Keep).to_string(),
+                "2024-02-29T12:00"
+            );
+            assert_eq!(
+                datetime
+                    .with_date_with(&date, FormatMode::Default)
+                    .to_string(),
+                "2024-02-29 12:00:00"
+            );
+            Ok(())
+        }
+    }
+}
+
+#[cfg(all(feature = "time", feature = "datetime"))]
+mod with_time_of {
+    use crate::{
+        GetInner,
+        datetime::{BASE_DATETIME_FORMAT, DateTime},
+        format::{Format, FormatMode},
+        time::Time,
+    };
+
+    impl DateTime {
+        /// Replace the time of the [DateTime] with the one of a [Time], with the default
+        /// [FormatMode]
+        ///
+        /// # Example
+        /// ```rust,ignore
+        /// let datetime = DateTime::new(2023, 10, 9)?;
+        /// let datetime = datetime.with_time_of(&Time::new(18, 45, 0)?);
+        /// assert_eq!(datetime.to_string(), "2023-10-09 18:45:00".to_string());
+        /// ```
+        pub fn with_time_of(&self, time: &Time) -> Self {
+            self.with_time_of_with(time, FormatMode::default())
+        }
+
+        /// Replace the time of the [DateTime] with the one of a [Time], with the format given
+        /// by `mode`
+        ///
+        /// [FormatMode::Derive] replaces the time part of the format with the format of `time`
+        pub fn with_time_of_with(&self, time: &Time, mode: FormatMode) -> Self {
+            let format = match mode {
+                FormatMode::Keep => self.format.clone(),
+                FormatMode::Derive => match self.format.split() {
+                    Some((date_format, _)) => Format::join(&date_format, &time.format),
+                    None => self.format.clone(),
+                },
+                FormatMode::Default => BASE_DATETIME_FORMAT.get(),
+            };
+            Self {
+                datetime: self.datetime.date().and_time(time.time()),
+                format,
+                locale: self.locale,
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod test {
+        use crate::{error::SpanError, format::FormatMode, prelude::*};
+
+        #[test]
+        fn datetime_with_time_of() -> Result<(), SpanError> {
+            let datetime = DateTime::new(2023, 10, 9)?.format("%d/%m/%Y %H:%M");
+            let time = Time::new(18, 45, 30)?.format("%-I:%M:%S %p");
+            assert_eq!(
+                datetime.with_time_of(&time).to_string(),
+                "09/10/2023 6:45:30 PM"
+            );
+            assert_eq!(
+                datetime
+                    .with_time_of_with(&time, FormatMode::Keep)
+                    .to_string(),
+                "09/10/2023 18:45"
+            );
             Ok(())
         }
     }
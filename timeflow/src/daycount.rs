@@ -0,0 +1,371 @@
+//! Day-count conventions for interest accrual
+//!
+//! A [DayCount] turns the time between two [Date] values into a number of days and a fraction
+//! of a year, following the ISDA 2006 definitions.
+//!
+//! # Example
+//! ```rust,ignore
+//! let start = Date::new(2003, 11, 1)?;
+//! let end = Date::new(2004, 5, 1)?;
+//! assert_eq!(DayCount::Act360.day_count(&start, &end), 182);
+//! assert!((DayCount::ActActIsda.year_fraction(&start, &end) - (61.0 / 365.0 + 121.0 / 366.0)).abs() < 1e-12);
+//! assert_eq!(DayCount::ActActIcma(Frequency::SemiAnnual).year_fraction(&start, &end), 0.5);
+//! ```
+
+use chrono::{Datelike, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    arithmetic::{MonthEnd, add_months},
+    date::Date,
+};
+
+/// Number of regular periods in a year, e.g. of coupons
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Frequency {
+    Annual,
+    SemiAnnual,
+    Quarterly,
+    Monthly,
+}
+
+impl Frequency {
+    /// Number of periods in a year
+    pub fn per_year(&self) -> u32 {
+        12 / self.months()
+    }
+
+    /// Length of a period in months
+    pub fn months(&self) -> u32 {
+        match self {
+            Frequency::Annual => 12,
+            Frequency::SemiAnnual => 6,
+            Frequency::Quarterly => 3,
+            Frequency::Monthly => 1,
+        }
+    }
+}
+
+/// Day-count convention
+///
+/// Days are counted from `start` included to `end` excluded, and both the count and the
+/// fraction are negative when `end` is before `start`.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DayCount {
+    /// 30/360 US (bond basis): months of 30 days, the 31st and the last day of February
+    /// counting as the 30th
+    Thirty360Us,
+    /// 30E/360 (Eurobond basis): months of 30 days, the 31st counting as the 30th
+    ThirtyE360,
+    /// Actual days over 360
+    Act360,
+    /// Actual days over 365, leap years included
+    Act365Fixed,
+    /// Actual days of common years over 365 plus actual days of leap years over 366
+    ActActIsda,
+    /// Actual days over the actual days of the coupon period times the number of periods in a
+    /// year, periods being rolled back from `end`
+    ActActIcma(Frequency),
+}
+
+impl DayCount {
+    /// Number of days from `start` to `end` following the convention
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let start = Date::new(2007, 2, 28)?;
+    /// let end = Date::new(2007, 3, 31)?;
+    /// assert_eq!(DayCount::Thirty360Us.day_count(&start, &end), 30);
+    /// assert_eq!(DayCount::ThirtyE360.day_count(&start, &end), 32);
+    /// assert_eq!(DayCount::Act360.day_count(&start, &end), 31);
+    /// ```
+    pub fn day_count(&self, start: &Date, end: &Date) -> i64 {
+        let (start, end) = (start.date(), end.date());
+        if end < start {
+            return -self.days(end, start);
+        }
+        self.days(start, end)
+    }
+
+    /// Fraction of a year from `start` to `end` following the convention
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let start = Date::new(2003, 11, 1)?;
+    /// let end = Date::new(2004, 5, 1)?;
+    /// assert_eq!(DayCount::Act360.year_fraction(&start, &end), 182.0 / 360.0);
+    /// assert_eq!(DayCount::Act365Fixed.year_fraction(&start, &end), 182.0 / 365.0);
+    /// ```
+    pub fn year_fraction(&self, start: &Date, end: &Date) -> f64 {
+        let (start, end) = (start.date(), end.date());
+        if end < start {
+            return -self.fraction(end, start);
+        }
+        self.fraction(start, end)
+    }
+
+    /// Days from `start` to `end`, `start` not after `end`
+    fn days(&self, start: NaiveDate, end: NaiveDate) -> i64 {
+        match self {
+            DayCount::Thirty360Us => {
+                let mut first = start.day();
+                let mut last = end.day();
+                if is_last_of_february(start) {
+                    if is_last_of_february(end) {
+                        last = 30;
+                    }
+                    first = 30;
+                }
+                if last == 31 && first >= 30 {
+                    last = 30;
+                }
+                thirty_360(start, end, first.min(30), last)
+            }
+            DayCount::ThirtyE360 => thirty_360(start, end, start.day().min(30), end.day().min(30)),
+            _ => (end - start).num_days(),
+        }
+    }
+
+    /// Fraction of a year from `start` to `end`, `start` not after `end`
+    fn fraction(&self, start: NaiveDate, end: NaiveDate) -> f64 {
+        let days = self.days(start, end) as f64;
+        match self {
+            DayCount::Thirty360Us | DayCount::ThirtyE360 | DayCount::Act360 => days / 360.0,
+            DayCount::Act365Fixed => days / 365.0,
+            DayCount::ActActIsda => (start.year()..=end.year())
+                .map(|year| {
+                    let first = NaiveDate::from_ymd_opt(year, 1, 1).map_or(start, |d| d.max(start));
+                    let last = NaiveDate::from_ymd_opt(year + 1, 1, 1).map_or(end, |d| d.min(end));
+                    let year_days = if is_leap_year(year) { 366.0 } else { 365.0 };
+                    (last - first).num_days().max(0) as f64 / year_days
+                })
+                .sum(),
+            DayCount::ActActIcma(frequency) => {
+                let months = frequency.months() as i64;
+                let per_year = frequency.per_year() as f64;
+                let mut fraction = 0.0;
+                let mut period_end = end;
+                for period in 1.. {
+                    let Some(period_start) = add_months(end, -period * months, MonthEnd::Clamp)
+                        .ok()
+                        .flatten()
+                    else {
+                        break;
+                    };
+                    let overlap = (period_end - period_start.max(start)).num_days() as f64;
+                    let length = (period_end - period_start).num_days() as f64;
+                    fraction += overlap / (length * per_year);
+                    if period_start <= start {
+                        break;
+                    }
+                    period_end = period_start;
+                }
+                fraction
+            }
+        }
+    }
+}
+
+/// Days of a 30/360 convention once the days of the month are adjusted
+fn thirty_360(start: NaiveDate, end: NaiveDate, first: u32, last: u32) -> i64 {
+    360 * (end.year() as i64 - start.year() as i64)
+        + 30 * (end.month() as i64 - start.month() as i64)
+        + (last as i64 - first as i64)
+}
+
+fn is_last_of_february(date: NaiveDate) -> bool {
+    date.month() == 2 && date.succ_opt().is_some_and(|next| next.month() == 3)
+}
+
+fn is_leap_year(year: i32) -> bool {
+    NaiveDate::from_ymd_opt(year, 2, 29).is_some()
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use crate::error::SpanError;
+
+    fn assert_fraction(day_count: DayCount, start: &Date, end: &Date, expected: f64) {
+        let fraction = day_count.year_fraction(start, end);
+        assert!(
+            (fraction - expected).abs() < 1e-5,
+            "{day_count:?} from {start} to {end}: {fraction} instead of {expected}"
+        );
+    }
+
+    #[test]
+    fn day_count_thirty_360() -> Result<(), SpanError> {
+        // start, end, 30/360 US, 30E/360
+        let vectors = [
+            ("2007-01-15", "2007-01-30", 15, 15),
+            ("2007-01-15", "2007-02-15", 30, 30),
+            ("2007-01-15", "2007-07-15", 180, 180),
+            ("2007-09-30", "2008-03-31", 180, 180),
+            ("2007-09-30", "2007-10-31", 30, 30),
+            ("2007-09-30", "2008-09-30", 360, 360),
+            ("2007-01-15", "2007-01-31", 16, 15),
+            ("2007-01-31", "2007-02-28", 28, 28),
+            ("2007-02-28", "2007-03-31", 30, 32),
+            ("2006-08-31", "2007-02-28", 178, 178),
+            ("2007-02-28", "2007-08-28", 178, 180),
+            ("2007-02-14", "2007-02-28", 14, 14),
+            ("2007-02-26", "2008-02-29", 363, 363),
+            ("2008-02-29", "2009-02-28", 360, 359),
+            ("2008-02-29", "2008-03-30", 30, 31),
+            ("2008-02-29", "2008-03-31", 30, 31),
+            ("2007-02-28", "2007-03-05", 5, 7),
+            ("2007-10-31", "2007-11-28", 28, 28),
+            ("2007-08-31", "2008-02-29", 179, 179),
+            ("2008-02-29", "2008-08-31", 180, 181),
+            ("2008-08-31", "2009-02-28", 178, 178),
+            ("2009-02-28", "2009-08-31", 180, 182),
+        ];
+        for (start, end, us, european) in vectors {
+            let (start, end) = (Date::try_from(start)?, Date::try_from(end)?);
+            assert_eq!(
+                DayCount::Thirty360Us.day_count(&start, &end),
+                us,
+                "30/360 US from {start} to {end}"
+            );
+            assert_eq!(
+                DayCount::ThirtyE360.day_count(&start, &end),
+                european,
+                "30E/360 from {start} to {end}"
+            );
+            assert_fraction(DayCount::Thirty360Us, &start, &end, us as f64 / 360.0);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn day_count_actual() -> Result<(), SpanError> {
+        let (start, end) = (Date::new(2003, 11, 1)?, Date::new(2004, 5, 1)?);
+        assert_eq!(DayCount::Act360.day_count(&start, &end), 182);
+        assert_eq!(DayCount::Act365Fixed.day_count(&start, &end), 182);
+        assert_eq!(DayCount::ActActIsda.day_count(&start, &end), 182);
+        assert_fraction(DayCount::Act360, &start, &end, 0.50556);
+        assert_fraction(DayCount::Act365Fixed, &start, &end, 0.49863);
+        // A leap year still counts 365 days with ACT/365F
+        assert_fraction(
+            DayCount::Act365Fixed,
+            &Date::new(2024, 1, 1)?,
+            &Date::new(2025, 1, 1)?,
+            366.0 / 365.0,
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn day_count_act_act() -> Result<(), SpanError> {
+        // ISDA 2006 examples of actual/actual: start, end, ISDA, ICMA
+        let vectors = [
+            // Regular semi-annual period
+            (
+                "2003-11-01",
+                "2004-05-01",
+                Frequency::SemiAnnual,
+                0.49772,
+                0.5,
+            ),
+            // Short first period of an annual bond
+            (
+                "1999-02-01",
+                "1999-07-01",
+                Frequency::Annual,
+                0.41096,
+                0.41096,
+            ),
+            // Long first period of an annual bond
+            (
+                "2002-08-15",
+                "2003-07-15",
+                Frequency::Annual,
+                0.91507,
+                0.91507,
+            ),
+            // Regular semi-annual period over the new year
+            (
+                "1999-07-30",
+                "2000-01-30",
+                Frequency::SemiAnnual,
+                0.50389,
+                0.5,
+            ),
+            // Regular quarterly period
+            (
+                "2023-01-15",
+                "2023-04-15",
+                Frequency::Quarterly,
+                0.24658,
+                0.25,
+            ),
+        ];
+        for (start, end, frequency, isda, icma) in vectors {
+            let (start, end) = (Date::try_from(start)?, Date::try_from(end)?);
+            assert_fraction(DayCount::ActActIsda, &start, &end, isda);
+            assert_fraction(DayCount::ActActIcma(frequency), &start, &end, icma);
+        }
+        assert_fraction(
+            DayCount::ActActIsda,
+            &Date::new(2023, 1, 1)?,
+            &Date::new(2025, 1, 1)?,
+            2.0,
+        );
+        // Long first period of a semi-annual bond, over three reference periods
+        assert_fraction(
+            DayCount::ActActIcma(Frequency::SemiAnnual),
+            &Date::new(2022, 10, 1)?,
+            &Date::new(2024, 1, 1)?,
+            0.5 + 0.5 + 92.0 / (2.0 * 184.0),
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn day_count_reversed() -> Result<(), SpanError> {
+        let (start, end) = (Date::new(2023, 1, 31)?, Date::new(2023, 3, 31)?);
+        for day_count in [
+            DayCount::Thirty360Us,
+            DayCount::ThirtyE360,
+            DayCount::Act360,
+            DayCount::Act365Fixed,
+            DayCount::ActActIsda,
+            DayCount::ActActIcma(Frequency::Monthly),
+        ] {
+            assert_eq!(
+                day_count.day_count(&end, &start),
+                -day_count.day_count(&start, &end)
+            );
+            assert_eq!(
+                day_count.year_fraction(&end, &start),
+                -day_count.year_fraction(&start, &end)
+            );
+            assert_eq!(day_count.year_fraction(&start, &start), 0.0);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn day_count_serde() -> Result<(), serde_json::Error> {
+        let day_counts: Vec<DayCount> =
+            serde_json::from_str(r#"["act360", "thirty360_us", {"act_act_icma": "semi_annual"}]"#)?;
+        assert_eq!(
+            day_counts,
+            [
+                DayCount::Act360,
+                DayCount::Thirty360Us,
+                DayCount::ActActIcma(Frequency::SemiAnnual)
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn frequency_months() {
+        assert_eq!(Frequency::SemiAnnual.months(), 6);
+        assert_eq!(Frequency::Quarterly.per_year(), 4);
+    }
+}
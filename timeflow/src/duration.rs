@@ -0,0 +1,131 @@
+use chrono::TimeDelta;
+
+const UNITS: [(&str, i128); 5] = [
+    ("day", 86_400_000_000_000),
+    ("hour", 3_600_000_000_000),
+    ("minute", 60_000_000_000),
+    ("second", 1_000_000_000),
+    ("millisecond", 1_000_000),
+];
+
+/// Display a [TimeDelta], as returned by [Span::elapsed](crate::span::Span::elapsed)
+/// or [Stopwatch](crate::stopwatch::Stopwatch), for humans or as ISO 8601
+///
+/// # Example
+/// ```rust,ignore
+/// let elapsed = TimeDelta::seconds(3725);
+/// assert_eq!(elapsed.humanize(), "1 hour, 2 minutes, 5 seconds".to_string());
+/// assert_eq!(elapsed.to_iso8601(), "PT1H2M5S".to_string());
+/// ```
+pub trait DurationFormat {
+    /// Non-zero days, hours, minutes, seconds and milliseconds in english, from the largest unit
+    ///
+    /// Below a millisecond, the duration is displayed as `0 milliseconds`
+    fn humanize(&self) -> String;
+
+    /// ISO 8601 duration with days, hours, minutes and fractional seconds, e.g. `P1DT2H0.5S`
+    ///
+    /// A negative duration is prefixed with `-`
+    fn to_iso8601(&self) -> String;
+}
+
+impl DurationFormat for TimeDelta {
+    fn humanize(&self) -> String {
+        let sign = if *self < TimeDelta::zero() { "-" } else { "" };
+        let delta = self.abs();
+        let mut rest = delta.num_seconds() as i128 * 1_000_000_000 + delta.subsec_nanos() as i128;
+        let mut parts = Vec::new();
+        for (name, nanos) in UNITS {
+            let count = rest / nanos;
+            if count == 0 {
+                continue;
+            }
+            rest %= nanos;
+            let plural = if count == 1 { "" } else { "s" };
+            parts.push(format!("{count} {name}{plural}"));
+        }
+        match parts.is_empty() {
+            true => "0 milliseconds".to_string(),
+            false => format!("{sign}{}", parts.join(", ")),
+        }
+    }
+
+    fn to_iso8601(&self) -> String {
+        let sign = if *self < TimeDelta::zero() { "-" } else { "" };
+        let delta = self.abs();
+        let days = delta.num_days();
+        let hours = delta.num_hours() % 24;
+        let minutes = delta.num_minutes() % 60;
+        let seconds = delta.num_seconds() % 60;
+        let nanos = delta.subsec_nanos();
+
+        let mut iso = format!("{sign}P");
+        if days != 0 {
+            iso.push_str(&format!("{days}D"));
+        }
+        if hours == 0 && minutes == 0 && seconds == 0 && nanos == 0 {
+            if days == 0 {
+                iso.push_str("T0S");
+            }
+            return iso;
+        }
+        iso.push('T');
+        if hours != 0 {
+            iso.push_str(&format!("{hours}H"));
+        }
+        if minutes != 0 {
+            iso.push_str(&format!("{minutes}M"));
+        }
+        if seconds != 0 || nanos != 0 {
+            iso.push_str(&seconds.to_string());
+            if nanos != 0 {
+                let fraction = format!("{nanos:09}");
+                iso.push('.');
+                iso.push_str(fraction.trim_end_matches('0'));
+            }
+            iso.push('S');
+        }
+        iso
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+
+    #[test]
+    fn humanize() {
+        assert_eq!(
+            TimeDelta::seconds(3725).humanize(),
+            "1 hour, 2 minutes, 5 seconds"
+        );
+        assert_eq!(
+            (TimeDelta::days(2) + TimeDelta::milliseconds(1)).humanize(),
+            "2 days, 1 millisecond"
+        );
+        assert_eq!(TimeDelta::minutes(-1).humanize(), "-1 minute");
+        assert_eq!(TimeDelta::microseconds(12).humanize(), "0 milliseconds");
+        assert_eq!(TimeDelta::zero().humanize(), "0 milliseconds");
+    }
+
+    #[test]
+    fn humanize_large() {
+        assert_eq!(
+            TimeDelta::days(365 * 1_000_000).humanize(),
+            "365000000 days"
+        );
+    }
+
+    #[test]
+    fn to_iso8601() {
+        assert_eq!(TimeDelta::seconds(3725).to_iso8601(), "PT1H2M5S");
+        assert_eq!(TimeDelta::days(1).to_iso8601(), "P1D");
+        assert_eq!(
+            (TimeDelta::days(1) + TimeDelta::milliseconds(500)).to_iso8601(),
+            "P1DT0.5S"
+        );
+        assert_eq!(TimeDelta::microseconds(1_250).to_iso8601(), "PT0.00125S");
+        assert_eq!(TimeDelta::minutes(-90).to_iso8601(), "-PT1H30M");
+        assert_eq!(TimeDelta::zero().to_iso8601(), "PT0S");
+    }
+}
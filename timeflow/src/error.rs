@@ -1,21 +1,57 @@
+use crate::{format::FormatKind, span::Unit};
+
+/// Errors of the crate
+///
+/// Every variant has a stable [SpanError::code], and errors pointing into an input or a
+/// format display a caret-annotated [SpanError::snippet].
 #[derive(thiserror::Error, Debug, PartialEq)]
 pub enum SpanError {
     #[error("Invalid Utc TryFrom / TryInto")]
     InvalidUtc,
-    #[error("ParseFromStr: {0}")]
-    ParseFromStr(#[from] chrono::ParseError),
-    #[error("ParseFromTimestamp: {0}")]
-    ParseFromTimestamp(String),
-    #[error("ClearUnit: {0}")]
-    ClearUnit(String),
-    #[error("InvalidUpdate: {0}")]
-    InvalidUpdate(String),
+    #[error("ParseFromStr: {reason} at byte {position} of \"{input}\" with format \"{format}\"\n{}", caret(input, *position))]
+    ParseFromStr {
+        input: String,
+        format: String,
+        position: usize,
+        reason: chrono::ParseError,
+    },
+    #[error("ParseFromTimestamp: {value} is out of range")]
+    ParseFromTimestamp { value: i64 },
+    #[error("ClearUnit: Cannot clear {unit} of {input}")]
+    ClearUnit { input: String, unit: Unit },
+    #[error("InvalidUpdate: Cannot Add/Remove {value} {unit} to/from {input}")]
+    InvalidUpdate {
+        input: String,
+        unit: Unit,
+        value: i64,
+    },
     #[error("Invalid datetime: {0}-{1}-{2} {3}:{4}:{5}")]
     InvalidDateTime(i32, u32, u32, u32, u32, u32),
     #[error("Invalid date: {0}-{1}-{2}")]
     InvalidDate(i32, u32, u32),
     #[error("Invalid time: {0}:{1}:{2}")]
     InvalidTime(u32, u32, u32),
+    #[error("Unknown locale: {0}")]
+    UnknownLocale(String),
+    #[error("Unknown unit: {0}")]
+    UnknownUnit(String),
+    #[error("UnitNotAllowed: {unit} is not a {kind} unit")]
+    UnitNotAllowed { unit: Unit, kind: FormatKind },
+    #[error("InvalidWindow: \"{0}\" is not a window like \"22:00-06:00\"")]
+    InvalidWindow(String),
+    #[error("InvalidOpeningHours: \"{0}\" is not a supported opening_hours rule")]
+    InvalidOpeningHours(String),
+    #[error("InvalidSchedule: end {end} is not after start {start}")]
+    InvalidSchedule { start: String, end: String },
+    #[error("InvalidBucketWidth: \"{0}\" is not a bucket width like \"5m\" or \"1M\"")]
+    InvalidBucketWidth(String),
+    #[error("InvalidFormat: `{specifier}` at byte {position} of \"{pattern}\" {issue}")]
+    InvalidFormat {
+        pattern: String,
+        specifier: String,
+        position: usize,
+        issue: FormatIssue,
+    },
     #[cfg(feature = "date")]
     #[error("{1} ➤  {0}")]
     Date(#[source] Box<SpanError>, DateError),
@@ -27,6 +63,106 @@ pub enum SpanError {
     Time(#[source] Box<SpanError>, TimeError),
 }
 
+impl SpanError {
+    /// Stable, machine-readable code of the error, ignoring the Date, Time or DateTime context
+    ///
+    /// | Code | Variant |
+    /// |------|---------|
+    /// | `invalid_utc` | [SpanError::InvalidUtc] |
+    /// | `parse_from_str` | [SpanError::ParseFromStr] |
+    /// | `parse_from_timestamp` | [SpanError::ParseFromTimestamp] |
+    /// | `clear_unit` | [SpanError::ClearUnit] |
+    /// | `invalid_update` | [SpanError::InvalidUpdate] |
+    /// | `invalid_datetime` | [SpanError::InvalidDateTime] |
+    /// | `invalid_date` | [SpanError::InvalidDate] |
+    /// | `invalid_time` | [SpanError::InvalidTime] |
+    /// | `unknown_locale` | [SpanError::UnknownLocale] |
+    /// | `unknown_unit` | [SpanError::UnknownUnit] |
+    /// | `unit_not_allowed` | [SpanError::UnitNotAllowed] |
+    /// | `invalid_window` | [SpanError::InvalidWindow] |
+    /// | `invalid_opening_hours` | [SpanError::InvalidOpeningHours] |
+    /// | `invalid_schedule` | [SpanError::InvalidSchedule] |
+    /// | `invalid_bucket_width` | [SpanError::InvalidBucketWidth] |
+    /// | `invalid_format` | [SpanError::InvalidFormat] |
+    pub fn code(&self) -> &'static str {
+        match self.root() {
+            SpanError::InvalidUtc => "invalid_utc",
+            SpanError::ParseFromStr { .. } => "parse_from_str",
+            SpanError::ParseFromTimestamp { .. } => "parse_from_timestamp",
+            SpanError::ClearUnit { .. } => "clear_unit",
+            SpanError::InvalidUpdate { .. } => "invalid_update",
+            SpanError::InvalidDateTime(..) => "invalid_datetime",
+            SpanError::InvalidDate(..) => "invalid_date",
+            SpanError::InvalidTime(..) => "invalid_time",
+            SpanError::UnknownLocale(_) => "unknown_locale",
+            SpanError::UnknownUnit(_) => "unknown_unit",
+            SpanError::UnitNotAllowed { .. } => "unit_not_allowed",
+            SpanError::InvalidWindow(_) => "invalid_window",
+            SpanError::InvalidOpeningHours(_) => "invalid_opening_hours",
+            SpanError::InvalidSchedule { .. } => "invalid_schedule",
+            SpanError::InvalidBucketWidth(_) => "invalid_bucket_width",
+            SpanError::InvalidFormat { .. } => "invalid_format",
+            #[cfg(feature = "date")]
+            SpanError::Date(error, _) => error.code(),
+            #[cfg(feature = "datetime")]
+            SpanError::DateTime(error, _) => error.code(),
+            #[cfg(feature = "time")]
+            SpanError::Time(error, _) => error.code(),
+        }
+    }
+
+    /// Error without its Date, Time or DateTime context
+    pub fn root(&self) -> &SpanError {
+        match self {
+            #[cfg(feature = "date")]
+            SpanError::Date(error, _) => error.root(),
+            #[cfg(feature = "datetime")]
+            SpanError::DateTime(error, _) => error.root(),
+            #[cfg(feature = "time")]
+            SpanError::Time(error, _) => error.root(),
+            error => error,
+        }
+    }
+
+    /// Offending input or format with a caret under the byte in error, if any
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let err = Date::try_from(("2023-1x-09", "%Y-%m-%d")).unwrap_err();
+    /// assert_eq!(err.snippet(), Some("    2023-1x-09\n          ^".to_string()));
+    /// ```
+    pub fn snippet(&self) -> Option<String> {
+        match self.root() {
+            SpanError::ParseFromStr {
+                input, position, ..
+            } => Some(caret(input, *position)),
+            SpanError::InvalidFormat {
+                pattern, position, ..
+            } => Some(caret(pattern, *position)),
+            _ => None,
+        }
+    }
+}
+
+/// `text` with a caret under the character at byte `position`
+fn caret(text: &str, position: usize) -> String {
+    let column = text
+        .get(..position)
+        .map_or(text.chars().count(), |before| before.chars().count());
+    format!("    {text}\n    {}^", " ".repeat(column))
+}
+
+/// Reason why a specifier of a [Format](crate::format::Format) was rejected
+#[derive(thiserror::Error, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum FormatIssue {
+    #[error("is not a valid specifier")]
+    Unknown,
+    #[error("is not terminated")]
+    Unterminated,
+    #[error("is not allowed in a {0} format")]
+    NotAllowed(FormatKind),
+}
+
 #[cfg(feature = "date")]
 #[derive(thiserror::Error, Debug, PartialEq)]
 #[error("Date")]
@@ -66,3 +202,45 @@ impl<T> ErrorContext<T, TimeError> for Result<T, SpanError> {
         self.map_err(|e| SpanError::Time(Box::new(e), context))
     }
 }
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+
+    #[test]
+    fn error_code_and_root() {
+        let err = SpanError::InvalidUpdate {
+            input: "2023-10-09".to_string(),
+            unit: Unit::Day,
+            value: 3,
+        };
+        assert_eq!(err.code(), "invalid_update");
+        #[cfg(feature = "date")]
+        {
+            let err = Err::<(), _>(err).err_ctx(DateError).unwrap_err();
+            assert_eq!(err.code(), "invalid_update");
+            assert!(matches!(
+                err.root(),
+                SpanError::InvalidUpdate { value: 3, .. }
+            ));
+        }
+    }
+
+    #[test]
+    fn error_snippet() {
+        let err = SpanError::InvalidFormat {
+            pattern: "%Y-%H".to_string(),
+            specifier: "%H".to_string(),
+            position: 3,
+            issue: FormatIssue::NotAllowed(FormatKind::Date),
+        };
+        assert_eq!(err.snippet(), Some("    %Y-%H\n       ^".to_string()));
+        assert_eq!(SpanError::InvalidUtc.snippet(), None);
+    }
+
+    #[test]
+    fn error_caret_multibyte() {
+        assert_eq!(caret("éà 12", 5), "    éà 12\n       ^".to_string());
+        assert_eq!(caret("12", 2), "    12\n      ^".to_string());
+    }
+}
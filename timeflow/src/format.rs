@@ -0,0 +1,944 @@
+//! Formatting layer on top of [chrono::format::strftime]
+//!
+//! Every standard specifier is rendered by [chrono], the localized ones (`%B`, `%b`, `%A`, `%a`,
+//! `%p`, `%P`) are rendered with the [Locale] of the value, and timeflow adds the following
+//! extensions, written `%{name}` or `%{name:argument}`:
+//!
+//! | Specifier                 | Example            | Description                                             |
+//! |---------------------------|--------------------|---------------------------------------------------------|
+//! | `%{ordinal}`              | `1st`, `1er`, `1.` | Day of the month with the ordinal suffix of the locale  |
+//! | `%{day_text}`             | `twenty-one`       | Day of the month spelled out (english only)             |
+//! | `%{ordinal_text}`         | `twenty-first`     | Ordinal day of the month spelled out (english only)     |
+//! | `%{quarter}`              | `3`                | Quarter of the year, from 1 to 4                        |
+//! | `%{iso_week}`             | `2024-W07`         | ISO 8601 week-based year and week number                |
+//! | `%{fiscal_year:M}`        | `2025`             | Fiscal year starting in month `M`, named after its end  |
+//! | `%{fiscal_quarter:M}`     | `2`                | Quarter of the fiscal year starting in month `M`        |
+//! | `%{fiscal_month:M}`       | `7`                | Month of the fiscal year starting in month `M`          |
+//!
+//! The argument of the fiscal specifiers is optional and defaults to `1` (January).
+//!
+//! Extensions are only available for display, parsing a value with an extension in its
+//! format returns an error.
+//!
+//! # Example
+//! ```rust,ignore
+//! let date = Date::new(2024, 3, 21)?.format("%A, the %{ordinal_text} of %B (Q%{quarter})");
+//! assert_eq!(date.to_string(), "Thursday, the twenty-first of March (Q1)".to_string());
+//! ```
+//!
+//! Patterns can be validated once with [Format], which rejects unknown specifiers and
+//! specifiers that do not fit the type of value (for example `%H` in a [FormatKind::Date] format).
+
+use std::{
+    borrow::Cow,
+    sync::{Arc, OnceLock},
+};
+
+use chrono::{
+    Datelike, NaiveDate, NaiveTime,
+    format::{Item, ParseResult, Parsed, StrftimeItems},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::{FormatIssue, SpanError},
+    locale::{Locale, delocalize, is_localized, localized_value},
+};
+
+#[cfg(any(feature = "date", feature = "datetime"))]
+pub(crate) const DEFAULT_DATE_FORMAT: &str = "%Y-%m-%d";
+#[cfg(any(feature = "time", feature = "datetime"))]
+pub(crate) const DEFAULT_TIME_FORMAT: &str = "%H:%M:%S";
+#[cfg(feature = "datetime")]
+pub(crate) const DEFAULT_DATETIME_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+/// Type of value a [Format] is meant for
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Serialize, Deserialize)]
+pub enum FormatKind {
+    Date,
+    Time,
+    DateTime,
+}
+
+impl std::fmt::Display for FormatKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FormatKind::Date => write!(f, "Date"),
+            FormatKind::Time => write!(f, "Time"),
+            FormatKind::DateTime => write!(f, "DateTime"),
+        }
+    }
+}
+
+/// What a specifier needs to be rendered
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Requirement {
+    /// Escaped characters such as `%%`, `%n` or `%t`
+    Nothing,
+    Date,
+    Time,
+    DateAndTime,
+    /// Timezone specifiers, never available on naive values
+    Offset,
+}
+
+impl Requirement {
+    fn of(specifier: char) -> Option<Self> {
+        match specifier {
+            '%' | 'n' | 't' => Some(Self::Nothing),
+            'Y' | 'C' | 'y' | 'm' | 'b' | 'B' | 'h' | 'd' | 'e' | 'a' | 'A' | 'w' | 'u' | 'U'
+            | 'W' | 'G' | 'g' | 'V' | 'j' | 'D' | 'x' | 'F' | 'v' => Some(Self::Date),
+            'H' | 'k' | 'I' | 'l' | 'P' | 'p' | 'M' | 'S' | 'f' | 'R' | 'T' | 'X' | 'r' => {
+                Some(Self::Time)
+            }
+            'c' | 's' => Some(Self::DateAndTime),
+            'Z' | 'z' | '+' => Some(Self::Offset),
+            _ => None,
+        }
+    }
+
+    fn allowed_in(self, kind: FormatKind) -> bool {
+        matches!(
+            (self, kind),
+            (Self::Nothing, _)
+                | (Self::Date, FormatKind::Date | FormatKind::DateTime)
+                | (Self::Time, FormatKind::Time | FormatKind::DateTime)
+                | (Self::DateAndTime, FormatKind::DateTime)
+        )
+    }
+}
+
+/// Compiled pattern for a [FormatKind]
+///
+/// The pattern is parsed once into [chrono] items, and shared behind an [Arc]: cloning a
+/// [Format] or giving it to many values does not copy nor re-parse the pattern.
+///
+/// Formats given as strings (e.g. with [Span::format](crate::span::Span::format)) are only
+/// compiled the first time they are displayed.
+///
+/// See the [chrono::format::strftime] for the supported escape sequences, and the
+/// [module documentation](crate::format) for the timeflow extensions.
+///
+/// # Example
+/// ```rust
+/// use timeflow::format::{Format, FormatKind};
+///
+/// let format = Format::date("%d/%m/%Y").unwrap();
+/// assert_eq!(format.pattern(), "%d/%m/%Y");
+/// assert_eq!(format.kind(), FormatKind::Date);
+///
+/// let error = Format::date("%d/%m/%Y %H").unwrap_err();
+/// assert_eq!(
+///     error.to_string(),
+///     "InvalidFormat: `%H` at byte 9 of \"%d/%m/%Y %H\" is not allowed in a Date format"
+/// );
+/// ```
+#[derive(Clone)]
+pub struct Format(Arc<Compiled>);
+
+struct Compiled {
+    pattern: String,
+    kind: FormatKind,
+    /// Compiled on first use, [None] when [chrono] cannot parse the pattern by itself
+    /// (timeflow extensions or invalid pattern)
+    items: OnceLock<Option<Vec<Item<'static>>>>,
+    /// The pattern contains `%B`, `%b`, `%A`, `%a`, `%p` or `%P`
+    localized: bool,
+}
+
+impl Format {
+    /// Parse and validate `pattern` for the given [FormatKind]
+    ///
+    /// # Errors
+    /// Return a [SpanError::InvalidFormat] pointing to the first rejected specifier
+    pub fn new(pattern: impl ToString, kind: FormatKind) -> Result<Self, SpanError> {
+        let pattern = pattern.to_string();
+        validate(&pattern, kind)?;
+        let format = Self::compile(pattern, kind);
+        format.compiled_items();
+        Ok(format)
+    }
+
+    /// Parse and validate a [FormatKind::Date] pattern
+    pub fn date(pattern: impl ToString) -> Result<Self, SpanError> {
+        Self::new(pattern, FormatKind::Date)
+    }
+
+    /// Parse and validate a [FormatKind::Time] pattern
+    pub fn time(pattern: impl ToString) -> Result<Self, SpanError> {
+        Self::new(pattern, FormatKind::Time)
+    }
+
+    /// Parse and validate a [FormatKind::DateTime] pattern
+    pub fn datetime(pattern: impl ToString) -> Result<Self, SpanError> {
+        Self::new(pattern, FormatKind::DateTime)
+    }
+
+    /// Wrap `pattern` without validating it, its items are compiled on first use
+    pub(crate) fn compile(pattern: impl ToString, kind: FormatKind) -> Self {
+        let pattern = pattern.to_string();
+        let mut localized = false;
+        let mut chars = pattern.chars();
+        while let Some(c) = chars.next() {
+            if c == '%'
+                && let Some(specifier) = chars.next()
+            {
+                localized |= is_localized(specifier);
+            }
+        }
+        Self(Arc::new(Compiled {
+            pattern,
+            kind,
+            items: OnceLock::new(),
+            localized,
+        }))
+    }
+
+    /// Getter for the pattern
+    pub fn pattern(&self) -> &str {
+        &self.0.pattern
+    }
+
+    /// Getter for the kind of value the pattern is meant for
+    pub fn kind(&self) -> FormatKind {
+        self.0.kind
+    }
+
+    fn compiled_items(&self) -> Option<&[Item<'static>]> {
+        self.0
+            .items
+            .get_or_init(|| StrftimeItems::new(self.pattern()).parse_to_owned().ok())
+            .as_deref()
+    }
+
+    /// Compiled [chrono] items, [None] if the pattern must be expanded for `locale` first
+    pub(crate) fn items(&self, locale: Locale) -> Option<&[Item<'static>]> {
+        if self.0.localized && locale != Locale::En {
+            return None;
+        }
+        self.compiled_items()
+    }
+
+    /// Parse `input` with the compiled items if any, translating the localized names of `locale` first,
+    /// then build the value with `build`
+    ///
+    /// On error, the position is the byte of the delocalized input where parsing stopped
+    pub(crate) fn parse_with<T>(
+        &self,
+        input: &str,
+        locale: Locale,
+        build: impl FnOnce(&Parsed) -> ParseResult<T>,
+    ) -> Result<T, SpanError> {
+        let mut parsed = Parsed::new();
+        let delocalized = match self.0.localized {
+            true => delocalize(input, self.pattern(), locale),
+            false => Cow::Borrowed(input),
+        };
+        let result = match self.0.items.get().and_then(Option::as_ref) {
+            Some(items) => chrono::format::parse(&mut parsed, &delocalized, items.iter()),
+            None => chrono::format::parse(
+                &mut parsed,
+                &delocalized,
+                StrftimeItems::new(self.pattern()),
+            ),
+        };
+        result
+            .and_then(|_| build(&parsed))
+            .map_err(|reason| SpanError::ParseFromStr {
+                input: input.to_string(),
+                format: self.pattern().to_string(),
+                position: self.error_position(&delocalized),
+                reason,
+            })
+    }
+
+    /// Byte of `input` where parsing stops, parsing item by item
+    fn error_position(&self, input: &str) -> usize {
+        let mut parsed = Parsed::new();
+        let mut rest = input;
+        for item in StrftimeItems::new(self.pattern()) {
+            match chrono::format::parse_and_remainder(&mut parsed, rest, std::iter::once(item)) {
+                Ok(remainder) => rest = remainder,
+                Err(_) => break,
+            }
+        }
+        input.len() - rest.len()
+    }
+
+    /// Join a date format and a time format into a [FormatKind::DateTime] format, separated by a space
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let format = Format::join(&Format::date("%d/%m/%Y")?, &Format::time("%Hh%M")?);
+    /// assert_eq!(format.pattern(), "%d/%m/%Y %Hh%M");
+    /// ```
+    pub fn join(date: &Format, time: &Format) -> Self {
+        Self::compile(
+            format!("{} {}", date.pattern(), time.pattern()),
+            FormatKind::DateTime,
+        )
+    }
+
+    /// Split a pattern into its date part and its time part, dropping the text between them
+    ///
+    /// Return [None] if the pattern lacks date or time specifiers, interleaves them, or uses
+    /// a specifier needing both (`%c`, `%s`).
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let (date, time) = Format::datetime("%d/%m/%Y at %Hh%M")?.split().unwrap();
+    /// assert_eq!((date.pattern(), time.pattern()), ("%d/%m/%Y", "%Hh%M"));
+    /// ```
+    pub fn split(&self) -> Option<(Format, Format)> {
+        let pattern = self.pattern();
+        let mut date: Option<(usize, usize)> = None;
+        let mut time: Option<(usize, usize)> = None;
+        for Specifier {
+            start,
+            end,
+            requirement,
+        } in specifiers(pattern)
+        {
+            let span = match requirement.ok()? {
+                Requirement::Nothing => continue,
+                Requirement::Date => &mut date,
+                Requirement::Time => &mut time,
+                Requirement::DateAndTime | Requirement::Offset => return None,
+            };
+            let (first, _) = span.get_or_insert((start, end));
+            *span = Some((*first, end));
+        }
+        let ((date_start, date_end), (time_start, time_end)) = (date?, time?);
+        let (date, time) = if date_end <= time_start {
+            (&pattern[..date_end], &pattern[time_start..])
+        } else if time_end <= date_start {
+            (&pattern[date_start..], &pattern[..time_end])
+        } else {
+            return None;
+        };
+        Some((
+            Self::compile(date, FormatKind::Date),
+            Self::compile(time, FormatKind::Time),
+        ))
+    }
+}
+
+/// Format given to the value built by a combinator such as `Date::at` or `DateTime::split`
+///
+/// Combinators without a mode, such as `Date::at`, `DateTime::split`, `DateTime::with_date` and
+/// `DateTime::with_time_of`, use the default, [FormatMode::Derive]. Their `_with` variant takes
+/// the mode.
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone, Copy, Serialize, Deserialize)]
+pub enum FormatMode {
+    /// Keep the format of the value the method is called on
+    ///
+    /// Same as [FormatMode::Derive] when that value has another type than the result
+    Keep,
+    /// Derive the format from the formats of the parts, with [Format::join] and [Format::split]
+    ///
+    /// Fall back to [FormatMode::Keep], then to [FormatMode::Default], when a format cannot be split
+    #[default]
+    Derive,
+    /// Use the default format of the result type
+    Default,
+}
+
+/// Specifier of a pattern, found by [specifiers]
+struct Specifier {
+    /// Byte offset of the `%`
+    start: usize,
+    /// Byte offset after the specifier
+    end: usize,
+    /// What the specifier needs, or why it is invalid
+    requirement: Result<Requirement, FormatIssue>,
+}
+
+/// Every specifier of `pattern`, in order
+///
+/// Unknown specifiers and extensions are [FormatIssue::Unknown], a trailing `%` or a `%{`
+/// without `}` is [FormatIssue::Unterminated].
+fn specifiers(pattern: &str) -> Vec<Specifier> {
+    let mut specifiers = Vec::new();
+    let mut chars = pattern.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        if c != '%' {
+            continue;
+        }
+        let mut requirement = Err(FormatIssue::Unterminated);
+        let mut end = pattern.len();
+        while let Some((index, c)) = chars.next() {
+            end = index + c.len_utf8();
+            match c {
+                // Padding, fractional and timezone modifiers are followed by the specifier itself
+                '-' | '_' | '0' | '.' | '3' | '6' | '9' | ':' | '#' => continue,
+                '{' => {
+                    let Some(close) = pattern[end..].find('}') else {
+                        end = pattern.len();
+                        break;
+                    };
+                    let spec = &pattern[end..end + close];
+                    end += close + 1;
+                    while chars.next_if(|(index, _)| *index < end).is_some() {}
+                    requirement = Extension::parse(spec)
+                        .map(|_| Requirement::Date)
+                        .ok_or(FormatIssue::Unknown);
+                }
+                c => requirement = Requirement::of(c).ok_or(FormatIssue::Unknown),
+            }
+            break;
+        }
+        specifiers.push(Specifier {
+            start,
+            end,
+            requirement,
+        });
+    }
+    specifiers
+}
+
+impl std::fmt::Display for Format {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.pattern())
+    }
+}
+
+impl std::fmt::Debug for Format {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Format")
+            .field("pattern", &self.pattern())
+            .field("kind", &self.kind())
+            .finish()
+    }
+}
+
+impl PartialEq for Format {
+    fn eq(&self, other: &Self) -> bool {
+        self.pattern() == other.pattern()
+    }
+}
+
+impl Eq for Format {}
+
+impl PartialOrd for Format {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Format {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.pattern().cmp(other.pattern())
+    }
+}
+
+impl std::hash::Hash for Format {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.pattern().hash(state);
+    }
+}
+
+/// Check every specifier of `pattern` against `kind`
+fn validate(pattern: &str, kind: FormatKind) -> Result<(), SpanError> {
+    specifiers(pattern).into_iter().try_for_each(|specifier| {
+        let issue = match specifier.requirement {
+            Ok(requirement) if requirement.allowed_in(kind) => return Ok(()),
+            Ok(_) => FormatIssue::NotAllowed(kind),
+            Err(issue) => issue,
+        };
+        Err(SpanError::InvalidFormat {
+            pattern: pattern.to_string(),
+            specifier: pattern[specifier.start..specifier.end].to_string(),
+            position: specifier.start,
+            issue,
+        })
+    })
+}
+
+/// Extensions of the strftime syntax handled by timeflow
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Extension {
+    Ordinal,
+    DayText,
+    OrdinalText,
+    Quarter,
+    IsoWeek,
+    FiscalYear(u32),
+    FiscalQuarter(u32),
+    FiscalMonth(u32),
+}
+
+impl Extension {
+    /// Parse the content of `%{...}`
+    fn parse(spec: &str) -> Option<Self> {
+        let (name, argument) = match spec.split_once(':') {
+            Some((name, argument)) => (name, Some(argument)),
+            None => (spec, None),
+        };
+        let start_month = || match argument {
+            None => Some(1),
+            Some(month) => month.parse().ok().filter(|month| (1..=12).contains(month)),
+        };
+        match (name, argument) {
+            ("ordinal", None) => Some(Self::Ordinal),
+            ("day_text", None) => Some(Self::DayText),
+            ("ordinal_text", None) => Some(Self::OrdinalText),
+            ("quarter", None) => Some(Self::Quarter),
+            ("iso_week", None) => Some(Self::IsoWeek),
+            ("fiscal_year", _) => start_month().map(Self::FiscalYear),
+            ("fiscal_quarter", _) => start_month().map(Self::FiscalQuarter),
+            ("fiscal_month", _) => start_month().map(Self::FiscalMonth),
+            _ => None,
+        }
+    }
+
+    fn render(self, date: NaiveDate, locale: Locale) -> String {
+        match self {
+            Self::Ordinal => ordinal(date.day(), locale),
+            Self::DayText => cardinal_text(date.day()),
+            Self::OrdinalText => ordinal_text(date.day()),
+            Self::Quarter => quarter(date.month()).to_string(),
+            Self::IsoWeek => {
+                let week = date.iso_week();
+                format!("{}-W{:02}", week.year(), week.week())
+            }
+            Self::FiscalYear(start) => fiscal_year(date, start).to_string(),
+            Self::FiscalQuarter(start) => quarter(fiscal_month(date.month(), start)).to_string(),
+            Self::FiscalMonth(start) => fiscal_month(date.month(), start).to_string(),
+        }
+    }
+}
+
+/// Expand the timeflow specifiers of `format` into a pattern understood by [chrono]
+///
+/// Localized names and extensions are replaced by their escaped value, standard specifiers
+/// are left untouched. Specifiers needing a date (resp. a time) are kept as-is when `date`
+/// (resp. `time`) is [None].
+pub(crate) fn expand(
+    format: &str,
+    locale: Locale,
+    date: Option<NaiveDate>,
+    time: Option<NaiveTime>,
+) -> Cow<'_, str> {
+    if locale == Locale::En && !format.contains("%{") {
+        return Cow::Borrowed(format);
+    }
+    let mut expanded = String::with_capacity(format.len());
+    let mut rest = format;
+    while let Some(index) = rest.find('%') {
+        expanded.push_str(&rest[..index]);
+        rest = &rest[index..];
+        let Some(specifier) = rest[1..].chars().next() else {
+            break;
+        };
+        let length = 1 + specifier.len_utf8();
+        let value = match specifier {
+            '{' => rest[2..].split_once('}').and_then(|(spec, _)| {
+                let extension = Extension::parse(spec)?;
+                let value = extension.render(date?, locale);
+                Some((value, spec.len() + 3))
+            }),
+            _ => localized_value(specifier, locale, date, time).map(|value| (value, length)),
+        };
+        match value {
+            Some((value, consumed)) => {
+                expanded.push_str(&value.replace('%', "%%"));
+                rest = &rest[consumed..];
+            }
+            None => {
+                expanded.push_str(&rest[..length]);
+                rest = &rest[length..];
+            }
+        }
+    }
+    expanded.push_str(rest);
+    Cow::Owned(expanded)
+}
+
+/// Quarter (1 to 4) of the month (1 to 12)
+pub(crate) fn quarter(month: u32) -> u32 {
+    (month - 1) / 3 + 1
+}
+
+/// Month (1 to 12) of the fiscal year starting in `start_month`
+fn fiscal_month(month: u32, start_month: u32) -> u32 {
+    (month + 12 - start_month) % 12 + 1
+}
+
+/// Fiscal year starting in `start_month`, named after the calendar year in which it ends
+fn fiscal_year(date: NaiveDate, start_month: u32) -> i32 {
+    if start_month > 1 && date.month() >= start_month {
+        date.year() + 1
+    } else {
+        date.year()
+    }
+}
+
+/// Number followed by the ordinal suffix of the `locale`
+fn ordinal(value: u32, locale: Locale) -> String {
+    match locale {
+        Locale::En => {
+            let suffix = match (value % 10, value % 100) {
+                (_, 11..=13) => "th",
+                (1, _) => "st",
+                (2, _) => "nd",
+                (3, _) => "rd",
+                _ => "th",
+            };
+            format!("{value}{suffix}")
+        }
+        Locale::Fr if value == 1 => "1er".to_string(),
+        Locale::Fr => value.to_string(),
+        Locale::De => format!("{value}."),
+        Locale::Es => format!("{value}.º"),
+    }
+}
+
+const ONES: [&str; 20] = [
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+
+const TENS: [&str; 10] = [
+    "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+/// English spelling of a number lower than 1000
+pub(crate) fn cardinal_text(value: u32) -> String {
+    match value {
+        0..20 => ONES[value as usize].to_string(),
+        20..100 if value.is_multiple_of(10) => TENS[value as usize / 10].to_string(),
+        20..100 => format!(
+            "{}-{}",
+            TENS[value as usize / 10],
+            ONES[value as usize % 10]
+        ),
+        100..1000 if value.is_multiple_of(100) => format!("{} hundred", ONES[value as usize / 100]),
+        100..1000 => format!(
+            "{} hundred {}",
+            ONES[value as usize / 100],
+            cardinal_text(value % 100)
+        ),
+        _ => value.to_string(),
+    }
+}
+
+/// English ordinal spelling of a number lower than 1000
+pub(crate) fn ordinal_text(value: u32) -> String {
+    let cardinal = cardinal_text(value);
+    let split = cardinal.rfind(['-', ' ']).map_or(0, |index| index + 1);
+    let (head, last) = cardinal.split_at(split);
+    let last = match last {
+        "one" => "first".to_string(),
+        "two" => "second".to_string(),
+        "three" => "third".to_string(),
+        "five" => "fifth".to_string(),
+        "eight" => "eighth".to_string(),
+        "nine" => "ninth".to_string(),
+        "twelve" => "twelfth".to_string(),
+        last if last.ends_with('y') => format!("{}ieth", &last[..last.len() - 1]),
+        last => format!("{last}th"),
+    };
+    format!("{head}{last}")
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+
+    fn invalid(pattern: &str, specifier: &str, position: usize, issue: FormatIssue) -> SpanError {
+        SpanError::InvalidFormat {
+            pattern: pattern.to_string(),
+            specifier: specifier.to_string(),
+            position,
+            issue,
+        }
+    }
+
+    #[test]
+    fn format_clone_is_shared() -> Result<(), SpanError> {
+        let format = Format::date("%d/%m/%Y")?;
+        let clone = format.clone();
+        assert!(Arc::ptr_eq(&format.0, &clone.0));
+        assert!(format.items(Locale::En).is_some());
+        Ok(())
+    }
+
+    #[test]
+    fn format_items_need_expansion() {
+        let format = Format::compile("%d %B %Y", FormatKind::Date);
+        assert!(format.items(Locale::En).is_some());
+        assert!(format.items(Locale::Fr).is_none());
+        let format = Format::compile("%{ordinal} %Y", FormatKind::Date);
+        assert!(format.items(Locale::En).is_none());
+    }
+
+    #[test]
+    fn format_parse_localized() {
+        let format = Format::compile("%d %B %Y", FormatKind::Date);
+        let parsed = format.parse_with("12 décembre 2024", Locale::Fr, Parsed::to_naive_date);
+        assert_eq!(parsed.ok(), NaiveDate::from_ymd_opt(2024, 12, 12));
+    }
+
+    #[test]
+    fn format_parse_error_position() {
+        let format = Format::compile("%Y-%m-%d %H:%M", FormatKind::DateTime);
+        let parsed = format.parse_with("2024-12-1x 10:00", Locale::En, |parsed| {
+            parsed.to_naive_datetime_with_offset(0)
+        });
+        assert!(matches!(
+            parsed,
+            Err(SpanError::ParseFromStr { position: 9, .. })
+        ));
+        let parsed = format.parse_with("2024-12-10 10:00 UTC", Locale::En, |parsed| {
+            parsed.to_naive_datetime_with_offset(0)
+        });
+        assert!(matches!(
+            parsed,
+            Err(SpanError::ParseFromStr { position: 16, .. })
+        ));
+    }
+
+    #[test]
+    fn format_join_and_split() -> Result<(), SpanError> {
+        let format = Format::join(&Format::date("%d/%m/%Y")?, &Format::time("%Hh%M")?);
+        assert_eq!(format.pattern(), "%d/%m/%Y %Hh%M");
+        assert_eq!(format.kind(), FormatKind::DateTime);
+
+        let (date, time) = Format::datetime("%A %{ordinal} %B, at %-I:%M %p")?
+            .split()
+            .unwrap();
+        assert_eq!(date.pattern(), "%A %{ordinal} %B");
+        assert_eq!(time.pattern(), "%-I:%M %p");
+        assert_eq!(
+            (date.kind(), time.kind()),
+            (FormatKind::Date, FormatKind::Time)
+        );
+
+        let (date, time) = Format::datetime("%H:%M on %d/%m/%Y")?.split().unwrap();
+        assert_eq!((date.pattern(), time.pattern()), ("%d/%m/%Y", "%H:%M"));
+        Ok(())
+    }
+
+    #[test]
+    fn format_split_none() -> Result<(), SpanError> {
+        assert_eq!(Format::datetime("%d %H %m")?.split(), None);
+        assert_eq!(Format::datetime("%c")?.split(), None);
+        assert_eq!(Format::datetime("%Y-%m-%d")?.split(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn format_valid_patterns() -> Result<(), SpanError> {
+        Format::date("%Y-%m-%d")?;
+        Format::date("%A %-d %B %Y, Q%{quarter} %{fiscal_year:4} 100%%")?;
+        Format::time("%H:%M:%S%.3f %p")?;
+        Format::time("%_H:%M:%S%.f")?;
+        Format::datetime("%Y-%m-%dT%H:%M:%S %{ordinal}")?;
+        Format::datetime("%c %s")?;
+        Ok(())
+    }
+
+    #[test]
+    fn format_not_allowed_for_kind() {
+        assert_eq!(
+            Format::date("%d/%m/%Y %H"),
+            Err(invalid(
+                "%d/%m/%Y %H",
+                "%H",
+                9,
+                FormatIssue::NotAllowed(FormatKind::Date)
+            ))
+        );
+        assert_eq!(
+            Format::time("%H:%M %{quarter}"),
+            Err(invalid(
+                "%H:%M %{quarter}",
+                "%{quarter}",
+                6,
+                FormatIssue::NotAllowed(FormatKind::Time)
+            ))
+        );
+        assert_eq!(
+            Format::datetime("%Y %:z"),
+            Err(invalid(
+                "%Y %:z",
+                "%:z",
+                3,
+                FormatIssue::NotAllowed(FormatKind::DateTime)
+            ))
+        );
+        assert_eq!(
+            Format::time("%-d"),
+            Err(invalid(
+                "%-d",
+                "%-d",
+                0,
+                FormatIssue::NotAllowed(FormatKind::Time)
+            ))
+        );
+    }
+
+    #[test]
+    fn format_unknown_specifier() {
+        assert_eq!(
+            Format::date("%Y-%Q"),
+            Err(invalid("%Y-%Q", "%Q", 3, FormatIssue::Unknown))
+        );
+        assert_eq!(
+            Format::date("%{year_text} %Y"),
+            Err(invalid(
+                "%{year_text} %Y",
+                "%{year_text}",
+                0,
+                FormatIssue::Unknown
+            ))
+        );
+    }
+
+    #[test]
+    fn format_unterminated_specifier() {
+        assert_eq!(
+            Format::date("%Y-%m-%"),
+            Err(invalid("%Y-%m-%", "%", 6, FormatIssue::Unterminated))
+        );
+        assert_eq!(
+            Format::date("%Y %{quarter"),
+            Err(invalid(
+                "%Y %{quarter",
+                "%{quarter",
+                3,
+                FormatIssue::Unterminated
+            ))
+        );
+    }
+
+    #[test]
+    fn format_byte_offset_after_multibyte() {
+        assert_eq!(
+            Format::date("été %Y %M"),
+            Err(invalid(
+                "été %Y %M",
+                "%M",
+                9,
+                FormatIssue::NotAllowed(FormatKind::Date)
+            ))
+        );
+    }
+
+    fn render(format: &str, locale: Locale, date: NaiveDate) -> String {
+        date.format(&expand(format, locale, Some(date), None))
+            .to_string()
+    }
+
+    #[test]
+    fn expand_without_extension_is_borrowed() {
+        let expanded = expand("%Y-%m-%d", Locale::En, None, None);
+        assert!(matches!(expanded, Cow::Borrowed("%Y-%m-%d")));
+    }
+
+    #[test]
+    fn ordinal_suffixes() {
+        let suffixes: Vec<String> = [1, 2, 3, 4, 11, 12, 13, 21, 22, 23, 31]
+            .into_iter()
+            .map(|day| ordinal(day, Locale::En))
+            .collect();
+        assert_eq!(
+            suffixes,
+            [
+                "1st", "2nd", "3rd", "4th", "11th", "12th", "13th", "21st", "22nd", "23rd", "31st"
+            ]
+        );
+        assert_eq!(ordinal(1, Locale::Fr), "1er");
+        assert_eq!(ordinal(2, Locale::Fr), "2");
+        assert_eq!(ordinal(3, Locale::De), "3.");
+    }
+
+    #[test]
+    fn spelled_numbers() {
+        assert_eq!(cardinal_text(7), "seven");
+        assert_eq!(cardinal_text(21), "twenty-one");
+        assert_eq!(cardinal_text(30), "thirty");
+        assert_eq!(cardinal_text(115), "one hundred fifteen");
+        assert_eq!(ordinal_text(1), "first");
+        assert_eq!(ordinal_text(12), "twelfth");
+        assert_eq!(ordinal_text(20), "twentieth");
+        assert_eq!(ordinal_text(21), "twenty-first");
+        assert_eq!(ordinal_text(28), "twenty-eighth");
+        assert_eq!(ordinal_text(300), "three hundredth");
+    }
+
+    #[test]
+    fn render_ordinal_day() {
+        let date = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+        assert_eq!(
+            render("the %{ordinal} of %B", Locale::En, date),
+            "the 1st of March"
+        );
+        assert_eq!(render("le %{ordinal} %B", Locale::Fr, date), "le 1er mars");
+    }
+
+    #[test]
+    fn render_spelled_day() {
+        let date = NaiveDate::from_ymd_opt(2024, 10, 21).unwrap();
+        assert_eq!(
+            render("%A, the %{ordinal_text}", Locale::En, date),
+            "Monday, the twenty-first"
+        );
+        assert_eq!(render("%{day_text}", Locale::En, date), "twenty-one");
+    }
+
+    #[test]
+    fn render_quarter_and_iso_week() {
+        let date = NaiveDate::from_ymd_opt(2024, 8, 15).unwrap();
+        assert_eq!(render("Q%{quarter} %Y", Locale::En, date), "Q3 2024");
+        let date = NaiveDate::from_ymd_opt(2021, 1, 3).unwrap();
+        assert_eq!(render("%{iso_week}", Locale::En, date), "2020-W53");
+    }
+
+    #[test]
+    fn render_fiscal_period() {
+        let date = NaiveDate::from_ymd_opt(2024, 11, 5).unwrap();
+        assert_eq!(
+            render(
+                "FY%{fiscal_year:10} Q%{fiscal_quarter:10} P%{fiscal_month:10}",
+                Locale::En,
+                date
+            ),
+            "FY2025 Q1 P2"
+        );
+        assert_eq!(
+            render("FY%{fiscal_year} Q%{fiscal_quarter}", Locale::En, date),
+            "FY2024 Q4"
+        );
+    }
+
+    #[test]
+    fn expand_escapes_and_keeps_unknown() {
+        let date = NaiveDate::from_ymd_opt(2024, 8, 15);
+        assert_eq!(expand("%%{quarter}", Locale::En, date, None), "%%{quarter}");
+        assert_eq!(expand("%{unknown}", Locale::En, date, None), "%{unknown}");
+        assert_eq!(
+            expand("%{fiscal_year:13}", Locale::En, date, None),
+            "%{fiscal_year:13}"
+        );
+        assert_eq!(expand("%{quarter}", Locale::En, None, None), "%{quarter}");
+    }
+}
@@ -0,0 +1,387 @@
+//! Calendar month grids
+//!
+//! A [MonthGrid] lays out a month in weeks of seven [Date] cells, starting on a configurable
+//! weekday, with the leading and trailing days of the adjacent months. It is rendered as text
+//! like the `cal` command.
+//!
+//! # Example
+//! ```rust,ignore
+//! let grid = MonthGrid::new(2023, 10)?;
+//! let weeks = grid.weeks()?;
+//! assert_eq!(weeks.len(), 6);
+//! assert_eq!(weeks[0].days()[0].date().to_string(), "2023-09-25");
+//! assert_eq!(weeks[0].week_number(), 39);
+//! println!("{grid}");
+//! //     October 2023
+//! // Mo Tu We Th Fr Sa Su
+//! //                    1
+//! //  2  3  4  5  6  7  8
+//! // ...
+//! ```
+
+use std::fmt::{Display, Write};
+
+use chrono::{Datelike, Days, NaiveDate, Weekday};
+use serde::Serialize;
+
+use crate::{
+    GetInner,
+    date::Date,
+    error::{DateError, ErrorContext, SpanError},
+    locale::{BASE_LOCALE, Locale},
+    span::{Span, Unit},
+};
+
+/// Width of a day in the text rendering
+const CELL_WIDTH: usize = 2;
+
+/// A day of a [MonthGrid]
+#[derive(Debug, PartialEq, Eq, Clone, Serialize)]
+pub struct GridDay {
+    date: Date,
+    in_month: bool,
+}
+
+impl GridDay {
+    /// Getter for the date
+    pub fn date(&self) -> &Date {
+        &self.date
+    }
+
+    /// Return `true` if the day is in the month of the grid, `false` for a day of an adjacent
+    /// month
+    pub fn in_month(&self) -> bool {
+        self.in_month
+    }
+}
+
+/// A week of a [MonthGrid], seven days from the first weekday of the grid
+#[derive(Debug, PartialEq, Eq, Clone, Serialize)]
+pub struct GridWeek {
+    week_number: u32,
+    days: Vec<GridDay>,
+}
+
+impl GridWeek {
+    /// ISO 8601 week number
+    ///
+    /// When the grid does not start on Monday, the week is numbered like the ISO week holding
+    /// most of its days, the one of its fourth day.
+    pub fn week_number(&self) -> u32 {
+        self.week_number
+    }
+
+    /// Getter for the seven days of the week
+    pub fn days(&self) -> &[GridDay] {
+        &self.days
+    }
+}
+
+/// Weeks of a month, for calendar views
+///
+/// Weeks start on Monday by default. The grid has only the weeks holding days of the month,
+/// four to six, unless [MonthGrid::fixed_weeks] asks for always six.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct MonthGrid {
+    first: NaiveDate,
+    first_weekday: Weekday,
+    fixed_weeks: bool,
+    week_numbers: bool,
+    locale: Locale,
+}
+
+impl MonthGrid {
+    /// Create the grid of `month` (1 to 12) of `year`, with the [Locale] in use
+    ///
+    /// # Errors
+    /// Return an Err(_) if the month does not exist
+    pub fn new(year: i32, month: u32) -> Result<Self, SpanError> {
+        let Some(first) = NaiveDate::from_ymd_opt(year, month, 1) else {
+            return Err(SpanError::InvalidDate(year, month, 1)).err_ctx(DateError);
+        };
+        Ok(Self {
+            first,
+            first_weekday: Weekday::Mon,
+            fixed_weeks: false,
+            week_numbers: false,
+            locale: BASE_LOCALE.get(),
+        })
+    }
+
+    /// Setter for the first day of the weeks
+    pub fn first_weekday(mut self, weekday: Weekday) -> Self {
+        self.first_weekday = weekday;
+        self
+    }
+
+    /// Always have six weeks, padding with days of the next month
+    pub fn fixed_weeks(mut self, fixed_weeks: bool) -> Self {
+        self.fixed_weeks = fixed_weeks;
+        self
+    }
+
+    /// Show the week numbers in the text rendering
+    pub fn week_numbers(mut self, week_numbers: bool) -> Self {
+        self.week_numbers = week_numbers;
+        self
+    }
+
+    /// Setter for the [Locale] of the days and of the text rendering
+    pub fn locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// Getter for the year
+    pub fn year(&self) -> i32 {
+        self.first.year()
+    }
+
+    /// Getter for the month (1 to 12)
+    pub fn month(&self) -> u32 {
+        self.first.month()
+    }
+
+    /// Weeks of the grid, from the week of the first day of the month
+    ///
+    /// # Errors
+    /// Return an Err(_) if a day of an adjacent month is out of the range of [Date], for the
+    /// first and last months of the calendar
+    pub fn weeks(&self) -> Result<Vec<GridWeek>, SpanError> {
+        let leading = self.leading();
+        let cells = self.rows() * 7;
+        let days = (0..cells)
+            .map(|cell| self.day(cell as i64 - leading as i64))
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(|| {
+                let value = match self.first.checked_sub_days(Days::new(leading.into())) {
+                    Some(_) => (cells - leading - 1).into(),
+                    None => -i64::from(leading),
+                };
+                SpanError::InvalidUpdate {
+                    input: self.first.to_string(),
+                    unit: Unit::Day,
+                    value,
+                }
+            })
+            .err_ctx(DateError)?;
+        Ok(days
+            .chunks(7)
+            .enumerate()
+            .map(|(row, days)| GridWeek {
+                week_number: self.week_number(row as u32),
+                days: days
+                    .iter()
+                    .map(|day| GridDay {
+                        date: Date::from(*day).locale(self.locale),
+                        in_month: day.month() == self.first.month(),
+                    })
+                    .collect(),
+            })
+            .collect())
+    }
+
+    /// Days of the previous month before the first day of the month
+    fn leading(&self) -> u32 {
+        self.first.weekday().days_since(self.first_weekday)
+    }
+
+    /// Number of weeks of the grid
+    fn rows(&self) -> u32 {
+        match self.fixed_weeks {
+            true => 6,
+            false => (self.leading() + self.days_in_month()).div_ceil(7),
+        }
+    }
+
+    /// Day `offset` days after the first day of the month, [None] if out of range
+    fn day(&self, offset: i64) -> Option<NaiveDate> {
+        match offset < 0 {
+            true => self
+                .first
+                .checked_sub_days(Days::new(offset.unsigned_abs())),
+            false => self
+                .first
+                .checked_add_days(Days::new(offset.unsigned_abs())),
+        }
+    }
+
+    /// ISO week of the fourth day of the week `row`, or of its closest day of the month when
+    /// out of range
+    fn week_number(&self, row: u32) -> u32 {
+        let offset = (row * 7 + 3) as i64 - self.leading() as i64;
+        let last = self.days_in_month() as i64 - 1;
+        self.day(offset)
+            .or_else(|| self.day(offset.clamp(0, last)))
+            .unwrap_or(self.first)
+            .iso_week()
+            .week()
+    }
+
+    fn days_in_month(&self) -> u32 {
+        self.first
+            .checked_add_months(chrono::Months::new(1))
+            .and_then(|next| next.pred_opt())
+            .map_or(31, |last| last.day())
+    }
+}
+
+impl Display for MonthGrid {
+    /// Render the grid like `cal`, days of the adjacent months being left blank
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let margin = match self.week_numbers {
+            true => CELL_WIDTH + 1,
+            false => 0,
+        };
+        let width = 7 * (CELL_WIDTH + 1) - 1;
+        let title = format!("{} {}", self.locale.month_name(self.month()), self.year());
+        let mut lines = vec![format!("{:margin$}{title:^width$}", "")];
+        let weekdays = std::iter::successors(Some(self.first_weekday), |day| Some(day.succ()))
+            .take(7)
+            .map(|weekday| {
+                let abbr = self.locale.weekday_abbr(weekday);
+                let abbr = abbr.chars().take(CELL_WIDTH).collect::<String>();
+                format!("{abbr:<CELL_WIDTH$}")
+            })
+            .collect::<Vec<_>>();
+        lines.push(format!("{:margin$}{}", "", weekdays.join(" ")));
+        let leading = self.leading() as i64;
+        let days = self.days_in_month() as i64;
+        for row in 0..self.rows() {
+            let mut line = String::new();
+            if self.week_numbers {
+                write!(line, "{:>CELL_WIDTH$} ", self.week_number(row))?;
+            }
+            let cells = (0..7)
+                .map(|column| match (row * 7 + column) as i64 - leading {
+                    day if (0..days).contains(&day) => format!("{:>CELL_WIDTH$}", day + 1),
+                    _ => " ".repeat(CELL_WIDTH),
+                })
+                .collect::<Vec<_>>();
+            line.push_str(&cells.join(" "));
+            lines.push(line);
+        }
+        let lines = lines.iter().map(|line| line.trim_end()).collect::<Vec<_>>();
+        write!(f, "{}", lines.join("\n"))
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+
+    #[test]
+    fn grid_weeks() -> Result<(), SpanError> {
+        let weeks = MonthGrid::new(2023, 10)?.weeks()?;
+        assert_eq!(weeks.len(), 6);
+        assert!(weeks.iter().all(|week| week.days().len() == 7));
+        let first = &weeks[0].days()[0];
+        assert_eq!(first.date().to_string(), "2023-09-25");
+        assert!(!first.in_month());
+        assert_eq!(weeks[0].days()[6].date().to_string(), "2023-10-01");
+        assert!(weeks[0].days()[6].in_month());
+        let last = &weeks[5].days()[6];
+        assert_eq!(last.date().to_string(), "2023-11-05");
+        assert_eq!(
+            weeks.iter().map(GridWeek::week_number).collect::<Vec<_>>(),
+            [39, 40, 41, 42, 43, 44]
+        );
+        // February 2021 starts on Monday and fits four weeks
+        assert_eq!(MonthGrid::new(2021, 2)?.weeks()?.len(), 4);
+        assert_eq!(MonthGrid::new(2021, 2)?.fixed_weeks(true).weeks()?.len(), 6);
+        Ok(())
+    }
+
+    #[test]
+    fn grid_first_weekday() -> Result<(), SpanError> {
+        let weeks = MonthGrid::new(2023, 10)?
+            .first_weekday(Weekday::Sun)
+            .weeks()?;
+        assert_eq!(weeks.len(), 5);
+        assert_eq!(weeks[0].days()[0].date().to_string(), "2023-10-01");
+        assert_eq!(weeks[4].days()[6].date().to_string(), "2023-11-04");
+        // Numbered like the ISO week of most of its days
+        assert_eq!(weeks[0].week_number(), 40);
+        // From Tuesday to Monday
+        let weeks = MonthGrid::new(2023, 10)?
+            .first_weekday(Weekday::Tue)
+            .weeks()?;
+        assert_eq!(weeks[0].days()[0].date().to_string(), "2023-09-26");
+        assert_eq!(weeks[0].week_number(), 39);
+        Ok(())
+    }
+
+    #[test]
+    fn grid_iso_week_numbers() -> Result<(), SpanError> {
+        // January 1st 2021 is in the week 53 of 2020
+        let weeks = MonthGrid::new(2021, 1)?.weeks()?;
+        assert_eq!(weeks[0].week_number(), 53);
+        assert_eq!(weeks[1].week_number(), 1);
+        let weeks = MonthGrid::new(2024, 12)?.weeks()?;
+        assert_eq!(weeks.last().map(GridWeek::week_number), Some(1));
+        Ok(())
+    }
+
+    #[test]
+    fn grid_render() -> Result<(), SpanError> {
+        let grid = MonthGrid::new(2023, 10)?.locale(Locale::En);
+        assert_eq!(
+            grid.to_string(),
+            [
+                "    October 2023",
+                "Mo Tu We Th Fr Sa Su",
+                "                   1",
+                " 2  3  4  5  6  7  8",
+                " 9 10 11 12 13 14 15",
+                "16 17 18 19 20 21 22",
+                "23 24 25 26 27 28 29",
+                "30 31",
+            ]
+            .join("\n")
+        );
+        let grid = MonthGrid::new(2023, 10)?
+            .locale(Locale::Fr)
+            .first_weekday(Weekday::Sun)
+            .week_numbers(true);
+        assert_eq!(
+            grid.to_string(),
+            [
+                "       octobre 2023",
+                "   di lu ma me je ve sa",
+                "40  1  2  3  4  5  6  7",
+                "41  8  9 10 11 12 13 14",
+                "42 15 16 17 18 19 20 21",
+                "43 22 23 24 25 26 27 28",
+                "44 29 30 31",
+            ]
+            .join("\n")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn grid_calendar_bounds() -> Result<(), SpanError> {
+        // The previous month of the first month is out of range
+        let min = NaiveDate::MIN;
+        let grid = MonthGrid::new(min.year(), 1)?.first_weekday(min.weekday().succ());
+        assert_eq!(grid.weeks().unwrap_err().code(), "invalid_update");
+        assert!(
+            grid.to_string()
+                .contains("\n                   1\n 2  3  4  5  6  7  8")
+        );
+        let weeks = grid.first_weekday(min.weekday()).weeks()?;
+        assert_eq!(weeks[0].days()[0].date().date(), min);
+        // The next month of the last month is out of range
+        let max = NaiveDate::MAX;
+        let grid = MonthGrid::new(max.year(), 12)?.fixed_weeks(true);
+        assert_eq!(grid.weeks().unwrap_err().code(), "invalid_update");
+        assert!(grid.to_string().contains("31"));
+        Ok(())
+    }
+
+    #[test]
+    fn grid_invalid_month() {
+        let err = MonthGrid::new(2023, 13).unwrap_err();
+        assert_eq!(err.code(), "invalid_date");
+    }
+}
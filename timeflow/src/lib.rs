@@ -3,53 +3,64 @@
 #[cfg(not(any(feature = "time", feature = "date", feature = "datetime")))]
 compile_error!("At least one feature must be enabled: 'time', 'date', or 'datetime'");
 
+pub mod arithmetic;
+#[cfg(feature = "datetime")]
+pub mod bucket;
 pub mod builder;
 #[cfg(feature = "date")]
+pub mod business;
+pub mod clock;
+pub mod context;
+#[cfg(feature = "date")]
 pub mod date;
 #[cfg(feature = "datetime")]
 pub mod datetime;
+#[cfg(feature = "date")]
+pub mod daycount;
+pub mod duration;
 pub mod error;
+pub mod format;
+#[cfg(feature = "date")]
+pub mod grid;
+pub mod locale;
+pub mod matcher;
+#[cfg(feature = "datetime")]
+pub mod opening;
+pub mod period;
 pub mod prelude;
+#[cfg(feature = "date")]
+pub mod schedule;
 pub mod span;
+pub mod stopwatch;
+#[cfg(feature = "testing")]
+pub mod testing;
 #[cfg(feature = "time")]
 pub mod time;
 pub mod timestamp;
-
+pub mod window;
 #[cfg(feature = "datetime")]
-use std::ops::Deref;
+pub mod working;
+
 use std::sync::{LazyLock, RwLock};
 
+pub use context::with_context;
+
 pub trait GetInner<T: std::fmt::Display> {
     fn get(&self) -> T;
 }
 
 pub(crate) type BaseFormat<T> = LazyLock<RwLock<T>>;
 
-#[cfg(any(feature = "date", feature = "time"))]
-impl GetInner<&'static str> for BaseFormat<&'static str> {
-    fn get(&self) -> &'static str {
-        &self.read().unwrap()
+impl GetInner<format::Format> for BaseFormat<format::Format> {
+    fn get(&self) -> format::Format {
+        let base = self.read().unwrap();
+        context::current_format(base.kind()).unwrap_or_else(|| base.clone())
     }
 }
 
-#[cfg(feature = "datetime")]
-impl GetInner<String> for BaseFormat<Option<&'static str>> {
-    fn get(&self) -> String {
-        match self.read().unwrap().deref() {
-            Some(format) => format.to_string(),
-            #[cfg(all(feature = "date", feature = "time"))]
-            None => format!(
-                "{} {}",
-                crate::date::BASE_DATE_FORMAT.get(),
-                crate::time::BASE_TIME_FORMAT.get()
-            ),
-            #[cfg(all(not(feature = "date"), feature = "time"))]
-            None => format!("%Y-%m-%d {}", BASE_TIME_FORMAT.get()),
-            #[cfg(all(feature = "date", not(feature = "time")))]
-            None => format!("{} %H:%M:%S", BASE_DATE_FORMAT.get()),
-            #[cfg(not(all(feature = "date", feature = "time")))]
-            None => "%Y-%m-%d %H:%M:%S".to_string(),
-        }
+impl GetInner<locale::Locale> for BaseFormat<locale::Locale> {
+    fn get(&self) -> locale::Locale {
+        context::current_locale().unwrap_or_else(|| *self.read().unwrap())
     }
 }
 
@@ -61,27 +72,25 @@ pub mod test {
     #[cfg(feature = "datetime")]
     #[test]
     fn log_error_datetime_parse() {
-        let datetime = DateTime::new(2023, 10, 9);
-        let _ = datetime.inspect_err(|e| {
-            assert_eq!(
-                e.to_string(),
-                "DateTime ➤  ParseFromStr: input contains invalid characters"
-            )
-        });
+        let datetime = DateTime::try_from(("2023-1x-09 12:00:00", "%Y-%m-%d %H:%M:%S"));
+        let err = datetime.unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "DateTime ➤  ParseFromStr: input contains invalid characters at byte 6 of \"2023-1x-09 12:00:00\" with format \"%Y-%m-%d %H:%M:%S\"\n    2023-1x-09 12:00:00\n          ^"
+        );
+        assert_eq!(err.code(), "parse_from_str");
     }
 
     #[cfg(feature = "datetime")]
     #[test]
     fn log_error_datetime_parse_timestamp() {
         let err = SpanError::DateTime(
-            Box::new(SpanError::ParseFromTimestamp(
-                "parse_from_timestamp error".to_string(),
-            )),
+            Box::new(SpanError::ParseFromTimestamp { value: i64::MAX }),
             DateTimeError,
         );
         assert_eq!(
             err.to_string(),
-            "DateTime ➤  ParseFromTimestamp: parse_from_timestamp error"
+            "DateTime ➤  ParseFromTimestamp: 9223372036854775807 is out of range"
         )
     }
 
@@ -89,38 +98,43 @@ pub mod test {
     #[test]
     fn log_error_datetime_invalid_update() {
         let err = SpanError::DateTime(
-            Box::new(SpanError::InvalidUpdate(
-                "Cannot add x Day to datetime error".to_string(),
-            )),
+            Box::new(SpanError::InvalidUpdate {
+                input: "2023-10-09 00:00:00".to_string(),
+                unit: Unit::Day,
+                value: 3,
+            }),
             DateTimeError,
         );
         assert_eq!(
             err.to_string(),
-            "DateTime ➤  InvalidUpdate: Cannot add x Day to datetime error"
+            "DateTime ➤  InvalidUpdate: Cannot Add/Remove 3 Day to/from 2023-10-09 00:00:00"
         )
     }
 
     #[cfg(feature = "date")]
     #[test]
     fn log_error_date_parse() {
-        let datetime = Date::new(2023, 10, 31);
-        let _ = datetime.inspect_err(|e| {
-            assert_eq!(e.to_string(), "Date ➤  ParseFromStr: input is out of range")
-        });
+        let err = Date::try_from(("2023-02-30", "%Y-%m-%d")).unwrap_err();
+        assert!(
+            err.to_string()
+                .starts_with("Date ➤  ParseFromStr: input is out of range at byte 10")
+        );
     }
 
     #[cfg(feature = "date")]
     #[test]
     fn log_error_date_invalid_update() {
         let err = SpanError::Date(
-            Box::new(SpanError::InvalidUpdate(
-                "Cannot add x Month to date error".to_string(),
-            )),
+            Box::new(SpanError::InvalidUpdate {
+                input: "2023-10-09".to_string(),
+                unit: Unit::Month,
+                value: 3,
+            }),
             DateError,
         );
         assert_eq!(
             err.to_string(),
-            "Date ➤  InvalidUpdate: Cannot add x Month to date error"
+            "Date ➤  InvalidUpdate: Cannot Add/Remove 3 Month to/from 2023-10-09"
         )
     }
 
@@ -128,14 +142,16 @@ pub mod test {
     #[test]
     fn log_error_time_invalid_update() {
         let err = SpanError::Time(
-            Box::new(SpanError::InvalidUpdate(
-                "Cannot add x Second to time error".to_string(),
-            )),
+            Box::new(SpanError::InvalidUpdate {
+                input: "23:00:00".to_string(),
+                unit: Unit::Second,
+                value: 3,
+            }),
             TimeError,
         );
         assert_eq!(
             err.to_string(),
-            "Time ➤  InvalidUpdate: Cannot add x Second to time error"
+            "Time ➤  InvalidUpdate: Cannot Add/Remove 3 Second to/from 23:00:00"
         )
     }
 
@@ -152,51 +168,139 @@ pub mod test {
         Ok(())
     }
 
-    /// This test is ignored because it changes the global state of the date, time, and datetime
-    /// Tests are running in parallel, and changing the global state might affect other tests
     #[cfg(all(feature = "date", feature = "time", feature = "datetime"))]
     #[test]
-    #[ignore]
+    fn builder_try_build_invalid_format() {
+        let result = SpanBuilder::builder()
+            .date_format("%d/%m/%Y")
+            .time_format("%H:%M:%S %d")
+            .try_build();
+        assert_eq!(
+            result,
+            Err(SpanError::InvalidFormat {
+                pattern: "%H:%M:%S %d".to_string(),
+                specifier: "%d".to_string(),
+                position: 9,
+                issue: error::FormatIssue::NotAllowed(format::FormatKind::Time),
+            })
+            .err_ctx(TimeError)
+        );
+        assert_eq!(
+            date::Date::new(2023, 1, 1).unwrap().to_string(),
+            "2023-01-01"
+        );
+    }
+
+    #[cfg(all(feature = "date", feature = "time", feature = "datetime"))]
+    #[test]
     fn builder_format_build() -> Result<(), SpanError> {
-        SpanBuilder::builder()
+        let context = SpanBuilder::builder()
             .datetime_format("%d/%m/%YT%H_%M_%S")
             .date_format("%d/%m/%Y")
             .time_format("%H_%M_%S")
-            .build();
-        datetime::DateTime::new(2023, 1, 1)?.with_time(12, 0, 0)?;
-        date::Date::new(2023, 1, 1)?;
-        time::Time::new(12, 0, 0)?;
-        Ok(())
+            .context();
+        with_context(&context, || {
+            let datetime = datetime::DateTime::new(2023, 1, 1)?.with_time(12, 0, 0)?;
+            assert_eq!(datetime.to_string(), "01/01/2023T12_00_00");
+            let date = date::Date::new(2023, 1, 1)?;
+            assert_eq!(date.to_string(), "01/01/2023");
+            let time = time::Time::new(12, 0, 0)?;
+            assert_eq!(time.to_string(), "12_00_00");
+            Ok(())
+        })
     }
 
     #[cfg(all(feature = "date", feature = "time", feature = "datetime"))]
     #[test]
-    #[ignore]
     fn builder_format_build_ignored() -> Result<(), SpanError> {
-        SpanBuilder::builder()
+        let context = SpanBuilder::builder()
             .datetime_format("%d/%m/%YT%H_%M_%S")
             .date_format("%d/%m/%Y")
             .time_format("%H_%M_%S")
-            .build();
-        datetime::DateTime::new(2023, 1, 1)?
-            .with_time(12, 0, 0)?
-            .format("%Y-%m-%d %H:%M:%S");
-        date::Date::new(2023, 1, 1)?.format("%Y-%m-%d");
-        time::Time::new(12, 0, 0)?.format("%H:%M:%S");
-        Ok(())
+            .context();
+        with_context(&context, || {
+            let datetime = datetime::DateTime::new(2023, 1, 1)?
+                .with_time(12, 0, 0)?
+                .format("%Y-%m-%d %H:%M:%S");
+            assert_eq!(datetime.to_string(), "2023-01-01 12:00:00");
+            let date = date::Date::new(2023, 1, 1)?.format("%Y-%m-%d");
+            assert_eq!(date.to_string(), "2023-01-01");
+            let time = time::Time::new(12, 0, 0)?.format("%H:%M:%S");
+            assert_eq!(time.to_string(), "12:00:00");
+            Ok(())
+        })
     }
 
     #[cfg(all(feature = "date", feature = "time", feature = "datetime"))]
     #[test]
-    #[ignore]
     fn builder_format_build_datetime_skipped() -> Result<(), SpanError> {
-        SpanBuilder::builder()
+        let context = SpanBuilder::builder()
             .date_format("%d/%m/%Y")
             .time_format("%H_%M_%S")
-            .build();
-        datetime::DateTime::new(2023, 1, 1)?.with_time(12, 0, 0)?;
-        date::Date::new(2023, 1, 1)?;
-        time::Time::new(12, 0, 0)?;
+            .context();
+        with_context(&context, || {
+            let datetime = datetime::DateTime::new(2023, 1, 1)?.with_time(12, 0, 0)?;
+            assert_eq!(datetime.to_string(), "01/01/2023 12_00_00");
+            date::Date::new(2023, 1, 1)?;
+            time::Time::new(12, 0, 0)?;
+            Ok(())
+        })
+    }
+
+    #[cfg(all(feature = "date", feature = "time", feature = "datetime"))]
+    #[test]
+    fn context_falls_back_to_global() -> Result<(), SpanError> {
+        let context = SpanBuilder::builder().locale(Locale::Fr).context();
+        let date = with_context(&context, || date::Date::new(2023, 3, 1))?;
+        assert_eq!(date.to_string(), "2023-03-01");
+        assert_eq!(date.get_locale(), Locale::Fr);
+        let date = date::Date::new(2023, 3, 1)?;
+        assert_eq!(date.get_locale(), Locale::En);
+        Ok(())
+    }
+
+    #[cfg(all(feature = "date", feature = "time", feature = "datetime"))]
+    #[test]
+    fn context_per_tenant() -> Result<(), SpanError> {
+        let tenants = [("%d/%m/%Y", "09/10/2023"), ("%m/%d/%Y", "10/09/2023")];
+        let handles = tenants.map(|(date_format, expected)| {
+            std::thread::spawn(move || {
+                let context = SpanBuilder::builder().date_format(date_format).context();
+                with_context(&context, || {
+                    let date = date::Date::new(2023, 10, 9)?;
+                    assert_eq!(date.to_string(), expected);
+                    Ok::<(), SpanError>(())
+                })
+            })
+        });
+        for handle in handles {
+            handle.join().unwrap()?;
+        }
+        Ok(())
+    }
+
+    #[cfg(all(feature = "date", feature = "time"))]
+    fn next_in<S: Span>(span: &S, unit: S::Unit) -> Result<String, SpanError> {
+        let next = span.next(unit)?;
+        Ok(format!(
+            "{span} -> {next} ({}s)",
+            next.elapsed(span).num_seconds()
+        ))
+    }
+
+    #[cfg(all(feature = "date", feature = "time"))]
+    #[test]
+    fn span_generic() -> Result<(), SpanError> {
+        let date = date::Date::from_components((2023, 12, 31))?;
+        assert_eq!(
+            next_in(&date, DateUnit::Day)?,
+            "2023-12-31 -> 2024-01-01 (86400s)"
+        );
+        let time = time::Time::from_components((23, 59, 0))?;
+        assert_eq!(
+            next_in(&time, TimeUnit::Minute)?,
+            "23:59:00 -> 00:00:00 (-86340s)"
+        );
         Ok(())
     }
 }
@@ -0,0 +1,504 @@
+use std::{
+    borrow::Cow,
+    sync::{LazyLock, RwLock},
+};
+
+use chrono::{Datelike, NaiveDate, NaiveTime, Timelike};
+use serde::{Deserialize, Serialize};
+
+use crate::{BaseFormat, GetInner, error::SpanError};
+
+pub(crate) static BASE_LOCALE: BaseFormat<Locale> = LazyLock::new(|| RwLock::new(Locale::En));
+
+pub(crate) fn base_locale() -> Locale {
+    BASE_LOCALE.get()
+}
+
+/// Language used to render and parse month names, weekday names and AM/PM markers
+///
+/// Only `%B`, `%b`/`%h`, `%A`, `%a`, `%p` and `%P` are localized, every other specifier is
+/// handled by [chrono::format::strftime].
+///
+/// All the locale data is bundled in the crate, nothing is loaded at runtime.
+#[derive(
+    Debug, Default, PartialEq, PartialOrd, Eq, Ord, Hash, Clone, Copy, Serialize, Deserialize,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum Locale {
+    #[default]
+    En,
+    Fr,
+    De,
+    Es,
+}
+
+/// Length of the locale-specific default patterns
+///
+/// # Example
+/// ```rust,ignore
+/// let date = Date::new(2024, 3, 1)?.locale(Locale::Fr).format(Locale::Fr.date_format(FormatLength::Full));
+/// assert_eq!(date.to_string(), "vendredi 1 mars 2024".to_string());
+/// ```
+#[derive(Debug, PartialEq, PartialOrd, Eq, Ord, Hash, Clone, Copy, Serialize, Deserialize)]
+pub enum FormatLength {
+    Short,
+    Medium,
+    Long,
+    Full,
+}
+
+struct LocaleData {
+    months: [&'static str; 12],
+    months_abbr: [&'static str; 12],
+    /// Starting on Monday
+    weekdays: [&'static str; 7],
+    /// Starting on Monday
+    weekdays_abbr: [&'static str; 7],
+    am_pm: [&'static str; 2],
+    /// Indexed by [FormatLength]
+    date_formats: [&'static str; 4],
+    /// Indexed by [FormatLength]
+    time_formats: [&'static str; 4],
+    /// Indexed by [FormatLength]
+    datetime_formats: [&'static str; 4],
+}
+
+const EN: LocaleData = LocaleData {
+    months: [
+        "January",
+        "February",
+        "March",
+        "April",
+        "May",
+        "June",
+        "July",
+        "August",
+        "September",
+        "October",
+        "November",
+        "December",
+    ],
+    months_abbr: [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ],
+    weekdays: [
+        "Monday",
+        "Tuesday",
+        "Wednesday",
+        "Thursday",
+        "Friday",
+        "Saturday",
+        "Sunday",
+    ],
+    weekdays_abbr: ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"],
+    am_pm: ["AM", "PM"],
+    date_formats: ["%m/%d/%y", "%b %-d, %Y", "%B %-d, %Y", "%A, %B %-d, %Y"],
+    time_formats: ["%-I:%M %p", "%-I:%M:%S %p", "%-I:%M:%S %p", "%-I:%M:%S %p"],
+    datetime_formats: [
+        "%m/%d/%y, %-I:%M %p",
+        "%b %-d, %Y, %-I:%M:%S %p",
+        "%B %-d, %Y at %-I:%M:%S %p",
+        "%A, %B %-d, %Y at %-I:%M:%S %p",
+    ],
+};
+
+const FR: LocaleData = LocaleData {
+    months: [
+        "janvier",
+        "février",
+        "mars",
+        "avril",
+        "mai",
+        "juin",
+        "juillet",
+        "août",
+        "septembre",
+        "octobre",
+        "novembre",
+        "décembre",
+    ],
+    months_abbr: [
+        "janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.", "nov.",
+        "déc.",
+    ],
+    weekdays: [
+        "lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi", "dimanche",
+    ],
+    weekdays_abbr: ["lun.", "mar.", "mer.", "jeu.", "ven.", "sam.", "dim."],
+    am_pm: ["AM", "PM"],
+    date_formats: ["%d/%m/%Y", "%-d %b %Y", "%-d %B %Y", "%A %-d %B %Y"],
+    time_formats: ["%H:%M", "%H:%M:%S", "%H:%M:%S", "%H:%M:%S"],
+    datetime_formats: [
+        "%d/%m/%Y %H:%M",
+        "%-d %b %Y, %H:%M:%S",
+        "%-d %B %Y à %H:%M:%S",
+        "%A %-d %B %Y à %H:%M:%S",
+    ],
+};
+
+const DE: LocaleData = LocaleData {
+    months: [
+        "Januar",
+        "Februar",
+        "März",
+        "April",
+        "Mai",
+        "Juni",
+        "Juli",
+        "August",
+        "September",
+        "Oktober",
+        "November",
+        "Dezember",
+    ],
+    months_abbr: [
+        "Jan.", "Feb.", "März", "Apr.", "Mai", "Juni", "Juli", "Aug.", "Sept.", "Okt.", "Nov.",
+        "Dez.",
+    ],
+    weekdays: [
+        "Montag",
+        "Dienstag",
+        "Mittwoch",
+        "Donnerstag",
+        "Freitag",
+        "Samstag",
+        "Sonntag",
+    ],
+    weekdays_abbr: ["Mo.", "Di.", "Mi.", "Do.", "Fr.", "Sa.", "So."],
+    am_pm: ["AM", "PM"],
+    date_formats: ["%d.%m.%y", "%d.%m.%Y", "%-d. %B %Y", "%A, %-d. %B %Y"],
+    time_formats: ["%H:%M", "%H:%M:%S", "%H:%M:%S", "%H:%M:%S"],
+    datetime_formats: [
+        "%d.%m.%y, %H:%M",
+        "%d.%m.%Y, %H:%M:%S",
+        "%-d. %B %Y um %H:%M:%S",
+        "%A, %-d. %B %Y um %H:%M:%S",
+    ],
+};
+
+const ES: LocaleData = LocaleData {
+    months: [
+        "enero",
+        "febrero",
+        "marzo",
+        "abril",
+        "mayo",
+        "junio",
+        "julio",
+        "agosto",
+        "septiembre",
+        "octubre",
+        "noviembre",
+        "diciembre",
+    ],
+    months_abbr: [
+        "ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept", "oct", "nov", "dic",
+    ],
+    weekdays: [
+        "lunes",
+        "martes",
+        "miércoles",
+        "jueves",
+        "viernes",
+        "sábado",
+        "domingo",
+    ],
+    weekdays_abbr: ["lun", "mar", "mié", "jue", "vie", "sáb", "dom"],
+    am_pm: ["a. m.", "p. m."],
+    date_formats: [
+        "%d/%m/%y",
+        "%-d %b %Y",
+        "%-d de %B de %Y",
+        "%A, %-d de %B de %Y",
+    ],
+    time_formats: ["%H:%M", "%H:%M:%S", "%H:%M:%S", "%H:%M:%S"],
+    datetime_formats: [
+        "%d/%m/%y, %H:%M",
+        "%-d %b %Y, %H:%M:%S",
+        "%-d de %B de %Y, %H:%M:%S",
+        "%A, %-d de %B de %Y, %H:%M:%S",
+    ],
+};
+
+impl Locale {
+    /// Every supported [Locale]
+    pub const ALL: [Locale; 4] = [Locale::En, Locale::Fr, Locale::De, Locale::Es];
+
+    fn data(self) -> &'static LocaleData {
+        match self {
+            Locale::En => &EN,
+            Locale::Fr => &FR,
+            Locale::De => &DE,
+            Locale::Es => &ES,
+        }
+    }
+
+    /// Full name of the month (1 to 12), or an empty string if `month` is out of range
+    pub fn month_name(self, month: u32) -> &'static str {
+        let index = month.wrapping_sub(1) as usize;
+        self.data().months.get(index).copied().unwrap_or_default()
+    }
+
+    /// Abbreviated name of the month (1 to 12), or an empty string if `month` is out of range
+    pub fn month_abbr(self, month: u32) -> &'static str {
+        let index = month.wrapping_sub(1) as usize;
+        self.data()
+            .months_abbr
+            .get(index)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Full name of the weekday
+    pub fn weekday_name(self, weekday: chrono::Weekday) -> &'static str {
+        self.data().weekdays[weekday.num_days_from_monday() as usize]
+    }
+
+    /// Abbreviated name of the weekday
+    pub fn weekday_abbr(self, weekday: chrono::Weekday) -> &'static str {
+        self.data().weekdays_abbr[weekday.num_days_from_monday() as usize]
+    }
+
+    /// AM/PM marker, `pm` is `true` for hours from 12 to 23
+    pub fn am_pm(self, pm: bool) -> &'static str {
+        self.data().am_pm[pm as usize]
+    }
+
+    /// Default date pattern of the [Locale] for the given [FormatLength]
+    pub fn date_format(self, length: FormatLength) -> &'static str {
+        self.data().date_formats[length as usize]
+    }
+
+    /// Default time pattern of the [Locale] for the given [FormatLength]
+    pub fn time_format(self, length: FormatLength) -> &'static str {
+        self.data().time_formats[length as usize]
+    }
+
+    /// Default datetime pattern of the [Locale] for the given [FormatLength]
+    pub fn datetime_format(self, length: FormatLength) -> &'static str {
+        self.data().datetime_formats[length as usize]
+    }
+}
+
+impl std::fmt::Display for Locale {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let code = match self {
+            Locale::En => "en",
+            Locale::Fr => "fr",
+            Locale::De => "de",
+            Locale::Es => "es",
+        };
+        write!(f, "{code}")
+    }
+}
+
+impl std::str::FromStr for Locale {
+    type Err = SpanError;
+
+    /// Parse a language code such as `"fr"`, `"fr-FR"` or `"de_CH"`
+    ///
+    /// Only the language part of the code is used.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let language = s.split(['-', '_']).next().unwrap_or_default();
+        match language.to_ascii_lowercase().as_str() {
+            "en" => Ok(Locale::En),
+            "fr" => Ok(Locale::Fr),
+            "de" => Ok(Locale::De),
+            "es" => Ok(Locale::Es),
+            _ => Err(SpanError::UnknownLocale(s.to_string())),
+        }
+    }
+}
+
+/// Localized specifiers of a strftime pattern
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Localized {
+    Month,
+    MonthAbbr,
+    Weekday,
+    WeekdayAbbr,
+    AmPm,
+    AmPmLower,
+}
+
+impl Localized {
+    fn from_specifier(c: char) -> Option<Self> {
+        match c {
+            'B' => Some(Self::Month),
+            'b' | 'h' => Some(Self::MonthAbbr),
+            'A' => Some(Self::Weekday),
+            'a' => Some(Self::WeekdayAbbr),
+            'p' => Some(Self::AmPm),
+            'P' => Some(Self::AmPmLower),
+            _ => None,
+        }
+    }
+
+    /// Localized names paired with their english counterpart understood by [chrono]
+    fn names(self, locale: Locale) -> Vec<(String, &'static str)> {
+        let (local, english): (&[&'static str], &[&'static str]) = match self {
+            Self::Month => (&locale.data().months, &EN.months),
+            Self::MonthAbbr => (&locale.data().months_abbr, &EN.months_abbr),
+            Self::Weekday => (&locale.data().weekdays, &EN.weekdays),
+            Self::WeekdayAbbr => (&locale.data().weekdays_abbr, &EN.weekdays_abbr),
+            Self::AmPm | Self::AmPmLower => (&locale.data().am_pm, &EN.am_pm),
+        };
+        let mut names: Vec<(String, &'static str)> = local
+            .iter()
+            .map(|name| name.to_lowercase())
+            .zip(english.iter().copied())
+            .collect();
+        names.sort_by_key(|(name, _)| std::cmp::Reverse(name.len()));
+        names
+    }
+}
+
+/// Return if the `specifier` is rendered differently depending on the [Locale]
+pub(crate) fn is_localized(specifier: char) -> bool {
+    Localized::from_specifier(specifier).is_some()
+}
+
+/// Value of the localized `specifier` in `locale`
+///
+/// Return [None] when [chrono] renders the specifier by itself: for [Locale::En], for
+/// non-localized specifiers, or when the `date` (resp. `time`) needed by the specifier is missing.
+pub(crate) fn localized_value(
+    specifier: char,
+    locale: Locale,
+    date: Option<NaiveDate>,
+    time: Option<NaiveTime>,
+) -> Option<String> {
+    if locale == Locale::En {
+        return None;
+    }
+    match (Localized::from_specifier(specifier)?, date, time) {
+        (Localized::Month, Some(date), _) => Some(locale.month_name(date.month()).to_string()),
+        (Localized::MonthAbbr, Some(date), _) => Some(locale.month_abbr(date.month()).to_string()),
+        (Localized::Weekday, Some(date), _) => {
+            Some(locale.weekday_name(date.weekday()).to_string())
+        }
+        (Localized::WeekdayAbbr, Some(date), _) => {
+            Some(locale.weekday_abbr(date.weekday()).to_string())
+        }
+        (Localized::AmPm, _, Some(time)) => Some(locale.am_pm(time.hour12().0).to_string()),
+        (Localized::AmPmLower, _, Some(time)) => Some(locale.am_pm(time.hour12().0).to_lowercase()),
+        _ => None,
+    }
+}
+
+/// Translate the localized names of `input` to english so that [chrono] can parse it with `format`
+///
+/// Names are matched case-insensitively, in the order their specifiers appear in `format`.
+pub(crate) fn delocalize<'a>(input: &'a str, format: &str, locale: Locale) -> Cow<'a, str> {
+    if locale == Locale::En {
+        return Cow::Borrowed(input);
+    }
+    let mut expected = Vec::new();
+    let mut chars = format.chars();
+    while let Some(c) = chars.next() {
+        if c == '%'
+            && let Some(specifier) = chars.next()
+            && let Some(localized) = Localized::from_specifier(specifier)
+        {
+            expected.push(localized.names(locale));
+        }
+    }
+    if expected.is_empty() {
+        return Cow::Borrowed(input);
+    }
+
+    let mut expected = expected.into_iter().peekable();
+    let mut delocalized = String::with_capacity(input.len());
+    let mut position = 0;
+    while position < input.len() {
+        let matched = expected.peek().and_then(|names| {
+            names.iter().find(|(name, _)| {
+                input
+                    .get(position..position + name.len())
+                    .is_some_and(|candidate| candidate.to_lowercase() == *name)
+            })
+        });
+        if let Some((name, english)) = matched {
+            delocalized.push_str(english);
+            position += name.len();
+            expected.next();
+            continue;
+        }
+        let Some(c) = input[position..].chars().next() else {
+            break;
+        };
+        delocalized.push(c);
+        position += c.len_utf8();
+    }
+    Cow::Owned(delocalized)
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+
+    #[test]
+    fn locale_from_str() -> Result<(), SpanError> {
+        assert_eq!("fr".parse::<Locale>()?, Locale::Fr);
+        assert_eq!("de-CH".parse::<Locale>()?, Locale::De);
+        assert_eq!("ES_es".parse::<Locale>()?, Locale::Es);
+        assert_eq!(
+            "it".parse::<Locale>(),
+            Err(SpanError::UnknownLocale("it".to_string()))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn localized_month_and_weekday() {
+        let date = NaiveDate::from_ymd_opt(2024, 3, 1);
+        let value = localized_value('A', Locale::Fr, date, None);
+        assert_eq!(value, Some("vendredi".to_string()));
+        let value = localized_value('b', Locale::De, date, None);
+        assert_eq!(value, Some("März".to_string()));
+        assert_eq!(localized_value('B', Locale::En, date, None), None);
+        assert_eq!(localized_value('d', Locale::Fr, date, None), None);
+    }
+
+    #[test]
+    fn localized_am_pm() {
+        let time = NaiveTime::from_hms_opt(15, 0, 0);
+        let value = localized_value('p', Locale::Es, None, time);
+        assert_eq!(value, Some("p. m.".to_string()));
+    }
+
+    #[test]
+    fn localized_without_date() {
+        let time = NaiveTime::from_hms_opt(3, 0, 0);
+        assert_eq!(localized_value('B', Locale::Fr, None, time), None);
+    }
+
+    #[test]
+    fn delocalize_in_format_order() {
+        let delocalized = delocalize("mar 5 mar 2024", "%a %-d %b %Y", Locale::Es);
+        assert_eq!(delocalized, "Tue 5 Mar 2024");
+        let delocalized = delocalize("Vendredi 1 MARS 2024", "%A %-d %B %Y", Locale::Fr);
+        assert_eq!(delocalized, "Friday 1 March 2024");
+    }
+
+    #[test]
+    fn default_formats_are_parsable() {
+        let date = NaiveDate::from_ymd_opt(2024, 12, 24).unwrap();
+        for locale in Locale::ALL {
+            for length in [
+                FormatLength::Short,
+                FormatLength::Medium,
+                FormatLength::Long,
+                FormatLength::Full,
+            ] {
+                let format = locale.date_format(length);
+                let rendered = date
+                    .format(&crate::format::expand(format, locale, Some(date), None))
+                    .to_string();
+                let parsed =
+                    NaiveDate::parse_from_str(&delocalize(&rendered, format, locale), format);
+                assert_eq!(parsed, Ok(date), "{locale} {length:?} {rendered}");
+            }
+        }
+    }
+}
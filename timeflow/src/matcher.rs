@@ -0,0 +1,414 @@
+//! Composable predicates on dates and times
+//!
+//! A [Matcher] checks fields of a [Date](crate::date::Date), a [Time](crate::time::Time) or a
+//! [DateTime](crate::datetime::DateTime) against values, ranges and sets, and combines the
+//! checks with [Matcher::and], [Matcher::or] and `!`. A check on a field the value does not
+//! have, such as the hour of a [Date](crate::date::Date), never matches, and neither does its
+//! negation.
+//!
+//! Matchers are serializable, so rules can be written in a config file:
+//! ```json
+//! {"and": [
+//!     {"weekday": ["Mon", "Tue", "Wed", "Thu", "Fri"]},
+//!     {"between": {"start": "09:00:00", "end": "17:30:00"}},
+//!     {"is": {"field": "quarter", "value": 4}}
+//! ]}
+//! ```
+//!
+//! # Example
+//! ```rust,ignore
+//! let office_hours = Matcher::weekdays([Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri])
+//!     .and(Matcher::between(NaiveTime::from_hms_opt(9, 0, 0).unwrap(), NaiveTime::from_hms_opt(17, 30, 0).unwrap()))
+//!     .and(Matcher::quarter(4));
+//! assert!(office_hours.matches(&DateTime::new(2023, 10, 9)?.with_time(10, 0, 0)?));
+//! ```
+
+use std::ops::{Not, RangeInclusive};
+
+use chrono::{NaiveTime, Weekday};
+use serde::{Deserialize, Serialize};
+
+use crate::window::TimeWindow;
+
+/// Field of a date or a time read by a [Matcher]
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Field {
+    Year,
+    /// Month, from 1 to 12
+    Month,
+    /// Day of the month, from 1 to 31
+    Day,
+    Hour,
+    Minute,
+    Second,
+    /// Day of the week, from 1 (Monday) to 7 (Sunday)
+    Weekday,
+    /// Quarter of the year, from 1 to 4
+    Quarter,
+    /// Day of the year, from 1 to 366
+    DayOfYear,
+    /// ISO 8601 week number, from 1 to 53
+    IsoWeek,
+}
+
+/// Value a [Matcher] can be evaluated against
+pub trait Matchable {
+    /// Value of `field`, [None] if the value has no such field
+    fn field(&self, field: Field) -> Option<i64>;
+    /// Time of the day, [None] for a value without time
+    fn time_of_day(&self) -> Option<NaiveTime>;
+}
+
+/// Predicate on the fields of a [Date](crate::date::Date), a [Time](crate::time::Time) or a
+/// [DateTime](crate::datetime::DateTime)
+///
+/// See the [module documentation](crate::matcher) for the serialized form.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Matcher {
+    /// The field equals `value`
+    Is { field: Field, value: i64 },
+    /// The field is between `start` and `end`, both included
+    Range { field: Field, start: i64, end: i64 },
+    /// The field is one of `values`
+    In { field: Field, values: Vec<i64> },
+    /// The day of the week is one of the given days
+    Weekday(Vec<Weekday>),
+    /// The time of the day is in the [TimeWindow] from `start` to `end`
+    Between { start: NaiveTime, end: NaiveTime },
+    /// Every matcher matches, always `true` when empty
+    And(Vec<Matcher>),
+    /// At least one matcher matches, always `false` when empty
+    Or(Vec<Matcher>),
+    /// The matcher does not match
+    Not(Box<Matcher>),
+}
+
+impl Matcher {
+    /// Match when `field` equals `value`
+    pub fn is(field: Field, value: i64) -> Self {
+        Self::Is { field, value }
+    }
+
+    /// Match when `field` is in `range`
+    pub fn range(field: Field, range: RangeInclusive<i64>) -> Self {
+        Self::Range {
+            field,
+            start: *range.start(),
+            end: *range.end(),
+        }
+    }
+
+    /// Match when `field` is one of `values`
+    pub fn one_of(field: Field, values: impl IntoIterator<Item = i64>) -> Self {
+        Self::In {
+            field,
+            values: values.into_iter().collect(),
+        }
+    }
+
+    /// Match when the day of the week is one of `weekdays`
+    pub fn weekdays(weekdays: impl IntoIterator<Item = Weekday>) -> Self {
+        Self::Weekday(weekdays.into_iter().collect())
+    }
+
+    /// Match during the given quarter of the year, from 1 to 4
+    pub fn quarter(quarter: u32) -> Self {
+        Self::is(Field::Quarter, quarter.into())
+    }
+
+    /// Match when the time of the day is at or after `start` and before `end`
+    pub fn between(start: NaiveTime, end: NaiveTime) -> Self {
+        Self::Between { start, end }
+    }
+
+    /// Match when both `self` and `other` match
+    pub fn and(self, other: Matcher) -> Self {
+        match self {
+            Self::And(mut matchers) => {
+                matchers.push(other);
+                Self::And(matchers)
+            }
+            matcher => Self::And(vec![matcher, other]),
+        }
+    }
+
+    /// Match when `self` or `other` matches
+    pub fn or(self, other: Matcher) -> Self {
+        match self {
+            Self::Or(mut matchers) => {
+                matchers.push(other);
+                Self::Or(matchers)
+            }
+            matcher => Self::Or(vec![matcher, other]),
+        }
+    }
+
+    /// Evaluate the matcher against `value`
+    ///
+    /// A check on a field `value` does not have never matches, even under `!`: see
+    /// [Matcher::evaluate].
+    pub fn matches(&self, value: &impl Matchable) -> bool {
+        self.evaluate(value) == Some(true)
+    }
+
+    /// Evaluate the matcher against `value`, [None] when it reads a field `value` does not have
+    ///
+    /// [None] goes through [Matcher::Not], so `!Matcher::is(Field::Hour, 10)` does not match a
+    /// [Date](crate::date::Date). [Matcher::And] is `Some(false)` as soon as one matcher is,
+    /// and [Matcher::Or] is `Some(true)` as soon as one matcher is, whatever the others are.
+    pub fn evaluate(&self, value: &impl Matchable) -> Option<bool> {
+        match self {
+            Self::Is {
+                field,
+                value: expected,
+            } => value.field(*field).map(|field| field == *expected),
+            Self::Range { field, start, end } => value
+                .field(*field)
+                .map(|field| (*start..=*end).contains(&field)),
+            Self::In { field, values } => value.field(*field).map(|field| values.contains(&field)),
+            Self::Weekday(weekdays) => value.field(Field::Weekday).map(|weekday| {
+                weekdays
+                    .iter()
+                    .any(|day| day.number_from_monday() as i64 == weekday)
+            }),
+            Self::Between { start, end } => value
+                .time_of_day()
+                .map(|time| TimeWindow::new(*start, *end).contains_time(time)),
+            Self::And(matchers) => {
+                let results: Vec<_> = matchers
+                    .iter()
+                    .map(|matcher| matcher.evaluate(value))
+                    .collect();
+                match results.contains(&Some(false)) {
+                    true => Some(false),
+                    false => results
+                        .into_iter()
+                        .collect::<Option<Vec<_>>>()
+                        .map(|_| true),
+                }
+            }
+            Self::Or(matchers) => {
+                let results: Vec<_> = matchers
+                    .iter()
+                    .map(|matcher| matcher.evaluate(value))
+                    .collect();
+                match results.contains(&Some(true)) {
+                    true => Some(true),
+                    false => results
+                        .into_iter()
+                        .collect::<Option<Vec<_>>>()
+                        .map(|_| false),
+                }
+            }
+            Self::Not(matcher) => matcher.evaluate(value).map(|matches| !matches),
+        }
+    }
+}
+
+impl Not for Matcher {
+    type Output = Self;
+    /// Match when `self` does not match
+    fn not(self) -> Self::Output {
+        match self {
+            Self::Not(matcher) => *matcher,
+            matcher => Self::Not(Box::new(matcher)),
+        }
+    }
+}
+
+/// Value of the date `field` of `date`, [None] for time fields
+#[cfg(any(feature = "date", feature = "datetime"))]
+fn date_field(date: chrono::NaiveDate, field: Field) -> Option<i64> {
+    use chrono::Datelike;
+
+    Some(match field {
+        Field::Year => date.year().into(),
+        Field::Month => date.month().into(),
+        Field::Day => date.day().into(),
+        Field::Weekday => date.weekday().number_from_monday().into(),
+        Field::Quarter => crate::format::quarter(date.month()).into(),
+        Field::DayOfYear => date.ordinal().into(),
+        Field::IsoWeek => date.iso_week().week().into(),
+        Field::Hour | Field::Minute | Field::Second => return None,
+    })
+}
+
+/// Value of the time `field` of `time`, [None] for date fields
+#[cfg(any(feature = "time", feature = "datetime"))]
+fn time_field(time: NaiveTime, field: Field) -> Option<i64> {
+    use chrono::Timelike;
+
+    match field {
+        Field::Hour => Some(time.hour().into()),
+        Field::Minute => Some(time.minute().into()),
+        Field::Second => Some(time.second().into()),
+        _ => None,
+    }
+}
+
+#[cfg(feature = "date")]
+impl Matchable for crate::date::Date {
+    fn field(&self, field: Field) -> Option<i64> {
+        date_field(self.date(), field)
+    }
+
+    fn time_of_day(&self) -> Option<NaiveTime> {
+        None
+    }
+}
+
+#[cfg(feature = "time")]
+impl Matchable for crate::time::Time {
+    fn field(&self, field: Field) -> Option<i64> {
+        time_field(self.time(), field)
+    }
+
+    fn time_of_day(&self) -> Option<NaiveTime> {
+        Some(self.time())
+    }
+}
+
+#[cfg(feature = "datetime")]
+impl Matchable for crate::datetime::DateTime {
+    fn field(&self, field: Field) -> Option<i64> {
+        let datetime = self.datetime();
+        date_field(datetime.date(), field).or_else(|| time_field(datetime.time(), field))
+    }
+
+    fn time_of_day(&self) -> Option<NaiveTime> {
+        Some(self.datetime().time())
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+
+    const WORKING_DAYS: [Weekday; 5] = [
+        Weekday::Mon,
+        Weekday::Tue,
+        Weekday::Wed,
+        Weekday::Thu,
+        Weekday::Fri,
+    ];
+
+    #[test]
+    fn matcher_combinators() {
+        let matcher = Matcher::quarter(4)
+            .and(Matcher::weekdays(WORKING_DAYS))
+            .and(Matcher::between(
+                "09:00:00".parse().unwrap(),
+                "17:30:00".parse().unwrap(),
+            ));
+        let Matcher::And(matchers) = &matcher else {
+            panic!("expected a flat And, got {matcher:?}");
+        };
+        assert_eq!(matchers.len(), 3);
+        assert_eq!(!!matcher.clone(), matcher);
+    }
+
+    #[test]
+    fn matcher_deserialize() -> Result<(), serde_json::Error> {
+        let matcher: Matcher = serde_json::from_str(
+            r#"{"and": [
+                {"weekday": ["Mon", "Tue", "Wed", "Thu", "Fri"]},
+                {"between": {"start": "09:00:00", "end": "17:30:00"}},
+                {"not": {"in": {"field": "month", "values": [8, 12]}}},
+                {"range": {"field": "quarter", "start": 3, "end": 4}}
+            ]}"#,
+        )?;
+        let expected = Matcher::weekdays(WORKING_DAYS)
+            .and(Matcher::between(
+                "09:00:00".parse().unwrap(),
+                "17:30:00".parse().unwrap(),
+            ))
+            .and(!Matcher::one_of(Field::Month, [8, 12]))
+            .and(Matcher::range(Field::Quarter, 3..=4));
+        assert_eq!(matcher, expected);
+        let json = serde_json::to_string(&expected)?;
+        assert_eq!(serde_json::from_str::<Matcher>(&json)?, expected);
+        Ok(())
+    }
+
+    #[cfg(feature = "datetime")]
+    #[test]
+    fn matcher_datetime() -> Result<(), crate::error::SpanError> {
+        use crate::datetime::DateTime;
+
+        let matcher = Matcher::weekdays(WORKING_DAYS)
+            .and(Matcher::between(
+                "09:00:00".parse().unwrap(),
+                "17:30:00".parse().unwrap(),
+            ))
+            .and(Matcher::quarter(4));
+        // Monday
+        let datetime = DateTime::new(2023, 10, 9)?;
+        assert!(matcher.matches(&datetime.clone().with_time(9, 0, 0)?));
+        assert!(matcher.matches(&datetime.clone().with_time(17, 29, 59)?));
+        assert!(!matcher.matches(&datetime.clone().with_time(17, 30, 0)?));
+        // Saturday
+        let datetime = DateTime::new(2023, 10, 14)?.with_time(10, 0, 0)?;
+        assert!(!matcher.matches(&datetime));
+        // Q3
+        let datetime = DateTime::new(2023, 9, 11)?.with_time(10, 0, 0)?;
+        assert!(!matcher.matches(&datetime));
+        assert!((!matcher).matches(&datetime));
+        Ok(())
+    }
+
+    #[cfg(feature = "datetime")]
+    #[test]
+    fn matcher_fields() -> Result<(), crate::error::SpanError> {
+        use crate::datetime::DateTime;
+
+        let datetime = DateTime::new(2024, 12, 30)?.with_time(23, 5, 0)?;
+        assert!(Matcher::is(Field::IsoWeek, 1).matches(&datetime));
+        assert!(Matcher::is(Field::DayOfYear, 365).matches(&datetime));
+        assert!(Matcher::range(Field::Minute, 0..=5).matches(&datetime));
+        assert!(
+            Matcher::between("22:00:00".parse().unwrap(), "06:00:00".parse().unwrap())
+                .matches(&datetime)
+        );
+        assert!(Matcher::Or(Vec::new()).matches(&datetime).not());
+        assert!(Matcher::And(Vec::new()).matches(&datetime));
+        Ok(())
+    }
+
+    #[cfg(all(feature = "date", feature = "time"))]
+    #[test]
+    fn matcher_missing_fields() -> Result<(), crate::error::SpanError> {
+        use crate::{date::Date, time::Time};
+
+        let date = Date::new(2023, 10, 9)?;
+        let time = Time::new(10, 0, 0)?;
+        let hour = Matcher::is(Field::Hour, 10);
+        let monday = Matcher::weekdays([Weekday::Mon]);
+        assert!(!hour.matches(&date));
+        assert!(hour.matches(&time));
+        assert!(monday.matches(&date));
+        assert!(!monday.matches(&time));
+        let morning = Matcher::between("09:00:00".parse().unwrap(), "11:00:00".parse().unwrap());
+        assert!(!morning.matches(&date));
+        // Missing fields go through `!`
+        assert_eq!(hour.evaluate(&date), None);
+        assert!(!(!hour.clone()).matches(&date));
+        assert!(!(!morning).matches(&date));
+        assert!(!(!monday.clone()).matches(&time));
+        // Unless the other side decides
+        assert!(hour.clone().or(monday.clone()).matches(&date));
+        assert!(!hour.clone().and(monday.clone()).matches(&date));
+        let tuesday = Matcher::weekdays([Weekday::Tue]);
+        assert_eq!(
+            hour.clone().and(tuesday.clone()).evaluate(&date),
+            Some(false)
+        );
+        assert!((!hour.and(tuesday)).matches(&date));
+        assert_eq!(
+            (!monday.or(Matcher::is(Field::Hour, 9))).evaluate(&time),
+            None
+        );
+        Ok(())
+    }
+}
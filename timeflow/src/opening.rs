@@ -0,0 +1,450 @@
+//! Opening hours with dated exceptions
+//!
+//! [OpeningHours] combine a [WeeklySchedule] with exceptions replacing the windows starting on
+//! given dates, such as closures on holidays. They can be parsed from a subset of the
+//! OpenStreetMap [`opening_hours`](https://wiki.openstreetmap.org/wiki/Key:opening_hours)
+//! syntax:
+//! - rules separated by `;`, a later rule replacing the windows of the days it selects
+//! - `24/7`
+//! - weekday selectors such as `Mo-Fr`, `Sa,Su` or `Fr-Mo`
+//! - date selectors such as `2023 Dec 25` or `2023 Dec 24-26`
+//! - windows such as `08:00-12:00,13:00-18:00`, `22:00-02:00` or `18:00-24:00`
+//! - `off` or `closed`, and a selector without windows for a whole day
+//!
+//! # Example
+//! ```rust,ignore
+//! let hours = OpeningHours::from_str("Mo-Fr 09:00-12:00,14:00-18:00; Sa 10:00-13:00; 2023 Dec 25 off")?;
+//! assert!(hours.is_open(&DateTime::build("2023-10-09 10:00:00")?));
+//! assert_eq!(hours.next_open_after(&DateTime::build("2023-10-09 12:30:00")?).unwrap().to_string(), "2023-10-09 14:00:00");
+//! ```
+
+use std::{collections::BTreeMap, str::FromStr};
+
+use chrono::{Days, Month, NaiveDate, NaiveDateTime, NaiveTime, TimeDelta, Weekday};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    datetime::DateTime,
+    error::SpanError,
+    window::{TimeWindow, WeeklySchedule},
+};
+
+/// Weekly opening hours with exceptions on given dates
+///
+/// The windows of an exception replace the windows of the schedule starting on its date, no
+/// window meaning the date is closed. A window of the day before wrapping past midnight still
+/// opens the start of an exception date.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OpeningHours {
+    weekly: WeeklySchedule,
+    exceptions: BTreeMap<NaiveDate, Vec<TimeWindow>>,
+}
+
+impl OpeningHours {
+    /// Create opening hours following `weekly` without any exception
+    pub fn new(weekly: WeeklySchedule) -> Self {
+        Self {
+            weekly,
+            exceptions: BTreeMap::new(),
+        }
+    }
+
+    /// Add `window` to the exception on `date`, replacing the weekly windows of that day
+    pub fn exception(mut self, date: NaiveDate, window: TimeWindow) -> Self {
+        self.exceptions.entry(date).or_default().push(window);
+        self
+    }
+
+    /// Close on `date`, dropping the windows of any exception on that day
+    pub fn closed_on(mut self, date: NaiveDate) -> Self {
+        self.exceptions.insert(date, Vec::new());
+        self
+    }
+
+    /// Getter for the weekly schedule
+    pub fn weekly(&self) -> &WeeklySchedule {
+        &self.weekly
+    }
+
+    /// Windows starting on `date`, from its exception if any or else from the weekly schedule
+    pub fn windows_on(&self, date: NaiveDate) -> &[TimeWindow] {
+        match self.exceptions.get(&date) {
+            Some(windows) => windows,
+            None => self.weekly.windows(chrono::Datelike::weekday(&date)),
+        }
+    }
+
+    /// Return `true` if open at `datetime`
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let hours = OpeningHours::from_str("Mo-Fr 09:00-18:00; 2023 Oct 09 off")?;
+    /// assert!(!hours.is_open(&DateTime::build("2023-10-09 10:00:00")?));
+    /// assert!(hours.is_open(&DateTime::build("2023-10-10 10:00:00")?));
+    /// ```
+    pub fn is_open(&self, datetime: &DateTime) -> bool {
+        let naive = datetime.datetime();
+        self.intervals(naive.date(), naive.date())
+            .iter()
+            .any(|(start, end)| *start <= naive && naive < *end)
+    }
+
+    /// First opening strictly after `datetime`, keeping its format and locale
+    ///
+    /// Return [None] if it never opens again.
+    pub fn next_open_after(&self, datetime: &DateTime) -> Option<DateTime> {
+        let naive = datetime.datetime();
+        self.intervals(naive.date(), self.horizon(naive.date())?)
+            .into_iter()
+            .map(|(start, _)| start)
+            .find(|start| *start > naive)
+            .map(|start| datetime.with_naive(start))
+    }
+
+    /// First closing strictly after `datetime`, keeping its format and locale
+    ///
+    /// Return [None] if it never closes again.
+    pub fn next_close_after(&self, datetime: &DateTime) -> Option<DateTime> {
+        let naive = datetime.datetime();
+        let last = self.horizon(naive.date())?;
+        let (_, end) = self
+            .intervals(naive.date(), last)
+            .into_iter()
+            .find(|(_, end)| *end > naive)?;
+        // An interval running into the last day may go on past the horizon
+        (end <= last.and_time(NaiveTime::MIN)).then(|| datetime.with_naive(end))
+    }
+
+    /// Time open from `start` included to `end` excluded, zero if `end` is not after `start`
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let hours = OpeningHours::from_str("Mo-Fr 09:00-17:00")?;
+    /// let start = DateTime::build("2023-10-09 12:00:00")?;
+    /// let end = DateTime::build("2023-10-16 10:00:00")?;
+    /// assert_eq!(hours.open_duration(&start, &end), TimeDelta::hours(38));
+    /// ```
+    pub fn open_duration(&self, start: &DateTime, end: &DateTime) -> TimeDelta {
+        let (start, end) = (start.datetime(), end.datetime());
+        if end <= start {
+            return TimeDelta::zero();
+        }
+        self.intervals(start.date(), end.date())
+            .into_iter()
+            .map(|(open, close)| (open.max(start), close.min(end)))
+            .filter(|(open, close)| open < close)
+            .map(|(open, close)| close - open)
+            .sum()
+    }
+
+    /// Last day to look at from `first` to find every change of the opening hours: a week past
+    /// `first` and the last exception
+    fn horizon(&self, first: NaiveDate) -> Option<NaiveDate> {
+        let last = self
+            .exceptions
+            .last_key_value()
+            .map_or(first, |(date, _)| first.max(*date));
+        last.checked_add_days(Days::new(8))
+    }
+
+    /// Sorted and merged open intervals of the windows starting from the day before `first` to
+    /// `last`
+    pub(crate) fn intervals(
+        &self,
+        first: NaiveDate,
+        last: NaiveDate,
+    ) -> Vec<(NaiveDateTime, NaiveDateTime)> {
+        let first = first.pred_opt().unwrap_or(first);
+        let mut intervals = first
+            .iter_days()
+            .take_while(|date| *date <= last)
+            .flat_map(|date| {
+                self.windows_on(date)
+                    .iter()
+                    .filter_map(move |window| window.on(date))
+            })
+            .collect::<Vec<_>>();
+        intervals.sort();
+        let mut merged: Vec<(NaiveDateTime, NaiveDateTime)> = Vec::with_capacity(intervals.len());
+        for (start, end) in intervals {
+            match merged.last_mut() {
+                Some((_, last_end)) if start <= *last_end => *last_end = end.max(*last_end),
+                _ => merged.push((start, end)),
+            }
+        }
+        merged
+    }
+
+    /// Apply one rule of the `opening_hours` syntax, [None] if it is not supported
+    fn apply_rule(&mut self, rule: &str) -> Option<()> {
+        let full_day = TimeWindow::new(NaiveTime::MIN, NaiveTime::MIN);
+        if rule == "24/7" {
+            for weekday in WEEKDAYS {
+                *self.weekly.windows_mut(weekday) = vec![full_day];
+            }
+            return Some(());
+        }
+        let tokens = rule.split_whitespace().collect::<Vec<_>>();
+        let (dates, weekdays, rest) = match tokens.as_slice() {
+            [year, month, days, rest @ ..] if year.parse::<i32>().is_ok() => {
+                (parse_dates(year, month, days)?, Vec::new(), rest)
+            }
+            [weekdays, rest @ ..] if weekdays.starts_with(char::is_alphabetic) => {
+                match parse_weekdays(weekdays) {
+                    Some(weekdays) => (Vec::new(), weekdays, rest),
+                    None => (Vec::new(), WEEKDAYS.to_vec(), &tokens[..]),
+                }
+            }
+            rest => (Vec::new(), WEEKDAYS.to_vec(), rest),
+        };
+        let windows = match rest.concat().as_str() {
+            "" => vec![full_day],
+            "off" | "closed" => Vec::new(),
+            windows => windows
+                .split(',')
+                .map(|window| TimeWindow::from_str(window).ok())
+                .collect::<Option<Vec<_>>>()?,
+        };
+        for weekday in weekdays {
+            *self.weekly.windows_mut(weekday) = windows.clone();
+        }
+        for date in dates {
+            self.exceptions.insert(date, windows.clone());
+        }
+        Some(())
+    }
+}
+
+const WEEKDAYS: [Weekday; 7] = [
+    Weekday::Mon,
+    Weekday::Tue,
+    Weekday::Wed,
+    Weekday::Thu,
+    Weekday::Fri,
+    Weekday::Sat,
+    Weekday::Sun,
+];
+
+/// Parse a weekday selector such as `Mo-Fr,Su`, ranges wrapping past Sunday
+fn parse_weekdays(selector: &str) -> Option<Vec<Weekday>> {
+    let weekday = |name: &str| {
+        ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"]
+            .iter()
+            .position(|day| *day == name)
+            .map(|index| WEEKDAYS[index])
+    };
+    let mut weekdays = Vec::new();
+    for item in selector.split(',') {
+        match item.split_once('-') {
+            Some((first, last)) => {
+                let (mut day, last) = (weekday(first)?, weekday(last)?);
+                weekdays.push(day);
+                while day != last {
+                    day = day.succ();
+                    weekdays.push(day);
+                }
+            }
+            None => weekdays.push(weekday(item)?),
+        }
+    }
+    Some(weekdays)
+}
+
+/// Parse a date selector such as `2023 Dec 24` or `2023 Dec 24-26`
+fn parse_dates(year: &str, month: &str, days: &str) -> Option<Vec<NaiveDate>> {
+    let month = Month::from_str(month).ok()?.number_from_month();
+    let (first, last) = days.split_once('-').unwrap_or((days, days));
+    let date = |day: &str| NaiveDate::from_ymd_opt(year.parse().ok()?, month, day.parse().ok()?);
+    let (first, last) = (date(first)?, date(last)?);
+    Some(first.iter_days().take_while(|date| *date <= last).collect())
+}
+
+impl FromStr for OpeningHours {
+    type Err = SpanError;
+
+    /// Parse opening hours written in the subset of the `opening_hours` syntax described in the
+    /// [module documentation](crate::opening)
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut hours = Self::default();
+        for rule in s.split(';').map(str::trim).filter(|rule| !rule.is_empty()) {
+            hours
+                .apply_rule(rule)
+                .ok_or_else(|| SpanError::InvalidOpeningHours(rule.to_string()))?;
+        }
+        Ok(hours)
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+
+    const STORE: &str = "Mo-Fr 09:00-12:00,14:00-18:00; Sa 10:00-13:00; Fr 09:00-12:00,14:00-02:00; 2023 Oct 11 off";
+
+    #[test]
+    fn opening_hours_parse() -> Result<(), SpanError> {
+        let hours = OpeningHours::from_str(STORE)?;
+        let date = |day| NaiveDate::from_ymd_opt(2023, 10, day).unwrap();
+        assert_eq!(hours.windows_on(date(9)).len(), 2);
+        assert_eq!(
+            hours.windows_on(date(13))[1],
+            TimeWindow::from_str("14:00-02:00")?
+        );
+        assert!(hours.windows_on(date(11)).is_empty());
+        assert!(hours.windows_on(date(15)).is_empty());
+        assert_eq!(
+            OpeningHours::from_str("24/7")?.windows_on(date(15)),
+            [TimeWindow::new(NaiveTime::MIN, NaiveTime::MIN)]
+        );
+        assert_eq!(
+            OpeningHours::from_str("Sa-Mo")?
+                .weekly()
+                .windows(Weekday::Sun)
+                .len(),
+            1
+        );
+        assert_eq!(
+            OpeningHours::from_str("10:00-16:00")?.windows_on(date(15)),
+            [TimeWindow::from_str("10:00-16:00")?]
+        );
+        let err = OpeningHours::from_str("Mo-Fr 09:00-18:00; PH off").unwrap_err();
+        assert_eq!(err, SpanError::InvalidOpeningHours("PH off".to_string()));
+        assert_eq!(err.code(), "invalid_opening_hours");
+        assert!(OpeningHours::from_str("Mo-Fr 9h-18h").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn opening_hours_is_open() -> Result<(), SpanError> {
+        let hours = OpeningHours::from_str(STORE)?;
+        assert!(hours.is_open(&DateTime::try_from("2023-10-09 09:00:00")?));
+        assert!(!hours.is_open(&DateTime::try_from("2023-10-09 12:00:00")?));
+        assert!(!hours.is_open(&DateTime::try_from("2023-10-11 10:00:00")?));
+        // Friday night until 02:00 on Saturday
+        assert!(hours.is_open(&DateTime::try_from("2023-10-14 01:59:00")?));
+        assert!(!hours.is_open(&DateTime::try_from("2023-10-14 02:00:00")?));
+        assert!(hours.is_open(&DateTime::try_from("2023-10-14 12:00:00")?));
+        Ok(())
+    }
+
+    #[test]
+    fn opening_hours_range_end() -> Result<(), SpanError> {
+        let hours = OpeningHours::from_str("Mo-Su 22:00-06:00")?;
+        let last = DateTime::from(NaiveDateTime::MAX);
+        assert!(!hours.is_open(&last));
+        assert_eq!(
+            hours.open_duration(&last.clone().with_time(0, 0, 0)?, &last),
+            TimeDelta::hours(6)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn opening_hours_next_open_and_close() -> Result<(), SpanError> {
+        let hours = OpeningHours::from_str(STORE)?;
+        let next_open = |datetime| hours.next_open_after(&datetime).map(|d| d.to_string());
+        let next_close = |datetime| hours.next_close_after(&datetime).map(|d| d.to_string());
+        assert_eq!(
+            next_open(DateTime::try_from("2023-10-09 12:00:00")?),
+            Some("2023-10-09 14:00:00".to_string())
+        );
+        assert_eq!(
+            next_open(DateTime::try_from("2023-10-10 18:00:00")?),
+            Some("2023-10-12 09:00:00".to_string())
+        );
+        assert_eq!(
+            next_open(DateTime::try_from("2023-10-14 14:00:00")?),
+            Some("2023-10-16 09:00:00".to_string())
+        );
+        assert_eq!(
+            next_close(DateTime::try_from("2023-10-09 10:00:00")?),
+            Some("2023-10-09 12:00:00".to_string())
+        );
+        assert_eq!(
+            next_close(DateTime::try_from("2023-10-13 20:00:00")?),
+            Some("2023-10-14 02:00:00".to_string())
+        );
+        assert_eq!(
+            next_close(DateTime::try_from("2023-10-11 08:00:00")?),
+            Some("2023-10-12 12:00:00".to_string())
+        );
+        let always = OpeningHours::from_str("24/7")?;
+        assert_eq!(
+            always.next_close_after(&DateTime::try_from("2023-10-09 00:00:00")?),
+            None
+        );
+        assert_eq!(
+            always.next_open_after(&DateTime::try_from("2023-10-09 00:00:00")?),
+            None
+        );
+        assert_eq!(
+            OpeningHours::default().next_open_after(&DateTime::try_from("2023-10-09 00:00:00")?),
+            None
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn opening_hours_next_open_after_closure() -> Result<(), SpanError> {
+        let date = |month, day| NaiveDate::from_ymd_opt(2023, month, day).unwrap();
+        let mut hours = OpeningHours::from_str("Mo-Su 09:00-17:00")?;
+        for day in date(10, 9)
+            .iter_days()
+            .take_while(|day| *day <= date(11, 30))
+        {
+            hours = hours.closed_on(day);
+        }
+        let hours = hours.exception(date(12, 24), TimeWindow::from_str("10:00-12:00")?);
+        assert_eq!(
+            hours
+                .next_open_after(&DateTime::try_from("2023-10-09 00:00:00")?)
+                .unwrap()
+                .to_string(),
+            "2023-12-01 09:00:00"
+        );
+        assert_eq!(
+            hours.windows_on(date(12, 24)),
+            [TimeWindow::from_str("10:00-12:00")?]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn opening_hours_open_duration() -> Result<(), SpanError> {
+        let hours = OpeningHours::from_str(STORE)?;
+        // Monday 10:00 to Monday 16:00
+        assert_eq!(
+            hours.open_duration(
+                &DateTime::try_from("2023-10-09 10:00:00")?,
+                &DateTime::try_from("2023-10-09 16:00:00")?
+            ),
+            TimeDelta::hours(4)
+        );
+        // The whole week, closed on Wednesday
+        assert_eq!(
+            hours.open_duration(
+                &DateTime::try_from("2023-10-09 00:00:00")?,
+                &DateTime::try_from("2023-10-16 00:00:00")?
+            ),
+            TimeDelta::hours(7 * 3 + 3 + 12 + 3)
+        );
+        assert_eq!(
+            hours.open_duration(
+                &DateTime::try_from("2023-10-16 00:00:00")?,
+                &DateTime::try_from("2023-10-09 00:00:00")?
+            ),
+            TimeDelta::zero()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn opening_hours_serde() -> Result<(), serde_json::Error> {
+        let hours = OpeningHours::from_str(STORE).unwrap();
+        let json = serde_json::to_string(&hours)?;
+        assert!(json.contains(r#""2023-10-11":[]"#));
+        assert_eq!(serde_json::from_str::<OpeningHours>(&json)?, hours);
+        Ok(())
+    }
+}
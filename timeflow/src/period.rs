@@ -0,0 +1,190 @@
+use std::ops::{Add, Neg, Sub};
+
+use chrono::TimeDelta;
+use serde::{Deserialize, Serialize};
+
+/// Calendar period of years, months, days, hours, minutes and seconds
+///
+/// Unlike a [TimeDelta], a period keeps its units apart: one month is not a fixed number of
+/// days, so adding a period to a span applies it like `update` does, years and months first
+/// following the [MonthEnd](crate::arithmetic::MonthEnd) policy, then the rest.
+///
+/// # Example
+/// ```rust,ignore
+/// let period = Period::new().months(1).days(2);
+/// let date = Date::new(2023, 1, 31)? + period;
+/// assert_eq!(date.to_string(), "2023-03-02".to_string());
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone, Copy, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Period {
+    years: i32,
+    months: i32,
+    days: i32,
+    hours: i32,
+    minutes: i32,
+    seconds: i32,
+}
+
+impl Period {
+    /// Create an empty period
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Setter for the years
+    pub fn years(mut self, years: i32) -> Self {
+        self.years = years;
+        self
+    }
+
+    /// Setter for the months
+    pub fn months(mut self, months: i32) -> Self {
+        self.months = months;
+        self
+    }
+
+    /// Setter for the days, as a number of weeks
+    pub fn weeks(mut self, weeks: i32) -> Self {
+        self.days = weeks.saturating_mul(7);
+        self
+    }
+
+    /// Setter for the days
+    pub fn days(mut self, days: i32) -> Self {
+        self.days = days;
+        self
+    }
+
+    /// Setter for the hours
+    pub fn hours(mut self, hours: i32) -> Self {
+        self.hours = hours;
+        self
+    }
+
+    /// Setter for the minutes
+    pub fn minutes(mut self, minutes: i32) -> Self {
+        self.minutes = minutes;
+        self
+    }
+
+    /// Setter for the seconds
+    pub fn seconds(mut self, seconds: i32) -> Self {
+        self.seconds = seconds;
+        self
+    }
+
+    /// Getter for the years
+    pub fn get_years(&self) -> i32 {
+        self.years
+    }
+
+    /// Getter for the months
+    pub fn get_months(&self) -> i32 {
+        self.months
+    }
+
+    /// Getter for the days
+    pub fn get_days(&self) -> i32 {
+        self.days
+    }
+
+    /// Getter for the hours
+    pub fn get_hours(&self) -> i32 {
+        self.hours
+    }
+
+    /// Getter for the minutes
+    pub fn get_minutes(&self) -> i32 {
+        self.minutes
+    }
+
+    /// Getter for the seconds
+    pub fn get_seconds(&self) -> i32 {
+        self.seconds
+    }
+
+    /// Return `true` if every unit of the period is zero
+    pub fn is_zero(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Years and months of the period as a number of months
+    pub fn total_months(&self) -> i64 {
+        self.years as i64 * 12 + self.months as i64
+    }
+
+    /// Days, hours, minutes and seconds of the period as a [TimeDelta]
+    pub fn time_delta(&self) -> TimeDelta {
+        TimeDelta::seconds(
+            self.days as i64 * 86_400
+                + self.hours as i64 * 3_600
+                + self.minutes as i64 * 60
+                + self.seconds as i64,
+        )
+    }
+
+    fn zip(self, rhs: Self, op: impl Fn(i32, i32) -> i32) -> Self {
+        Self {
+            years: op(self.years, rhs.years),
+            months: op(self.months, rhs.months),
+            days: op(self.days, rhs.days),
+            hours: op(self.hours, rhs.hours),
+            minutes: op(self.minutes, rhs.minutes),
+            seconds: op(self.seconds, rhs.seconds),
+        }
+    }
+}
+
+impl Add for Period {
+    type Output = Self;
+    /// Add each unit, saturating at the bounds of `i32`
+    fn add(self, rhs: Self) -> Self::Output {
+        self.zip(rhs, i32::saturating_add)
+    }
+}
+
+impl Sub for Period {
+    type Output = Self;
+    /// Subtract each unit, saturating at the bounds of `i32`
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.zip(rhs, i32::saturating_sub)
+    }
+}
+
+impl Neg for Period {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Self::new() - self
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+
+    #[test]
+    fn period_ops() {
+        let period = Period::new().years(1).months(2) + Period::new().months(-3).days(4);
+        assert_eq!(period, Period::new().years(1).months(-1).days(4));
+        assert_eq!(period.total_months(), 11);
+        assert_eq!(-period, Period::new().years(-1).months(1).days(-4));
+        assert!((period - period).is_zero());
+    }
+
+    #[test]
+    fn period_time_delta() {
+        let period = Period::new().weeks(1).hours(-1).minutes(30).seconds(5);
+        assert_eq!(
+            period.time_delta(),
+            TimeDelta::days(7) - TimeDelta::minutes(30) + TimeDelta::seconds(5)
+        );
+    }
+
+    #[test]
+    fn period_deserialize() -> Result<(), serde_json::Error> {
+        let period: Period = serde_json::from_str(r#"{"months":1,"days":2}"#)?;
+        assert_eq!(period, Period::new().months(1).days(2));
+        Ok(())
+    }
+}
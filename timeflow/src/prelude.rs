@@ -1,18 +1,35 @@
+pub use crate::arithmetic::{MonthEnd, Overflow, Rounding, UpdatePolicy};
 pub use crate::builder::SpanBuilder;
-pub use crate::error::{ErrorContext, SpanError};
-pub use crate::span::Span;
+pub use crate::clock::{Clock, FixedClock, ManualClock, OffsetClock, SystemClock};
+pub use crate::context::{Context, ContextGuard, with_context};
+pub use crate::duration::DurationFormat;
+pub use crate::error::{ErrorContext, FormatIssue, SpanError};
+pub use crate::format::{Format, FormatKind, FormatMode};
+pub use crate::locale::{FormatLength, Locale};
+pub use crate::matcher::{Field, Matchable, Matcher};
+pub use crate::period::Period;
+pub use crate::span::{Span, Unit};
+pub use crate::stopwatch::Stopwatch;
 pub use crate::timestamp::{TimestampMicro, TimestampMilli, TimestampNano};
+pub use crate::window::{TimeWindow, WeeklySchedule};
 
 #[cfg(feature = "date")]
 pub use crate::{
+    business::{BusinessCalendar, BusinessDayConvention},
     date::{Date, DateUnit},
+    daycount::{DayCount, Frequency},
     error::DateError,
+    grid::{GridDay, GridWeek, MonthGrid},
+    schedule::{Schedule, SchedulePeriod, Stub},
 };
 
 #[cfg(feature = "datetime")]
 pub use crate::{
+    bucket::{BucketWidth, Buckets, bucket_of, buckets, fill_gaps, group_by_bucket},
     datetime::{DateTime, DateTimeUnit},
     error::DateTimeError,
+    opening::OpeningHours,
+    working::WorkingCalendar,
 };
 
 #[cfg(feature = "time")]
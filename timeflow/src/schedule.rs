@@ -0,0 +1,397 @@
+//! Periodic schedules of payment or coupon dates
+//!
+//! A [Schedule] rolls dates every [Frequency] from the start or the end of a range, leaving an
+//! irregular stub period at the other side, and moves them to business days with a
+//! [BusinessCalendar].
+//!
+//! # Example
+//! ```rust,ignore
+//! let schedule = Schedule::new(Date::new(2023, 1, 15)?, Date::new(2024, 3, 31)?, Frequency::Quarterly)
+//!     .end_of_month(true)
+//!     .convention(BusinessDayConvention::ModifiedFollowing);
+//! let dates = schedule.unadjusted_dates()?;
+//! // Short front stub, then quarterly month ends rolled back from March 31st
+//! assert_eq!(dates.iter().map(|date| date.to_string()).collect::<Vec<_>>(), [
+//!     "2023-01-15", "2023-03-31", "2023-06-30", "2023-09-30", "2023-12-31", "2024-03-31",
+//! ]);
+//! assert_eq!(schedule.adjusted_dates()?[3].to_string(), "2023-09-29");
+//! ```
+
+use chrono::{Datelike, NaiveDate};
+
+use crate::{
+    arithmetic::{MonthEnd, Overflow, UpdatePolicy},
+    business::{BusinessCalendar, BusinessDayConvention},
+    date::{Date, DateUnit},
+    daycount::Frequency,
+    error::{DateError, ErrorContext, SpanError},
+    span::Span,
+};
+
+/// Side of a [Schedule] holding the irregular period when the range is not a whole number of
+/// periods
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum Stub {
+    /// Short first period, dates rolled back from the end, the default
+    #[default]
+    ShortFront,
+    /// First period longer than the others, dates rolled back from the end
+    LongFront,
+    /// Short last period, dates rolled forward from the start
+    ShortBack,
+    /// Last period longer than the others, dates rolled forward from the start
+    LongBack,
+}
+
+/// Generator of periodic dates from a start to an end
+///
+/// Dates are stepped in months from the end, or from the start with a back [Stub], clamped to
+/// the end of shorter months. With the end-of-month rule, an anchor on the last day of a month
+/// rolls to the last day of every month.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Schedule {
+    start: Date,
+    end: Date,
+    frequency: Frequency,
+    stub: Stub,
+    end_of_month: bool,
+    convention: BusinessDayConvention,
+    calendar: BusinessCalendar,
+}
+
+/// Period of a [Schedule], with its unadjusted and adjusted boundaries
+#[derive(Debug, PartialEq, Clone)]
+pub struct SchedulePeriod {
+    unadjusted_start: Date,
+    unadjusted_end: Date,
+    start: Date,
+    end: Date,
+}
+
+impl SchedulePeriod {
+    /// Getter for the start before business-day adjustment
+    pub fn unadjusted_start(&self) -> &Date {
+        &self.unadjusted_start
+    }
+
+    /// Getter for the end before business-day adjustment
+    pub fn unadjusted_end(&self) -> &Date {
+        &self.unadjusted_end
+    }
+
+    /// Getter for the start moved to a business day
+    pub fn start(&self) -> &Date {
+        &self.start
+    }
+
+    /// Getter for the end moved to a business day
+    pub fn end(&self) -> &Date {
+        &self.end
+    }
+}
+
+impl Schedule {
+    /// Create a schedule from `start` to `end` every `frequency`, with a short front stub and
+    /// without adjustment
+    pub fn new(start: Date, end: Date, frequency: Frequency) -> Self {
+        Self {
+            start,
+            end,
+            frequency,
+            stub: Stub::default(),
+            end_of_month: false,
+            convention: BusinessDayConvention::default(),
+            calendar: BusinessCalendar::default(),
+        }
+    }
+
+    /// Setter for the side of the stub period
+    pub fn stub(mut self, stub: Stub) -> Self {
+        self.stub = stub;
+        self
+    }
+
+    /// Setter for the end-of-month rule
+    pub fn end_of_month(mut self, end_of_month: bool) -> Self {
+        self.end_of_month = end_of_month;
+        self
+    }
+
+    /// Setter for the business-day convention of the adjusted dates
+    pub fn convention(mut self, convention: BusinessDayConvention) -> Self {
+        self.convention = convention;
+        self
+    }
+
+    /// Setter for the business days of the adjusted dates
+    pub fn calendar(mut self, calendar: BusinessCalendar) -> Self {
+        self.calendar = calendar;
+        self
+    }
+
+    /// Period boundaries from the start to the end, both included, before business-day
+    /// adjustment
+    ///
+    /// # Errors
+    /// Return an Err(_) if the end is not after the start or a date is out of range
+    pub fn unadjusted_dates(&self) -> Result<Vec<Date>, SpanError> {
+        if self.end.date() <= self.start.date() {
+            return Err(SpanError::InvalidSchedule {
+                start: self.start.to_string(),
+                end: self.end.to_string(),
+            })
+            .err_ctx(DateError);
+        }
+        let front = matches!(self.stub, Stub::ShortFront | Stub::LongFront);
+        let (anchor, other) = match front {
+            true => (&self.end, &self.start),
+            false => (&self.start, &self.end),
+        };
+        let months = self.frequency.months() as i32 * if front { -1 } else { 1 };
+        let mut dates = vec![anchor.clone()];
+        for period in 1.. {
+            let date = self.roll(anchor, period * months)?;
+            let past = match front {
+                true => date.date() <= other.date(),
+                false => date.date() >= other.date(),
+            };
+            if past {
+                let long = matches!(self.stub, Stub::LongFront | Stub::LongBack);
+                if long && date.date() != other.date() && dates.len() > 1 {
+                    dates.pop();
+                }
+                break;
+            }
+            dates.push(date);
+        }
+        dates.push(other.clone());
+        if front {
+            dates.reverse();
+        }
+        Ok(dates)
+    }
+
+    /// Period boundaries from the start to the end, both included, moved to business days
+    ///
+    /// # Errors
+    /// Return an Err(_) if the end is not after the start or a date cannot be adjusted
+    pub fn adjusted_dates(&self) -> Result<Vec<Date>, SpanError> {
+        self.unadjusted_dates()?
+            .iter()
+            .map(|date| self.calendar.adjust(date, self.convention))
+            .collect()
+    }
+
+    /// Periods of the schedule with their unadjusted and adjusted boundaries
+    ///
+    /// # Errors
+    /// Return an Err(_) if the end is not after the start or a date cannot be adjusted
+    pub fn periods(&self) -> Result<Vec<SchedulePeriod>, SpanError> {
+        let unadjusted = self.unadjusted_dates()?;
+        let adjusted = self.adjusted_dates()?;
+        Ok(unadjusted
+            .windows(2)
+            .zip(adjusted.windows(2))
+            .map(|(unadjusted, adjusted)| SchedulePeriod {
+                unadjusted_start: unadjusted[0].clone(),
+                unadjusted_end: unadjusted[1].clone(),
+                start: adjusted[0].clone(),
+                end: adjusted[1].clone(),
+            })
+            .collect())
+    }
+
+    /// `anchor` moved by `months`, following the end-of-month rule
+    fn roll(&self, anchor: &Date, months: i32) -> Result<Date, SpanError> {
+        let policy = UpdatePolicy::new()
+            .overflow(Overflow::Checked)
+            .month_end(MonthEnd::Clamp);
+        let mut date = anchor.update_with(DateUnit::Month, months, policy)?;
+        if self.end_of_month && is_month_end(anchor.date()) {
+            date.date = date
+                .date
+                .with_day(date.date.num_days_in_month() as u32)
+                .unwrap_or(date.date);
+        }
+        Ok(date)
+    }
+}
+
+fn is_month_end(date: NaiveDate) -> bool {
+    date.day() == date.num_days_in_month() as u32
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+
+    fn strings(dates: Vec<Date>) -> Vec<String> {
+        dates.iter().map(|date| date.to_string()).collect()
+    }
+
+    #[test]
+    fn schedule_regular() -> Result<(), SpanError> {
+        let schedule = Schedule::new(
+            Date::new(2023, 1, 15)?,
+            Date::new(2024, 1, 15)?,
+            Frequency::Quarterly,
+        );
+        let expected = [
+            "2023-01-15",
+            "2023-04-15",
+            "2023-07-15",
+            "2023-10-15",
+            "2024-01-15",
+        ];
+        assert_eq!(strings(schedule.unadjusted_dates()?), expected);
+        assert_eq!(
+            strings(schedule.clone().stub(Stub::LongBack).unadjusted_dates()?),
+            expected
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn schedule_stubs() -> Result<(), SpanError> {
+        let schedule = Schedule::new(
+            Date::new(2023, 2, 1)?,
+            Date::new(2024, 1, 15)?,
+            Frequency::Quarterly,
+        );
+        assert_eq!(
+            strings(schedule.unadjusted_dates()?),
+            [
+                "2023-02-01",
+                "2023-04-15",
+                "2023-07-15",
+                "2023-10-15",
+                "2024-01-15"
+            ]
+        );
+        assert_eq!(
+            strings(schedule.clone().stub(Stub::LongFront).unadjusted_dates()?),
+            ["2023-02-01", "2023-07-15", "2023-10-15", "2024-01-15"]
+        );
+        assert_eq!(
+            strings(schedule.clone().stub(Stub::ShortBack).unadjusted_dates()?),
+            [
+                "2023-02-01",
+                "2023-05-01",
+                "2023-08-01",
+                "2023-11-01",
+                "2024-01-15"
+            ]
+        );
+        assert_eq!(
+            strings(schedule.clone().stub(Stub::LongBack).unadjusted_dates()?),
+            ["2023-02-01", "2023-05-01", "2023-08-01", "2024-01-15"]
+        );
+        // A range shorter than a period is a single stub
+        let schedule = Schedule::new(
+            Date::new(2023, 2, 1)?,
+            Date::new(2023, 3, 1)?,
+            Frequency::Quarterly,
+        );
+        assert_eq!(
+            strings(schedule.stub(Stub::LongFront).unadjusted_dates()?),
+            ["2023-02-01", "2023-03-01"]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn schedule_end_of_month() -> Result<(), SpanError> {
+        let schedule = Schedule::new(
+            Date::new(2023, 2, 28)?,
+            Date::new(2023, 8, 31)?,
+            Frequency::Monthly,
+        )
+        .stub(Stub::ShortBack);
+        assert_eq!(
+            strings(schedule.unadjusted_dates()?),
+            [
+                "2023-02-28",
+                "2023-03-28",
+                "2023-04-28",
+                "2023-05-28",
+                "2023-06-28",
+                "2023-07-28",
+                "2023-08-28",
+                "2023-08-31"
+            ]
+        );
+        assert_eq!(
+            strings(schedule.end_of_month(true).unadjusted_dates()?),
+            [
+                "2023-02-28",
+                "2023-03-31",
+                "2023-04-30",
+                "2023-05-31",
+                "2023-06-30",
+                "2023-07-31",
+                "2023-08-31"
+            ]
+        );
+        // Without the rule, rolling back from the 31st clamps to shorter months
+        let schedule = Schedule::new(
+            Date::new(2023, 1, 31)?,
+            Date::new(2023, 5, 31)?,
+            Frequency::Monthly,
+        );
+        assert_eq!(
+            strings(schedule.unadjusted_dates()?),
+            [
+                "2023-01-31",
+                "2023-02-28",
+                "2023-03-31",
+                "2023-04-30",
+                "2023-05-31"
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn schedule_adjusted() -> Result<(), SpanError> {
+        let calendar =
+            BusinessCalendar::new().holiday(NaiveDate::from_ymd_opt(2024, 1, 1).unwrap());
+        let schedule = Schedule::new(
+            Date::new(2023, 3, 31)?,
+            Date::new(2024, 3, 31)?,
+            Frequency::Quarterly,
+        )
+        .end_of_month(true)
+        .calendar(calendar)
+        .convention(BusinessDayConvention::ModifiedFollowing);
+        // December 31st would move to January 2nd, after the holiday, so back to December 29th
+        assert_eq!(
+            strings(schedule.adjusted_dates()?),
+            [
+                "2023-03-31",
+                "2023-06-30",
+                "2023-09-29",
+                "2023-12-29",
+                "2024-03-29"
+            ]
+        );
+        let periods = schedule
+            .convention(BusinessDayConvention::Following)
+            .periods()?;
+        assert_eq!(periods.len(), 4);
+        assert_eq!(periods[2].unadjusted_end().to_string(), "2023-12-31");
+        assert_eq!(periods[2].end().to_string(), "2024-01-02");
+        assert_eq!(periods[3].start(), periods[2].end());
+        assert_eq!(periods[3].unadjusted_start().to_string(), "2023-12-31");
+        Ok(())
+    }
+
+    #[test]
+    fn schedule_invalid_range() -> Result<(), SpanError> {
+        let date = Date::new(2023, 1, 15)?;
+        let err = Schedule::new(date.clone(), date, Frequency::Annual)
+            .unadjusted_dates()
+            .unwrap_err();
+        assert_eq!(err.code(), "invalid_schedule");
+        Ok(())
+    }
+}
@@ -1,41 +1,181 @@
 use chrono::Duration;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
-use crate::error::SpanError;
-
-/// U is the Unit of the span
-pub trait Span<U, F>
-where
-    Self: Sized,
-{
-    fn new(_: F, _: u32, _: u32) -> Result<Self, SpanError>;
+use crate::{arithmetic::UpdatePolicy, error::SpanError, format::Format, locale::Locale};
+
+/// Unit of any span, carried by [SpanError] whatever the type of the span
+///
+/// Units are ordered from the largest to the smallest, `Unit::Year < Unit::Day`.
+///
+/// Units are parsed from their name or an alias, ignoring case: `y`, `yr`, `yrs`, `year`, `years`,
+/// `mo`, `mon`, `month`, `months`, `d`, `day`, `days`, `h`, `hr`, `hrs`, `hour`, `hours`,
+/// `min`, `mins`, `minute`, `minutes`, `s`, `sec`, `secs`, `second`, `seconds`.
+/// The single letter `M` is a month and `m` a minute. Deserialization accepts the same names.
+///
+/// # Example
+/// ```rust,ignore
+/// let unit: Unit = "hours".parse()?;
+/// assert_eq!(unit, Unit::Hour);
+/// assert_eq!(unit.as_seconds(), Some(3600));
+/// assert_eq!(unit.to_string(), "Hour".to_string());
+/// ```
+#[derive(Debug, PartialEq, PartialOrd, Eq, Ord, Hash, Clone, Copy, Serialize, Deserialize)]
+#[serde(try_from = "String")]
+pub enum Unit {
+    Year,
+    Month,
+    Day,
+    Hour,
+    Minute,
+    Second,
+}
+
+impl Unit {
+    /// Every unit, from the largest to the smallest
+    pub const ALL: [Unit; 6] = [
+        Unit::Year,
+        Unit::Month,
+        Unit::Day,
+        Unit::Hour,
+        Unit::Minute,
+        Unit::Second,
+    ];
+
+    /// Iterate over every unit, from the largest to the smallest
+    pub fn iter() -> impl DoubleEndedIterator<Item = Unit> {
+        Self::ALL.into_iter()
+    }
+
+    /// Number of seconds in the unit, [None] for years and months whose length varies
+    pub fn as_seconds(&self) -> Option<i64> {
+        match self {
+            Unit::Year | Unit::Month => None,
+            Unit::Day => Some(86_400),
+            Unit::Hour => Some(3_600),
+            Unit::Minute => Some(60),
+            Unit::Second => Some(1),
+        }
+    }
+}
+
+impl std::fmt::Display for Unit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+impl std::str::FromStr for Unit {
+    type Err = SpanError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let unit = s.trim();
+        match unit {
+            "M" => return Ok(Unit::Month),
+            "m" => return Ok(Unit::Minute),
+            _ => {}
+        }
+        match unit.to_ascii_lowercase().as_str() {
+            "y" | "yr" | "yrs" | "year" | "years" => Ok(Unit::Year),
+            "mo" | "mon" | "month" | "months" => Ok(Unit::Month),
+            "d" | "day" | "days" => Ok(Unit::Day),
+            "h" | "hr" | "hrs" | "hour" | "hours" => Ok(Unit::Hour),
+            "min" | "mins" | "minute" | "minutes" => Ok(Unit::Minute),
+            "s" | "sec" | "secs" | "second" | "seconds" => Ok(Unit::Second),
+            _ => Err(SpanError::UnknownUnit(s.to_string())),
+        }
+    }
+}
+
+impl TryFrom<String> for Unit {
+    type Error = SpanError;
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+/// Behavior shared by [Date](crate::date::Date), [Time](crate::time::Time) and
+/// [DateTime](crate::datetime::DateTime)
+///
+/// The unit and the components of a span are associated types, so generic code only needs
+/// the span type.
+///
+/// # Example
+/// ```rust,ignore
+/// fn report<S: Span>(span: &S, unit: S::Unit) -> Result<String, SpanError> {
+///     let next = span.next(unit)?;
+///     Ok(format!("{span} then {next} in {}", next.elapsed(span)))
+/// }
+/// ```
+pub trait Span: Sized + Clone + std::fmt::Display {
+    /// Unit used to update, match, clear and measure the span
+    type Unit: Copy + Into<Unit>;
+    /// Values the span is built from, e.g. `(year, month, day)` for a [Date](crate::date::Date)
+    type Components;
+
+    /// Create a span from its [Span::Components], with the default format and locale
+    fn from_components(components: Self::Components) -> Result<Self, SpanError>;
+    /// Getter for the [Span::Components] of the span
+    fn components(&self) -> Self::Components;
     /// Getter for the format of the span
     fn get_format(&self) -> String;
     /// Setter for the format of the span
     fn format(self, format: impl ToString) -> Self;
+    /// Setter for a compiled [Format], shared with the span instead of being copied
+    fn with_format(self, format: Format) -> Self;
+    /// Setter for the format of the span, validated against the type of the span
+    fn try_format(self, format: impl ToString) -> Result<Self, SpanError>;
     /// Reset the format of the span to the default format
     fn default_format(self) -> Self;
-    /// Update the value of the span by a given Unit (U) and value.
-    fn update(&self, unit: U, value: i32) -> Result<Self, SpanError>;
-    /// Get the value of the span incremented by a given Unit (U).
-    fn next(&self, unit: U) -> Result<Self, SpanError>;
+    /// Getter for the locale of the span
+    fn get_locale(&self) -> Locale;
+    /// Setter for the locale used to display month names, weekday names and AM/PM markers
+    ///
+    /// Parsing from a string uses the locale in use, the one of the
+    /// [Context](crate::context::Context) or the global one, unless given explicitly, e.g.
+    /// `Date::try_from((s, "%A %-d %B %Y", Locale::Fr))` or with a [Format] instead of the
+    /// pattern.
+    fn locale(self, locale: Locale) -> Self;
+    /// Update the value of the span by a given [Span::Unit] and value.
+    ///
+    /// Use the [UpdatePolicy] of the [Context](crate::context::Context) or the
+    /// [SpanBuilder](crate::builder::SpanBuilder), or the default of the span.
+    fn update(&self, unit: Self::Unit, value: i32) -> Result<Self, SpanError> {
+        self.update_with(unit, value, UpdatePolicy::new())
+    }
+    /// Update the value of the span by a given [Span::Unit] and value, with the given [UpdatePolicy].
+    fn update_with(
+        &self,
+        unit: Self::Unit,
+        value: i32,
+        policy: UpdatePolicy,
+    ) -> Result<Self, SpanError>;
+    /// Get the value of the span incremented by a given [Span::Unit].
+    fn next(&self, unit: Self::Unit) -> Result<Self, SpanError>;
     /// Return if the span matches the given unit and value.
-    fn matches(&self, unit: U, value: u32) -> bool;
+    fn matches(&self, unit: Self::Unit, value: u32) -> bool;
     /// Return the value of the span at system time.
     fn now() -> Result<Self, SpanError>;
     fn is_in_future(&self) -> Result<bool, SpanError>;
     fn elapsed(&self, lhs: &Self) -> Duration;
-    fn unit_elapsed(&self, rhs: &Self, unit: U) -> Result<i64, SpanError>;
-    fn clear_unit(&self, unit: U) -> Result<Self, SpanError>;
-    fn deserialize_with_format<'de, D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: Deserializer<'de>,
-        Self: Deserialize<'de>;
-
-    fn serialize_with_format<S: Serializer>(value: &Self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        Self: ToString,
-    {
+    fn unit_elapsed(&self, rhs: &Self, unit: Self::Unit) -> Result<i64, SpanError>;
+    fn clear_unit(&self, unit: Self::Unit) -> Result<Self, SpanError>;
+    /// Deserialize a span written as `{ "date": "...", "format": "..." }`
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// #[derive(Deserialize)]
+    /// struct Event(#[serde(deserialize_with = "Date::deserialize_with_format")] Date);
+    /// ```
+    fn deserialize_with_format<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error>;
+
+    /// Serialize the span as `{ "date": "...", "format": "..." }`, read back by
+    /// [Span::deserialize_with_format]
+    fn serialize_with_format<S: Serializer>(
+        value: &Self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
         #[derive(Serialize)]
         struct Visitor {
             date: String,
@@ -49,3 +189,67 @@ where
         visitor.serialize(serializer)
     }
 }
+
+/// Convert a [Unit] to the unit of a span of kind `kind`, with `convert`
+#[cfg(any(feature = "date", feature = "time"))]
+pub(crate) fn unit_for<U>(
+    unit: Unit,
+    kind: crate::format::FormatKind,
+    convert: impl FnOnce(Unit) -> Option<U>,
+) -> Result<U, SpanError> {
+    convert(unit).ok_or(SpanError::UnitNotAllowed { unit, kind })
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+
+    #[test]
+    fn unit_from_str() -> Result<(), SpanError> {
+        assert_eq!("years".parse::<Unit>()?, Unit::Year);
+        assert_eq!(" Mon ".parse::<Unit>()?, Unit::Month);
+        assert_eq!("M".parse::<Unit>()?, Unit::Month);
+        assert_eq!("m".parse::<Unit>()?, Unit::Minute);
+        assert_eq!("D".parse::<Unit>()?, Unit::Day);
+        assert_eq!("HOURS".parse::<Unit>()?, Unit::Hour);
+        assert_eq!("secs".parse::<Unit>()?, Unit::Second);
+        assert_eq!(
+            "fortnight".parse::<Unit>(),
+            Err(SpanError::UnknownUnit("fortnight".to_string()))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn unit_display_round_trip() -> Result<(), SpanError> {
+        for unit in Unit::iter() {
+            assert_eq!(unit.to_string().parse::<Unit>()?, unit);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn unit_iter_largest_first() {
+        let units: Vec<Unit> = Unit::iter().collect();
+        assert_eq!(units.first(), Some(&Unit::Year));
+        assert_eq!(units.last(), Some(&Unit::Second));
+        assert!(units.is_sorted());
+        assert_eq!(Unit::iter().next_back(), Some(Unit::Second));
+    }
+
+    #[test]
+    fn unit_as_seconds() {
+        assert_eq!(Unit::Month.as_seconds(), None);
+        assert_eq!(Unit::Day.as_seconds(), Some(86_400));
+        assert_eq!(Unit::Minute.as_seconds(), Some(60));
+    }
+
+    #[test]
+    fn unit_deserialize_alias() -> Result<(), serde_json::Error> {
+        let units: Vec<Unit> = serde_json::from_str(r#"["Day", "h", "minutes"]"#)?;
+        assert_eq!(units, vec![Unit::Day, Unit::Hour, Unit::Minute]);
+        assert_eq!(serde_json::to_string(&Unit::Day)?, r#""Day""#);
+        assert!(serde_json::from_str::<Unit>(r#""week""#).is_err());
+        Ok(())
+    }
+}
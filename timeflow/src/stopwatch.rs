@@ -0,0 +1,211 @@
+use std::ops::Sub;
+
+use chrono::TimeDelta;
+
+/// Monotonic instant, measuring durations as [TimeDelta] like [Span::elapsed](crate::span::Span::elapsed)
+///
+/// Unlike `now()`, it always reads the system monotonic clock and ignores the
+/// [Clock](crate::clock::Clock) in use, so it cannot go backward.
+///
+/// # Example
+/// ```rust,ignore
+/// let start = Instant::now();
+/// run_job();
+/// println!("Job ran in {}", start.elapsed().humanize());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Instant(std::time::Instant);
+
+impl Instant {
+    /// Current monotonic instant
+    pub fn now() -> Self {
+        Self(std::time::Instant::now())
+    }
+
+    /// [TimeDelta] elapsed since the instant
+    pub fn elapsed(&self) -> TimeDelta {
+        Self::now() - *self
+    }
+
+    /// [TimeDelta] elapsed from `earlier` to the instant, negative if `earlier` is later
+    pub fn duration_since(&self, earlier: Instant) -> TimeDelta {
+        *self - earlier
+    }
+}
+
+impl Sub for Instant {
+    type Output = TimeDelta;
+    fn sub(self, rhs: Self) -> Self::Output {
+        match self.0.checked_duration_since(rhs.0) {
+            Some(duration) => to_delta(duration),
+            None => -to_delta(rhs.0.duration_since(self.0)),
+        }
+    }
+}
+
+impl From<std::time::Instant> for Instant {
+    fn from(value: std::time::Instant) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Instant> for std::time::Instant {
+    fn from(value: Instant) -> Self {
+        value.0
+    }
+}
+
+fn to_delta(duration: std::time::Duration) -> TimeDelta {
+    TimeDelta::from_std(duration).unwrap_or(TimeDelta::MAX)
+}
+
+/// Stopwatch with laps, pause and resume, based on [Instant]
+///
+/// # Example
+/// ```rust,ignore
+/// let mut stopwatch = Stopwatch::start();
+/// download();
+/// stopwatch.lap();
+/// stopwatch.pause();
+/// wait_for_user();
+/// stopwatch.resume();
+/// process();
+/// stopwatch.lap();
+/// for lap in stopwatch.laps() {
+///     println!("{}", lap.to_iso8601());
+/// }
+/// println!("Total: {}", stopwatch.elapsed().humanize());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Stopwatch {
+    /// Instant of the last start or resume, when running
+    running_since: Option<Instant>,
+    /// Time measured before the last start or resume
+    accumulated: TimeDelta,
+    /// Total elapsed time when the last lap ended
+    last_lap: TimeDelta,
+    laps: Vec<TimeDelta>,
+}
+
+impl Stopwatch {
+    /// Create a paused stopwatch
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a running stopwatch
+    pub fn start() -> Self {
+        let mut stopwatch = Self::new();
+        stopwatch.resume();
+        stopwatch
+    }
+
+    /// Return `true` if the stopwatch is running
+    pub fn is_running(&self) -> bool {
+        self.running_since.is_some()
+    }
+
+    /// Pause the stopwatch, doing nothing if it is already paused
+    pub fn pause(&mut self) -> &mut Self {
+        if let Some(since) = self.running_since.take() {
+            self.accumulated += since.elapsed();
+        }
+        self
+    }
+
+    /// Resume the stopwatch, doing nothing if it is already running
+    pub fn resume(&mut self) -> &mut Self {
+        if self.running_since.is_none() {
+            self.running_since = Some(Instant::now());
+        }
+        self
+    }
+
+    /// Total [TimeDelta] measured, without the paused time
+    pub fn elapsed(&self) -> TimeDelta {
+        match self.running_since {
+            Some(since) => self.accumulated + since.elapsed(),
+            None => self.accumulated,
+        }
+    }
+
+    /// End the current lap and return its [TimeDelta]
+    pub fn lap(&mut self) -> TimeDelta {
+        let elapsed = self.elapsed();
+        let lap = elapsed - self.last_lap;
+        self.last_lap = elapsed;
+        self.laps.push(lap);
+        lap
+    }
+
+    /// [TimeDelta] of every lap, in order
+    pub fn laps(&self) -> &[TimeDelta] {
+        &self.laps
+    }
+
+    /// Reset the measured time and the laps, keeping the stopwatch running or paused
+    pub fn reset(&mut self) -> &mut Self {
+        let running = self.is_running();
+        *self = Self::new();
+        if running {
+            self.resume();
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use std::{thread::sleep, time::Duration};
+
+    use super::*;
+
+    #[test]
+    fn instant_sub() {
+        let earlier = Instant::now();
+        let later = Instant::from(std::time::Instant::from(earlier) + Duration::from_millis(1500));
+        assert_eq!(later - earlier, TimeDelta::milliseconds(1500));
+        assert_eq!(
+            earlier.duration_since(later),
+            TimeDelta::milliseconds(-1500)
+        );
+    }
+
+    #[test]
+    fn stopwatch_paused() {
+        let mut stopwatch = Stopwatch::new();
+        assert!(!stopwatch.is_running());
+        sleep(Duration::from_millis(5));
+        assert_eq!(stopwatch.elapsed(), TimeDelta::zero());
+        stopwatch.resume();
+        sleep(Duration::from_millis(5));
+        stopwatch.pause();
+        let elapsed = stopwatch.elapsed();
+        assert!(elapsed >= TimeDelta::milliseconds(5));
+        sleep(Duration::from_millis(5));
+        assert_eq!(stopwatch.elapsed(), elapsed);
+    }
+
+    #[test]
+    fn stopwatch_laps() {
+        let mut stopwatch = Stopwatch::start();
+        sleep(Duration::from_millis(5));
+        let first = stopwatch.lap();
+        sleep(Duration::from_millis(5));
+        stopwatch.pause();
+        let second = stopwatch.lap();
+        assert!(first >= TimeDelta::milliseconds(5));
+        assert!(second >= TimeDelta::milliseconds(5));
+        assert_eq!(stopwatch.laps(), &[first, second]);
+        assert_eq!(stopwatch.elapsed(), first + second);
+    }
+
+    #[test]
+    fn stopwatch_reset() {
+        let mut stopwatch = Stopwatch::start();
+        stopwatch.lap();
+        stopwatch.reset();
+        assert!(stopwatch.is_running());
+        assert!(stopwatch.laps().is_empty());
+    }
+}
@@ -0,0 +1,248 @@
+//! Time travel for tests
+//!
+//! Freeze, advance, move or speed up the global [Clock] from a test. The clock is installed
+//! globally, so it is seen by every thread, and it is restored when the returned [TimeTravel]
+//! guard is dropped.
+//!
+//! Only one [TimeTravel] can be alive at a time: tests using this module wait for each other,
+//! so they stay deterministic when cargo runs them in parallel. Creating a second guard on a
+//! thread that already holds one deadlocks, use [TimeTravel::travel_to],
+//! [TimeTravel::advance] or [TimeTravel::advance_period] instead.
+//!
+//! A clock set on a [Context](crate::context::Context) still takes precedence on its thread.
+//!
+//! # Example
+//! ```rust,ignore
+//! let time = freeze_at(DateTime::new(2024, 1, 1)?);
+//! let deadline = DateTime::now()?.update(DateTimeUnit::Hour, 1)?;
+//! time.advance(TimeDelta::minutes(61));
+//! assert!(!deadline.is_in_future()?);
+//! ```
+
+use std::{
+    sync::{Arc, Mutex, MutexGuard},
+    time::Instant,
+};
+
+use chrono::{NaiveDateTime, TimeDelta};
+
+use crate::{
+    clock::{self, Clock},
+    datetime::DateTime,
+    period::Period,
+};
+
+static TRAVEL_LOCK: Mutex<()> = Mutex::new(());
+
+/// Freeze the clock at `datetime` until the guard is dropped
+pub fn freeze_at(datetime: DateTime) -> TimeTravel {
+    TimeTravel::new(*datetime, 0.0)
+}
+
+/// Move the clock to `datetime` and let it run from there until the guard is dropped
+pub fn travel_to(datetime: DateTime) -> TimeTravel {
+    TimeTravel::new(*datetime, 1.0)
+}
+
+/// Start the clock at `datetime`, running `speed` times faster than the real time,
+/// until the guard is dropped
+///
+/// # Example
+/// ```rust,ignore
+/// // One real second is one virtual hour
+/// let time = scaled(DateTime::new(2024, 1, 1)?, 3600.0);
+/// ```
+pub fn scaled(datetime: DateTime, speed: f64) -> TimeTravel {
+    TimeTravel::new(*datetime, speed)
+}
+
+/// [Clock] running at a given speed from a virtual date and time
+#[derive(Debug)]
+struct TravelClock {
+    /// Virtual date and time at `anchor`
+    origin: NaiveDateTime,
+    anchor: Instant,
+    speed: f64,
+}
+
+impl TravelClock {
+    fn now(&self) -> NaiveDateTime {
+        let elapsed = self.anchor.elapsed().mul_f64(self.speed.max(0.0));
+        TimeDelta::from_std(elapsed)
+            .ok()
+            .and_then(|elapsed| self.origin.checked_add_signed(elapsed))
+            .unwrap_or(self.origin)
+    }
+}
+
+#[derive(Debug, Clone)]
+struct SharedClock(Arc<Mutex<TravelClock>>);
+
+impl Clock for SharedClock {
+    fn now(&self) -> NaiveDateTime {
+        self.0.lock().unwrap().now()
+    }
+}
+
+/// Guard returned by [freeze_at], [travel_to] and [scaled], restoring the previous
+/// global [Clock] when dropped
+#[must_use = "the clock is restored as soon as the guard is dropped"]
+#[derive(Debug)]
+pub struct TimeTravel {
+    clock: SharedClock,
+    previous: Option<Arc<dyn Clock>>,
+    _lock: MutexGuard<'static, ()>,
+}
+
+impl TimeTravel {
+    fn new(origin: NaiveDateTime, speed: f64) -> Self {
+        let lock = TRAVEL_LOCK
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let clock = SharedClock(Arc::new(Mutex::new(TravelClock {
+            origin,
+            anchor: Instant::now(),
+            speed,
+        })));
+        let previous = clock::replace_clock(Arc::new(clock.clone()));
+        Self {
+            clock,
+            previous: Some(previous),
+            _lock: lock,
+        }
+    }
+
+    /// Current virtual date and time
+    pub fn now(&self) -> NaiveDateTime {
+        self.clock.now()
+    }
+
+    /// Move the clock forward by `delta`, or backward if negative
+    ///
+    /// The clock is left unchanged if the new time is out of range
+    pub fn advance(&self, delta: TimeDelta) -> &Self {
+        let mut clock = self.clock.0.lock().unwrap();
+        if let Some(origin) = clock.now().checked_add_signed(delta) {
+            clock.origin = origin;
+            clock.anchor = Instant::now();
+        }
+        self
+    }
+
+    /// Move the clock by a [Period], years and months first, following the [MonthEnd] policy
+    /// in use, like [DateTime::add_period]
+    ///
+    /// The clock is left unchanged if the new time is out of range or rejected by the policy
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let time = freeze_at(DateTime::new(2024, 1, 31)?);
+    /// time.advance_period(Period::new().months(1));
+    /// assert_eq!(DateTime::now()?.to_string(), "2024-02-29 00:00:00");
+    /// ```
+    ///
+    /// [MonthEnd]: crate::arithmetic::MonthEnd
+    pub fn advance_period(&self, period: Period) -> &Self {
+        let mut clock = self.clock.0.lock().unwrap();
+        if let Ok(origin) = DateTime::from(clock.now()).add_period(period) {
+            clock.origin = *origin;
+            clock.anchor = Instant::now();
+        }
+        self
+    }
+
+    /// Move the clock to `datetime`, keeping its speed
+    pub fn travel_to(&self, datetime: DateTime) -> &Self {
+        let mut clock = self.clock.0.lock().unwrap();
+        clock.origin = *datetime;
+        clock.anchor = Instant::now();
+        self
+    }
+
+    /// Change the speed of the clock, `0.0` freezes it and `1.0` is the real time
+    ///
+    /// A negative speed freezes the clock
+    pub fn speed(&self, speed: f64) -> &Self {
+        let mut clock = self.clock.0.lock().unwrap();
+        clock.origin = clock.now();
+        clock.anchor = Instant::now();
+        clock.speed = speed;
+        self
+    }
+}
+
+impl Drop for TimeTravel {
+    fn drop(&mut self) {
+        if let Some(previous) = self.previous.take() {
+            clock::replace_clock(previous);
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use chrono::Datelike;
+
+    use super::*;
+    use crate::{datetime::DateTimeUnit, error::SpanError, span::Span};
+
+    #[test]
+    fn testing_freeze_and_advance() -> Result<(), SpanError> {
+        let time = freeze_at(DateTime::new(2024, 1, 1)?);
+        let deadline = DateTime::now()?.update(DateTimeUnit::Hour, 1)?;
+        assert!(deadline.is_in_future()?);
+        time.advance(TimeDelta::minutes(61));
+        assert!(!deadline.is_in_future()?);
+        assert_eq!(DateTime::now()?.to_string(), "2024-01-01 01:01:00");
+        Ok(())
+    }
+
+    #[test]
+    fn testing_advance_period() -> Result<(), SpanError> {
+        let time = freeze_at(DateTime::new(2024, 1, 31)?);
+        time.advance_period(Period::new().months(1).hours(2));
+        assert_eq!(DateTime::now()?.to_string(), "2024-02-29 02:00:00");
+        time.advance_period(Period::new().years(-1).days(1));
+        assert_eq!(DateTime::now()?.to_string(), "2023-03-01 02:00:00");
+        time.advance_period(Period::new().years(i32::MAX));
+        assert_eq!(DateTime::now()?.to_string(), "2023-03-01 02:00:00");
+        Ok(())
+    }
+
+    #[test]
+    fn testing_travel_to() -> Result<(), SpanError> {
+        let time = travel_to(DateTime::new(2030, 6, 1)?);
+        assert!(time.now() >= *DateTime::new(2030, 6, 1)?);
+        time.speed(0.0).travel_to(DateTime::new(1999, 12, 31)?);
+        assert_eq!(DateTime::now()?.to_string(), "1999-12-31 00:00:00");
+        Ok(())
+    }
+
+    #[test]
+    fn testing_scaled() -> Result<(), SpanError> {
+        let time = scaled(DateTime::new(2024, 1, 1)?, 3600.0);
+        std::thread::sleep(std::time::Duration::from_millis(20));
+        assert!(time.now() >= *DateTime::new(2024, 1, 1)?.with_time(0, 1, 0)?);
+        Ok(())
+    }
+
+    #[test]
+    fn testing_across_threads() -> Result<(), SpanError> {
+        let _time = freeze_at(DateTime::new(2024, 2, 29)?);
+        let now = std::thread::spawn(DateTime::now).join().unwrap()?;
+        assert_eq!(now.to_string(), "2024-02-29 00:00:00");
+        Ok(())
+    }
+
+    #[test]
+    fn testing_restore_clock() -> Result<(), SpanError> {
+        {
+            let _time = freeze_at(DateTime::new(2000, 1, 1)?);
+        }
+        let _lock = TRAVEL_LOCK
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        assert!(DateTime::now()?.year() > 2000);
+        Ok(())
+    }
+}
@@ -1,28 +1,93 @@
 use std::{
-    ops::{Deref, DerefMut},
+    ops::{Add, AddAssign, Deref, DerefMut, Sub, SubAssign},
     sync::{LazyLock, RwLock},
 };
 
-use chrono::{Local, NaiveDateTime, NaiveTime, TimeDelta, Timelike, Utc};
+use chrono::{NaiveDateTime, NaiveTime, TimeDelta, Timelike, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::{
     BaseFormat, GetInner,
+    arithmetic::{Overflow, UpdatePolicy},
+    clock,
     error::{ErrorContext, SpanError, TimeError},
-    span::Span,
+    format::{DEFAULT_TIME_FORMAT, Format, FormatKind, expand},
+    locale::{BASE_LOCALE, Locale},
+    period::Period,
+    span::{Span, Unit, unit_for},
 };
 
-pub(crate) static BASE_TIME_FORMAT: BaseFormat<&'static str> =
-    LazyLock::new(|| RwLock::new("%H:%M:%S"));
+pub(crate) static BASE_TIME_FORMAT: BaseFormat<Format> =
+    LazyLock::new(|| RwLock::new(Format::compile(DEFAULT_TIME_FORMAT, FormatKind::Time)));
 
-/// Unit to update [Time]
+/// Unit to update [Time], ordered from the largest to the smallest
+///
+/// Parsed and displayed like [Unit], e.g. `"h"`, `"hour"` or `"hours"` for [TimeUnit::Hour]
 #[derive(Debug, PartialEq, PartialOrd, Eq, Ord, Clone, Copy, Serialize, Deserialize)]
+#[serde(try_from = "String")]
 pub enum TimeUnit {
     Hour,
     Minute,
     Second,
 }
 
+impl From<TimeUnit> for Unit {
+    fn from(unit: TimeUnit) -> Self {
+        match unit {
+            TimeUnit::Hour => Unit::Hour,
+            TimeUnit::Minute => Unit::Minute,
+            TimeUnit::Second => Unit::Second,
+        }
+    }
+}
+
+impl TimeUnit {
+    /// Every unit of a [Time], from the largest to the smallest
+    pub const ALL: [TimeUnit; 3] = [TimeUnit::Hour, TimeUnit::Minute, TimeUnit::Second];
+
+    /// Iterate over every unit of a [Time], from the largest to the smallest
+    pub fn iter() -> impl DoubleEndedIterator<Item = TimeUnit> {
+        Self::ALL.into_iter()
+    }
+
+    /// Number of seconds in the unit, always [Some] as time units have a fixed length
+    pub fn as_seconds(&self) -> Option<i64> {
+        Unit::from(*self).as_seconds()
+    }
+}
+
+impl std::fmt::Display for TimeUnit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Unit::from(*self).fmt(f)
+    }
+}
+
+impl TryFrom<Unit> for TimeUnit {
+    type Error = SpanError;
+    fn try_from(unit: Unit) -> Result<Self, Self::Error> {
+        unit_for(unit, FormatKind::Time, |unit| match unit {
+            Unit::Hour => Some(TimeUnit::Hour),
+            Unit::Minute => Some(TimeUnit::Minute),
+            Unit::Second => Some(TimeUnit::Second),
+            _ => None,
+        })
+    }
+}
+
+impl std::str::FromStr for TimeUnit {
+    type Err = SpanError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<Unit>()?.try_into()
+    }
+}
+
+impl TryFrom<String> for TimeUnit {
+    type Error = SpanError;
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
 /// Structure to handle time management
 ///
 /// Use [BASE_TIME_FORMAT](static@BASE_TIME_FORMAT) as default format for time
@@ -34,11 +99,14 @@ pub struct Time {
     pub(crate) time: NaiveTime,
     #[serde(skip)]
     #[serde(default = "base_time_format")]
-    pub(crate) format: String,
+    pub(crate) format: Format,
+    #[serde(skip)]
+    #[serde(default = "crate::locale::base_locale")]
+    pub(crate) locale: Locale,
 }
 
-fn base_time_format() -> String {
-    BASE_TIME_FORMAT.get().to_string()
+fn base_time_format() -> Format {
+    BASE_TIME_FORMAT.get()
 }
 
 impl Default for Time {
@@ -49,7 +117,13 @@ impl Default for Time {
 
 impl std::fmt::Display for Time {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.time.format(&self.format))
+        match self.format.items(self.locale) {
+            Some(items) => write!(f, "{}", self.time.format_with_items(items.iter())),
+            None => {
+                let format = expand(self.format.pattern(), self.locale, None, Some(self.time));
+                write!(f, "{}", self.time.format(&format))
+            }
+        }
     }
 }
 
@@ -73,6 +147,32 @@ impl Time {
         self.time
     }
 
+    /// Create a new variable [Time] from hour, minute and second
+    ///
+    /// Use the format [BASE_TIME_FORMAT](static@BASE_TIME_FORMAT) by default
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let time = Time::new(9, 27, 0)?;
+    ///
+    /// assert_eq!(time.to_string(), "09:27:00".to_string());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Return an Err(_) if the time does not exist
+    pub fn new(hour: u32, minute: u32, second: u32) -> Result<Self, SpanError> {
+        let Some(time) = NaiveTime::from_hms_opt(hour, minute, second) else {
+            return Err(SpanError::InvalidTime(hour, minute, second)).err_ctx(TimeError);
+        };
+        Ok(Self {
+            time,
+            format: BASE_TIME_FORMAT.get(),
+            locale: BASE_LOCALE.get(),
+        })
+    }
+
     /// Return midnight [Time]
     ///
     /// # Example
@@ -85,48 +185,217 @@ impl Time {
         let time = NaiveTime::from_hms_opt(0, 0, 0).expect("Error Time midnight");
         Self {
             time,
-            format: BASE_TIME_FORMAT.get().to_string(),
+            format: BASE_TIME_FORMAT.get(),
+            locale: BASE_LOCALE.get(),
         }
     }
-}
 
-impl Span<TimeUnit, u32> for Time {
-    /// Create a new variable [Time] from hour, minute and second
-    ///
-    /// Use the format [BASE_TIME_FORMAT](static@BASE_TIME_FORMAT) by default
+    /// Update the [Time] by [TimeUnit], wrapping around midnight, and return the number of days carried
     ///
     /// # Example
-    ///
     /// ```rust,ignore
-    /// let time = Time::new(9, 27, 0)?;
-    ///
-    /// assert_eq!(time.to_string(), "09:27:00".to_string());
+    /// let (time, days) = Time::new(22, 0, 0)?.overflowing_update(TimeUnit::Hour, 27);
+    /// assert_eq!(time.to_string(), "01:00:00".to_string());
+    /// assert_eq!(days, 2);
     /// ```
+    pub fn overflowing_update(&self, unit: TimeUnit, value: i32) -> (Self, i64) {
+        let seconds = match unit {
+            TimeUnit::Hour => value as i64 * 60 * 60,
+            TimeUnit::Minute => value as i64 * 60,
+            TimeUnit::Second => value as i64,
+        };
+        self.overflowing_add(TimeDelta::seconds(seconds))
+    }
+
+    /// Add a [TimeDelta] to the [Time], following the [UpdatePolicy] in use
     ///
-    /// # Errors
+    /// # Example
+    /// ```rust,ignore
+    /// let time = Time::new(23, 0, 0)?.add_delta(TimeDelta::minutes(90))?;
+    /// assert_eq!(time.to_string(), "00:30:00".to_string());
+    /// ```
+    pub fn add_delta(&self, delta: TimeDelta) -> Result<Self, SpanError> {
+        let overflow = UpdatePolicy::new().resolve().get_overflow();
+        let (time, days) = self.overflowing_add(delta);
+        self.with_overflow(time, days, overflow, Unit::Second, delta.num_seconds())
+    }
+
+    /// Add the days, hours, minutes and seconds of a [Period] to the [Time], following the
+    /// [UpdatePolicy] in use
     ///
-    /// Return an Err(_) if `time` is not formated with `format`
-    fn new(hour: u32, minute: u32, second: u32) -> Result<Self, SpanError> {
-        let Some(time) = NaiveTime::from_hms_opt(hour, minute, second) else {
-            return Err(SpanError::InvalidTime(hour, minute, second)).err_ctx(TimeError);
-        };
-        Ok(Self {
+    /// Years and months are ignored, and a day counts as 24 hours
+    pub fn add_period(&self, period: Period) -> Result<Self, SpanError> {
+        self.add_delta(period.time_delta())
+    }
+
+    /// Add a [TimeDelta], wrapping around midnight, and return the number of days carried
+    fn overflowing_add(&self, delta: TimeDelta) -> (Self, i64) {
+        let (time, carry) = self.time.overflowing_add_signed(delta);
+        let time = Self {
             time,
-            format: BASE_TIME_FORMAT.get().to_string(),
-        })
+            format: self.format.clone(),
+            locale: self.locale,
+        };
+        (time, carry / 86_400)
+    }
+
+    /// Apply `overflow` to `time`, which carried `days` days
+    fn with_overflow(
+        &self,
+        time: Self,
+        days: i64,
+        overflow: Option<Overflow>,
+        unit: Unit,
+        value: i64,
+    ) -> Result<Self, SpanError> {
+        match (days, overflow.unwrap_or(Overflow::Wrapping)) {
+            (0, _) | (_, Overflow::Wrapping) => Ok(time),
+            (days, Overflow::Saturating) => {
+                let bound = match days > 0 {
+                    true => NaiveTime::from_hms_opt(23, 59, 59),
+                    false => NaiveTime::from_hms_opt(0, 0, 0),
+                };
+                Ok(Self {
+                    time: bound.unwrap_or(time.time),
+                    ..time
+                })
+            }
+            (_, Overflow::Checked) => Err(SpanError::InvalidUpdate {
+                input: self.to_string(),
+                unit,
+                value,
+            })
+            .err_ctx(TimeError),
+        }
+    }
+}
+
+/// Add a [TimeDelta], like [Time::add_delta]
+///
+/// # Panics
+/// Panic if the result is past midnight and the [Overflow] policy is [Overflow::Checked]
+impl Add<TimeDelta> for Time {
+    type Output = Self;
+    fn add(self, rhs: TimeDelta) -> Self::Output {
+        self.add_delta(rhs).unwrap_or_else(|err| panic!("{err}"))
+    }
+}
+
+impl Sub<TimeDelta> for Time {
+    type Output = Self;
+    fn sub(self, rhs: TimeDelta) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl AddAssign<TimeDelta> for Time {
+    fn add_assign(&mut self, rhs: TimeDelta) {
+        *self = self.clone() + rhs;
+    }
+}
+
+impl SubAssign<TimeDelta> for Time {
+    fn sub_assign(&mut self, rhs: TimeDelta) {
+        *self = self.clone() - rhs;
+    }
+}
+
+/// Add a [Period], like [Time::add_period]
+///
+/// # Panics
+/// Panic if the result is past midnight and the [Overflow] policy is [Overflow::Checked]
+impl Add<Period> for Time {
+    type Output = Self;
+    fn add(self, rhs: Period) -> Self::Output {
+        self.add_period(rhs).unwrap_or_else(|err| panic!("{err}"))
+    }
+}
+
+impl Sub<Period> for Time {
+    type Output = Self;
+    fn sub(self, rhs: Period) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl AddAssign<Period> for Time {
+    fn add_assign(&mut self, rhs: Period) {
+        *self = self.clone() + rhs;
+    }
+}
+
+impl SubAssign<Period> for Time {
+    fn sub_assign(&mut self, rhs: Period) {
+        *self = self.clone() - rhs;
+    }
+}
+
+/// [TimeDelta] between two [Time], like [Span::elapsed]
+impl Sub for Time {
+    type Output = TimeDelta;
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.elapsed(&rhs)
+    }
+}
+
+impl Sub for &Time {
+    type Output = TimeDelta;
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.elapsed(rhs)
+    }
+}
+
+impl Span for Time {
+    type Unit = TimeUnit;
+    type Components = (u32, u32, u32);
+
+    /// Create a [Time] from hour, minute and second, like [Time::new]
+    fn from_components((hour, minute, second): Self::Components) -> Result<Self, SpanError> {
+        Self::new(hour, minute, second)
+    }
+
+    /// Hour, minute and second of the [Time]
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// assert_eq!(Time::new(9, 27, 0)?.components(), (9, 27, 0));
+    /// ```
+    fn components(&self) -> Self::Components {
+        (self.time.hour(), self.time.minute(), self.time.second())
     }
 
     /// Setter for the format
     ///
-    ///  See the [chrono::format::strftime] for the supported escape sequences of `format`.
+    ///  See the [chrono::format::strftime] for the supported escape sequences of `format`,
+    ///  and [crate::format] for the timeflow extensions.
     fn format(mut self, format: impl ToString) -> Self {
-        self.format = format.to_string();
+        self.format = Format::compile(format, FormatKind::Time);
         self
     }
 
+    /// Setter for a [Format] compiled beforehand, shared instead of being copied
+    fn with_format(mut self, format: Format) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Setter for the format, validated with [Format::time]
+    ///
+    /// # Errors
+    /// Return an Err(_) if `format` contains a specifier that is unknown or not allowed for a [Time]
+    fn try_format(self, format: impl ToString) -> Result<Self, SpanError> {
+        let format = Format::time(format).err_ctx(TimeError)?;
+        Ok(self.with_format(format))
+    }
+
     /// Set the format to [BASE_TIME_FORMAT](static@BASE_TIME_FORMAT)
     fn default_format(mut self) -> Self {
-        self.format = BASE_TIME_FORMAT.get().to_string();
+        self.format = BASE_TIME_FORMAT.get();
+        self
+    }
+
+    fn locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
         self
     }
 
@@ -144,24 +413,20 @@ impl Span<TimeUnit, u32> for Time {
     /// }
     /// ```
     ///
+    /// By default the time wraps around midnight, see [Overflow] to saturate or return an error instead,
+    /// and [Time::overflowing_update] to get the number of days carried.
+    ///
     /// # Errors
     /// Return an Err(_) if the operation is not possible
-    fn update(&self, unit: TimeUnit, value: i32) -> Result<Self, SpanError> {
-        let delta_time = match unit {
-            TimeUnit::Hour => TimeDelta::new(value as i64 * 60 * 60, 0),
-            TimeUnit::Minute => TimeDelta::new(value as i64 * 60, 0),
-            TimeUnit::Second => TimeDelta::new(value as i64, 0),
-        };
-        match delta_time {
-            Some(delta_time) => Ok(Self {
-                time: self.time + delta_time,
-                format: self.format.clone(),
-            }),
-            None => Err(SpanError::InvalidUpdate(format!(
-                "Cannot Add/Remove {value} {unit:?} to/from {self}"
-            )))
-            .err_ctx(TimeError),
-        }
+    fn update_with(
+        &self,
+        unit: TimeUnit,
+        value: i32,
+        policy: UpdatePolicy,
+    ) -> Result<Self, SpanError> {
+        let (time, days) = self.overflowing_update(unit, value);
+        let overflow = policy.resolve().get_overflow();
+        self.with_overflow(time, days, overflow, unit.into(), value.into())
     }
 
     /// Go to the next [TimeUnit] from [Time]
@@ -193,9 +458,9 @@ impl Span<TimeUnit, u32> for Time {
         }
     }
 
-    /// Return the current [Time] from the system
+    /// Return the current [Time] from the [Clock](crate::clock::Clock) in use
     fn now() -> Result<Self, SpanError> {
-        let time = Local::now();
+        let time = clock::now();
         Self::new(time.hour(), time.minute(), time.second())
     }
 
@@ -229,6 +494,20 @@ impl Span<TimeUnit, u32> for Time {
         })
     }
 
+    /// Return `true` if the [Time] is still to come today, strictly after [Time::now]
+    ///
+    /// A [Time] has no date, so the day ends at midnight: at 23:00, 01:00 is not in the future
+    /// even though it comes in two hours. For a time of day wrapping past midnight, use a
+    /// [TimeWindow](crate::window::TimeWindow), e.g. `TimeWindow::between(&Time::now()?, &time)`
+    /// whose duration is the time until the next occurrence, or a
+    /// [DateTime](crate::datetime::DateTime).
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// // If now is 23:00
+    /// assert!(Time::new(23, 30, 0)?.is_in_future()?);
+    /// assert!(!Time::new(1, 0, 0)?.is_in_future()?);
+    /// ```
     fn is_in_future(&self) -> Result<bool, SpanError> {
         let now = Self::now()?;
         Ok(self.time > now.time)
@@ -251,32 +530,33 @@ impl Span<TimeUnit, u32> for Time {
     /// ```
     fn clear_unit(&self, unit: TimeUnit) -> Result<Self, SpanError> {
         let time = match unit {
-            TimeUnit::Hour => self.time.with_hour(0).ok_or(SpanError::ClearUnit(
-                "Error while setting hour to 0".to_string(),
-            )),
-            TimeUnit::Minute => self.time.with_minute(0).ok_or(SpanError::ClearUnit(
-                "Error while setting minute to 0".to_string(),
-            )),
-            TimeUnit::Second => self.time.with_second(0).ok_or(SpanError::ClearUnit(
-                "Error while setting second to 0".to_string(),
-            )),
+            TimeUnit::Hour => self.time.with_hour(0),
+            TimeUnit::Minute => self.time.with_minute(0),
+            TimeUnit::Second => self.time.with_second(0),
         }
+        .ok_or_else(|| SpanError::ClearUnit {
+            input: self.to_string(),
+            unit: unit.into(),
+        })
         .err_ctx(TimeError)?;
         Ok(Self {
             time,
             format: self.format.clone(),
+            locale: self.locale,
         })
     }
 
     fn get_format(&self) -> String {
-        self.format.clone()
+        self.format.pattern().to_string()
     }
 
-    fn deserialize_with_format<'de, D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: serde::Deserializer<'de>,
-        Self: TryFrom<(String, String)>,
-    {
+    fn get_locale(&self) -> Locale {
+        self.locale
+    }
+
+    fn deserialize_with_format<'de, D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
         #[derive(Deserialize)]
         struct Visitor {
             time: String,
@@ -284,8 +564,7 @@ impl Span<TimeUnit, u32> for Time {
         }
 
         let visitor: Visitor = Deserialize::deserialize(deserializer)?;
-        Self::try_from((visitor.time, visitor.format))
-            .map_err(|_| serde::de::Error::custom("Invalid time"))
+        Self::try_from((visitor.time, visitor.format)).map_err(serde::de::Error::custom)
     }
 }
 
@@ -293,7 +572,8 @@ impl From<NaiveDateTime> for Time {
     fn from(datetime: NaiveDateTime) -> Self {
         Self {
             time: datetime.time(),
-            format: BASE_TIME_FORMAT.get().to_string(),
+            format: BASE_TIME_FORMAT.get(),
+            locale: BASE_LOCALE.get(),
         }
     }
 }
@@ -302,55 +582,82 @@ impl From<NaiveTime> for Time {
     fn from(time: NaiveTime) -> Self {
         Self {
             time,
-            format: BASE_TIME_FORMAT.get().to_string(),
+            format: BASE_TIME_FORMAT.get(),
+            locale: BASE_LOCALE.get(),
         }
     }
 }
 
 impl TryFrom<(String, String)> for Time {
     type Error = SpanError;
-
     fn try_from((time, format): (String, String)) -> Result<Self, Self::Error> {
-        let time = NaiveTime::parse_from_str(&time, &format)
-            .map_err(SpanError::ParseFromStr)
-            .err_ctx(TimeError)?;
-        Ok(Self { time, format })
+        Self::try_from((time.as_str(), format.as_str()))
     }
 }
 
 impl TryFrom<(&str, &str)> for Time {
     type Error = SpanError;
-
     fn try_from((time, format): (&str, &str)) -> Result<Self, Self::Error> {
-        let time = NaiveTime::parse_from_str(time, format)
-            .map_err(SpanError::ParseFromStr)
+        Self::try_from((time, format, BASE_LOCALE.get()))
+    }
+}
+
+/// Parse a [Time] whose month names, weekday names and AM/PM markers are written in `locale`
+///
+/// # Example
+/// ```rust,ignore
+/// let time = Time::try_from(("3:05 p. m.", "%-I:%M %p", Locale::Es))?;
+/// assert_eq!(time.hour(), 15);
+/// ```
+impl TryFrom<(&str, &str, Locale)> for Time {
+    type Error = SpanError;
+    fn try_from((time, format, locale): (&str, &str, Locale)) -> Result<Self, Self::Error> {
+        let format = Format::compile(format, FormatKind::Time);
+        Self::try_from((time, &format, locale))
+    }
+}
+
+/// Parse a [Time] with a [Format] compiled beforehand
+///
+/// The [Format] is shared with the parsed [Time], the pattern is neither copied nor parsed again.
+///
+/// # Example
+/// ```rust,ignore
+/// let format = Format::time("%H:%M:%S")?;
+/// let values = lines.iter().map(|line| Time::try_from((line.as_str(), &format)));
+/// ```
+impl TryFrom<(&str, &Format)> for Time {
+    type Error = SpanError;
+    fn try_from((time, format): (&str, &Format)) -> Result<Self, Self::Error> {
+        Self::try_from((time, format, BASE_LOCALE.get()))
+    }
+}
+
+impl TryFrom<(&str, &Format, Locale)> for Time {
+    type Error = SpanError;
+    fn try_from((time, format, locale): (&str, &Format, Locale)) -> Result<Self, Self::Error> {
+        let time = format
+            .parse_with(time, locale, |parsed| parsed.to_naive_time())
             .err_ctx(TimeError)?;
         Ok(Self {
             time,
-            format: format.to_string(),
+            format: format.clone(),
+            locale,
         })
     }
 }
 
 impl TryFrom<String> for Time {
     type Error = SpanError;
-
     fn try_from(time: String) -> Result<Self, Self::Error> {
-        let time = NaiveTime::parse_from_str(&time, BASE_TIME_FORMAT.get())
-            .map_err(SpanError::ParseFromStr)
-            .err_ctx(TimeError)?;
-        Self::new(time.hour(), time.minute(), time.second())
+        Self::try_from(time.as_str())
     }
 }
 
 impl TryFrom<&str> for Time {
     type Error = SpanError;
-
     fn try_from(time: &str) -> Result<Self, Self::Error> {
-        let time = NaiveTime::parse_from_str(time, BASE_TIME_FORMAT.get())
-            .map_err(SpanError::ParseFromStr)
-            .err_ctx(TimeError)?;
-        Self::new(time.hour(), time.minute(), time.second())
+        Self::try_from((time, &BASE_TIME_FORMAT.get()))
     }
 }
 
@@ -365,7 +672,7 @@ impl TryFrom<&Time> for chrono::DateTime<Utc> {
     type Error = SpanError;
     fn try_from(value: &Time) -> Result<Self, Self::Error> {
         let date = value.time;
-        match Utc::now()
+        match clock::now_utc()
             .with_hour(date.hour())
             .and_then(|utc| utc.with_minute(date.minute()))
             .and_then(|utc| utc.with_second(date.second()))
@@ -379,6 +686,32 @@ impl TryFrom<&Time> for chrono::DateTime<Utc> {
 #[cfg(test)]
 pub mod test {
     use super::*;
+    use crate::{
+        clock::test::fixed_context,
+        context::{Context, with_context},
+    };
+
+    #[test]
+    fn is_in_future_from_clock() -> Result<(), SpanError> {
+        with_context(&fixed_context("2023-10-09T12:00:00"), || {
+            assert_eq!(Time::now()?.to_string(), "12:00:00".to_string());
+            assert!(Time::new(12, 0, 1)?.is_in_future()?);
+            assert!(!Time::new(12, 0, 0)?.is_in_future()?);
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn is_in_future_ends_at_midnight() -> Result<(), SpanError> {
+        with_context(&fixed_context("2023-10-09T23:00:00"), || {
+            assert!(Time::new(23, 30, 0)?.is_in_future()?);
+            assert!(!Time::new(1, 0, 0)?.is_in_future()?);
+            // The time until the next 01:00 wraps past midnight
+            let until = crate::window::TimeWindow::between(&Time::now()?, &Time::new(1, 0, 0)?);
+            assert_eq!(until.duration(), TimeDelta::hours(2));
+            Ok(())
+        })
+    }
 
     #[test]
     fn time_add_overflow() -> Result<(), SpanError> {
@@ -388,6 +721,118 @@ pub mod test {
         Ok(())
     }
 
+    #[test]
+    fn time_update_checked() -> Result<(), SpanError> {
+        let policy = UpdatePolicy::new().overflow(Overflow::Checked);
+        let time = Time::new(23, 0, 0)?;
+        assert_eq!(
+            time.update_with(TimeUnit::Minute, 59, policy)?.to_string(),
+            "23:59:00"
+        );
+        assert_eq!(
+            time.update_with(TimeUnit::Hour, 1, policy),
+            Err(SpanError::InvalidUpdate {
+                input: "23:00:00".to_string(),
+                unit: Unit::Hour,
+                value: 1,
+            })
+            .err_ctx(TimeError)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn time_update_saturating() -> Result<(), SpanError> {
+        let policy = UpdatePolicy::new().overflow(Overflow::Saturating);
+        let time = Time::new(23, 0, 0)?;
+        assert_eq!(
+            time.update_with(TimeUnit::Hour, 2, policy)?.to_string(),
+            "23:59:59"
+        );
+        assert_eq!(
+            time.update_with(TimeUnit::Hour, -24, policy)?.to_string(),
+            "00:00:00"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn time_overflowing_update() -> Result<(), SpanError> {
+        let time = Time::new(22, 0, 0)?;
+        let (next, days) = time.overflowing_update(TimeUnit::Hour, 50);
+        assert_eq!((next.to_string(), days), ("00:00:00".to_string(), 3));
+        let (previous, days) = time.overflowing_update(TimeUnit::Hour, -23);
+        assert_eq!((previous.to_string(), days), ("23:00:00".to_string(), -1));
+        Ok(())
+    }
+
+    #[test]
+    fn time_ops_time_delta() -> Result<(), SpanError> {
+        let time = Time::new(23, 0, 0)?.format("%Hh%M");
+        let mut next = time.clone() + TimeDelta::minutes(90);
+        assert_eq!(next.to_string(), "00h30");
+        next -= TimeDelta::hours(1);
+        assert_eq!(next.to_string(), "23h30");
+        assert_eq!(&next - &time, TimeDelta::minutes(30));
+        assert_eq!(time - next, TimeDelta::minutes(-30));
+        Ok(())
+    }
+
+    #[test]
+    fn time_ops_period() -> Result<(), SpanError> {
+        let mut time = Time::new(8, 0, 0)? + Period::new().years(1).days(1).hours(2);
+        assert_eq!(time.to_string(), "10:00:00");
+        time -= Period::new().minutes(30).seconds(15);
+        assert_eq!(time.to_string(), "09:29:45");
+        Ok(())
+    }
+
+    #[test]
+    fn time_add_delta_checked() -> Result<(), SpanError> {
+        let mut context = Context::new();
+        context.policy(UpdatePolicy::new().overflow(Overflow::Checked));
+        let time = Time::new(23, 0, 0)?;
+        assert_eq!(
+            with_context(&context, || time.add_delta(TimeDelta::hours(2))),
+            Err(SpanError::InvalidUpdate {
+                input: "23:00:00".to_string(),
+                unit: Unit::Second,
+                value: 7200,
+            })
+            .err_ctx(TimeError)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn time_components() -> Result<(), SpanError> {
+        let time = Time::from_components((23, 59, 1))?;
+        assert_eq!(time.components(), (23, 59, 1));
+        assert_eq!(
+            Time::from_components((24, 0, 0)),
+            Err(SpanError::InvalidTime(24, 0, 0)).err_ctx(TimeError)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn time_unit_parse_and_display() -> Result<(), SpanError> {
+        assert_eq!("h".parse::<TimeUnit>()?, TimeUnit::Hour);
+        assert_eq!("m".parse::<TimeUnit>()?, TimeUnit::Minute);
+        assert_eq!(TimeUnit::Second.to_string(), "Second");
+        assert_eq!(
+            "M".parse::<TimeUnit>().map_err(|err| err.code()),
+            Err("unit_not_allowed")
+        );
+        assert_eq!(
+            "week".parse::<TimeUnit>(),
+            Err(SpanError::UnknownUnit("week".to_string()))
+        );
+        let seconds: Vec<Option<i64>> = TimeUnit::iter().map(|unit| unit.as_seconds()).collect();
+        assert_eq!(seconds, vec![Some(3_600), Some(60), Some(1)]);
+        Ok(())
+    }
+
     #[test]
     fn time_add_one_hour() -> Result<(), SpanError> {
         let time = Time::new(0, 0, 0)?;
@@ -453,7 +898,7 @@ pub mod test {
             panic!("Error while deserializing time");
         };
         assert_eq!(time.to_string(), "12:21:46".to_string());
-        assert_eq!(time.format, BASE_TIME_FORMAT.get().to_string());
+        assert_eq!(time.format, BASE_TIME_FORMAT.get());
         Ok(())
     }
 
@@ -512,7 +957,7 @@ pub mod test {
             panic!("Error while deserializing time");
         };
         assert_eq!(test.begin_at.to_string(), "23:10:09".to_string());
-        assert_eq!(test.begin_at.format, BASE_TIME_FORMAT.get().to_string());
+        assert_eq!(test.begin_at.format, BASE_TIME_FORMAT.get());
         Ok(())
     }
 
@@ -614,6 +1059,29 @@ pub mod test {
         assert_eq!(time.to_string(), "00:00:00".to_string());
         Ok(())
     }
+
+    #[test]
+    fn time_display_locale() -> Result<(), SpanError> {
+        let time = Time::new(15, 5, 0)?.format("%-I:%M %p").locale(Locale::Es);
+        assert_eq!(time.to_string(), "3:05 p. m.".to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn time_try_format() -> Result<(), SpanError> {
+        let time = Time::new(12, 21, 46)?.try_format("%Hh%M")?;
+        assert_eq!(time.to_string(), "12h21".to_string());
+        assert!(Time::new(12, 21, 46)?.try_format("%Y %H").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn time_parse_locale() -> Result<(), SpanError> {
+        let time = Time::try_from(("3:05 p. m.", "%-I:%M %p", Locale::Es))?;
+        assert_eq!(time.hour(), 15);
+        assert_eq!(time.minute(), 5);
+        Ok(())
+    }
 }
 
 #[cfg(all(feature = "time", feature = "datetime"))]
@@ -635,14 +1103,14 @@ mod datetime_into_time {
             let time = value.datetime().time();
             Self {
                 time,
-                format: crate::time::BASE_TIME_FORMAT.get().to_string(),
+                format: crate::time::BASE_TIME_FORMAT.get(),
+                locale: value.locale,
             }
         }
     }
 
     #[cfg(test)]
     mod test {
-        use crate::span::Span;
 
         #[test]
         fn datetime_into_time() -> Result<(), crate::error::SpanError> {
@@ -653,16 +1121,18 @@ mod datetime_into_time {
         }
 
         #[test]
-        #[ignore]
         fn datetime_into_time_wrong_format() -> Result<(), crate::error::SpanError> {
-            crate::builder::SpanBuilder::builder()
+            let context = crate::builder::SpanBuilder::builder()
                 .datetime_format("%Y-%m-%d %H:%M:%S")
                 .time_format("%H_%M_%S")
-                .build();
-            let datetime = crate::datetime::DateTime::new(2021, 10, 10)?.with_time(12, 34, 56)?;
-            let time = crate::time::Time::from(datetime);
-            assert_eq!(time.to_string(), "12_34_56".to_string());
-            Ok(())
+                .context();
+            crate::context::with_context(&context, || {
+                let datetime =
+                    crate::datetime::DateTime::new(2021, 10, 10)?.with_time(12, 34, 56)?;
+                let time = crate::time::Time::from(datetime);
+                assert_eq!(time.to_string(), "12_34_56".to_string());
+                Ok(())
+            })
         }
     }
 }
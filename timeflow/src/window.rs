@@ -0,0 +1,446 @@
+//! Time-of-day windows and weekly schedules
+//!
+//! A [TimeWindow] such as `22:00-06:00` may wrap around midnight, which two [Time](crate::time::Time)
+//! comparisons cannot express. A [WeeklySchedule] lists windows per day of the week, a window
+//! wrapping midnight belonging to the day it starts on.
+//!
+//! # Example
+//! ```rust,ignore
+//! let night = TimeWindow::from_str("22:00-06:00")?;
+//! assert!(night.contains(&Time::new(23, 0, 0)?));
+//! assert_eq!(night.duration(), TimeDelta::hours(8));
+//!
+//! let on_call = WeeklySchedule::new().window(Weekday::Fri, night);
+//! // Saturday 02:00 belongs to the Friday night
+//! assert!(on_call.contains(&DateTime::build("2023-10-14 02:00:00")?));
+//! ```
+
+use std::{fmt::Display, str::FromStr};
+
+use chrono::{NaiveTime, TimeDelta, Weekday};
+use serde::{Deserialize, Serialize};
+
+use crate::{error::SpanError, matcher::Matchable};
+
+/// Window of the day from `start` included to `end` excluded
+///
+/// When `end` is before `start` the window wraps around midnight, and when both are equal the
+/// window covers the whole day.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Serialize, Deserialize)]
+pub struct TimeWindow {
+    start: NaiveTime,
+    end: NaiveTime,
+}
+
+impl TimeWindow {
+    /// Create a window from `start` to `end`
+    pub fn new(start: NaiveTime, end: NaiveTime) -> Self {
+        Self { start, end }
+    }
+
+    /// Create a window from the [Time](crate::time::Time) `start` to `end`
+    #[cfg(feature = "time")]
+    pub fn between(start: &crate::time::Time, end: &crate::time::Time) -> Self {
+        Self::new(start.time(), end.time())
+    }
+
+    /// Getter for the start of the window
+    pub fn start(&self) -> NaiveTime {
+        self.start
+    }
+
+    /// Getter for the end of the window
+    pub fn end(&self) -> NaiveTime {
+        self.end
+    }
+
+    /// Return `true` if the window goes past midnight
+    pub fn wraps_midnight(&self) -> bool {
+        self.end <= self.start && self.end != NaiveTime::MIN
+    }
+
+    /// Return `true` if the time of the day of `value` is in the window, always `false` for a
+    /// value without time
+    pub fn contains(&self, value: &impl Matchable) -> bool {
+        value
+            .time_of_day()
+            .is_some_and(|time| self.contains_time(time))
+    }
+
+    pub(crate) fn contains_time(&self, time: NaiveTime) -> bool {
+        match self.start.cmp(&self.end) {
+            std::cmp::Ordering::Less => self.start <= time && time < self.end,
+            std::cmp::Ordering::Greater => self.start <= time || time < self.end,
+            std::cmp::Ordering::Equal => true,
+        }
+    }
+
+    /// Length of the window, 24 hours when `start` equals `end`
+    pub fn duration(&self) -> TimeDelta {
+        let duration = self.end - self.start;
+        match duration <= TimeDelta::zero() {
+            true => duration + TimeDelta::days(1),
+            false => duration,
+        }
+    }
+}
+
+impl Display for TimeWindow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let format = |time: NaiveTime| match chrono::Timelike::second(&time) {
+            0 => time.format("%H:%M").to_string(),
+            _ => time.format("%H:%M:%S").to_string(),
+        };
+        let end = match self.end == NaiveTime::MIN && self.start != NaiveTime::MIN {
+            true => "24:00".to_string(),
+            false => format(self.end),
+        };
+        write!(f, "{}-{end}", format(self.start))
+    }
+}
+
+impl FromStr for TimeWindow {
+    type Err = SpanError;
+
+    /// Parse a window written `HH:MM-HH:MM`, seconds being optional and `24:00` allowed as end
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || SpanError::InvalidWindow(s.to_string());
+        let (start, end) = s.split_once('-').ok_or_else(invalid)?;
+        let parse = |time: &str| {
+            NaiveTime::parse_from_str(time.trim(), "%H:%M:%S")
+                .or_else(|_| NaiveTime::parse_from_str(time.trim(), "%H:%M"))
+                .map_err(|_| invalid())
+        };
+        let end = match end.trim() {
+            "24:00" | "24:00:00" => NaiveTime::MIN,
+            end => parse(end)?,
+        };
+        Ok(Self::new(parse(start)?, end))
+    }
+}
+
+/// Windows of the day for each day of the week
+///
+/// Serialized with one optional list of windows per day, from `mon` to `sun`.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WeeklySchedule {
+    mon: Vec<TimeWindow>,
+    tue: Vec<TimeWindow>,
+    wed: Vec<TimeWindow>,
+    thu: Vec<TimeWindow>,
+    fri: Vec<TimeWindow>,
+    sat: Vec<TimeWindow>,
+    sun: Vec<TimeWindow>,
+}
+
+impl WeeklySchedule {
+    /// Create a schedule without any window
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add `window` to the windows starting on `weekday`
+    pub fn window(mut self, weekday: Weekday, window: TimeWindow) -> Self {
+        self.windows_mut(weekday).push(window);
+        self
+    }
+
+    /// Add `window` to the windows starting on each of `weekdays`
+    pub fn windows_on(
+        mut self,
+        weekdays: impl IntoIterator<Item = Weekday>,
+        window: TimeWindow,
+    ) -> Self {
+        for weekday in weekdays {
+            self = self.window(weekday, window);
+        }
+        self
+    }
+
+    /// Windows starting on `weekday`
+    pub fn windows(&self, weekday: Weekday) -> &[TimeWindow] {
+        match weekday {
+            Weekday::Mon => &self.mon,
+            Weekday::Tue => &self.tue,
+            Weekday::Wed => &self.wed,
+            Weekday::Thu => &self.thu,
+            Weekday::Fri => &self.fri,
+            Weekday::Sat => &self.sat,
+            Weekday::Sun => &self.sun,
+        }
+    }
+
+    pub(crate) fn windows_mut(&mut self, weekday: Weekday) -> &mut Vec<TimeWindow> {
+        match weekday {
+            Weekday::Mon => &mut self.mon,
+            Weekday::Tue => &mut self.tue,
+            Weekday::Wed => &mut self.wed,
+            Weekday::Thu => &mut self.thu,
+            Weekday::Fri => &mut self.fri,
+            Weekday::Sat => &mut self.sat,
+            Weekday::Sun => &mut self.sun,
+        }
+    }
+
+    /// Return `true` if no day has a window
+    pub fn is_empty(&self) -> bool {
+        [
+            &self.mon, &self.tue, &self.wed, &self.thu, &self.fri, &self.sat, &self.sun,
+        ]
+        .iter()
+        .all(|windows| windows.is_empty())
+    }
+}
+
+#[cfg(feature = "datetime")]
+mod datetime {
+    use chrono::{Datelike, Days, NaiveDate, NaiveDateTime};
+
+    use super::*;
+    use crate::datetime::DateTime;
+
+    impl TimeWindow {
+        /// Start and end of the occurrence of the window starting on `date`, [None] if it ends
+        /// out of the range of [NaiveDateTime]
+        pub(crate) fn on(&self, date: NaiveDate) -> Option<(NaiveDateTime, NaiveDateTime)> {
+            let start = date.and_time(self.start);
+            Some((start, start.checked_add_signed(self.duration())?))
+        }
+
+        /// First start of the window strictly after `datetime`, keeping its format and locale
+        ///
+        /// Return [None] if it is out of the range of [DateTime].
+        ///
+        /// # Example
+        /// ```rust,ignore
+        /// let night = TimeWindow::from_str("22:00-06:00")?;
+        /// let datetime = DateTime::build("2023-10-09 23:00:00")?;
+        /// assert_eq!(night.next_start_after(&datetime).unwrap().to_string(), "2023-10-10 22:00:00");
+        /// ```
+        pub fn next_start_after(&self, datetime: &DateTime) -> Option<DateTime> {
+            next_after(datetime, self.start)
+        }
+
+        /// First end of the window strictly after `datetime`, keeping its format and locale
+        ///
+        /// Return [None] if it is out of the range of [DateTime].
+        pub fn next_end_after(&self, datetime: &DateTime) -> Option<DateTime> {
+            next_after(datetime, self.end)
+        }
+    }
+
+    /// First `time` of the day strictly after `datetime`
+    fn next_after(datetime: &DateTime, time: NaiveTime) -> Option<DateTime> {
+        let candidate = datetime.date().and_time(time);
+        let candidate = match candidate > datetime.datetime() {
+            true => candidate,
+            false => candidate.checked_add_days(Days::new(1))?,
+        };
+        Some(datetime.with_naive(candidate))
+    }
+
+    impl WeeklySchedule {
+        /// Occurrences of the windows starting on `date`
+        pub(crate) fn occurrences_on(
+            &self,
+            date: NaiveDate,
+        ) -> impl Iterator<Item = (NaiveDateTime, NaiveDateTime)> + '_ {
+            self.windows(date.weekday())
+                .iter()
+                .filter_map(move |window| window.on(date))
+        }
+
+        /// Return `true` if `datetime` is in a window, including a window of the day before
+        /// wrapping past midnight
+        pub fn contains(&self, datetime: &DateTime) -> bool {
+            let naive = datetime.datetime();
+            [naive.date().pred_opt(), Some(naive.date())]
+                .into_iter()
+                .flatten()
+                .flat_map(|date| self.occurrences_on(date))
+                .any(|(start, end)| start <= naive && naive < end)
+        }
+
+        /// First start of a window strictly after `datetime`, keeping its format and locale
+        ///
+        /// Return [None] if the schedule is empty or the start is out of the range of
+        /// [DateTime].
+        pub fn next_start_after(&self, datetime: &DateTime) -> Option<DateTime> {
+            let naive = datetime.datetime();
+            naive
+                .date()
+                .iter_days()
+                .take(8)
+                .flat_map(|date| self.occurrences_on(date))
+                .map(|(start, _)| start)
+                .filter(|start| *start > naive)
+                .min()
+                .map(|start| datetime.with_naive(start))
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+
+    #[test]
+    fn window_contains_and_duration() -> Result<(), SpanError> {
+        let day = TimeWindow::from_str("09:00-17:30")?;
+        let night = TimeWindow::from_str("22:00-06:00")?;
+        let all_day = TimeWindow::new("00:00:00".parse().unwrap(), "00:00:00".parse().unwrap());
+        assert!(day.contains_time("09:00:00".parse().unwrap()));
+        assert!(!day.contains_time("17:30:00".parse().unwrap()));
+        assert!(night.contains_time("23:00:00".parse().unwrap()));
+        assert!(night.contains_time("05:59:59".parse().unwrap()));
+        assert!(!night.contains_time("06:00:00".parse().unwrap()));
+        assert!(!night.contains_time("12:00:00".parse().unwrap()));
+        assert!(all_day.contains_time("12:00:00".parse().unwrap()));
+        assert!(night.wraps_midnight() && !day.wraps_midnight());
+        assert_eq!(day.duration(), TimeDelta::minutes(510));
+        assert_eq!(night.duration(), TimeDelta::hours(8));
+        assert_eq!(all_day.duration(), TimeDelta::days(1));
+        Ok(())
+    }
+
+    #[test]
+    fn window_parse_and_display() -> Result<(), SpanError> {
+        let evening = TimeWindow::from_str("18:00 - 24:00")?;
+        assert_eq!(
+            evening,
+            TimeWindow::new("18:00:00".parse().unwrap(), "00:00:00".parse().unwrap())
+        );
+        assert!(!evening.wraps_midnight());
+        assert_eq!(evening.duration(), TimeDelta::hours(6));
+        assert_eq!(evening.to_string(), "18:00-24:00");
+        assert_eq!(
+            TimeWindow::from_str("08:00:30-12:00")?.to_string(),
+            "08:00:30-12:00"
+        );
+        let err = TimeWindow::from_str("8h-12h").unwrap_err();
+        assert_eq!(err.code(), "invalid_window");
+        assert!(TimeWindow::from_str("08:00").is_err());
+        Ok(())
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn window_time() -> Result<(), SpanError> {
+        use crate::time::Time;
+
+        let night = TimeWindow::between(&Time::new(22, 0, 0)?, &Time::new(6, 0, 0)?);
+        assert!(night.contains(&Time::new(1, 0, 0)?));
+        assert!(!night.contains(&Time::new(21, 59, 59)?));
+        Ok(())
+    }
+
+    #[cfg(feature = "datetime")]
+    #[test]
+    fn window_next_start_after() -> Result<(), SpanError> {
+        use crate::datetime::DateTime;
+
+        let night = TimeWindow::from_str("22:00-06:00")?;
+        let datetime = DateTime::new(2023, 10, 9)?.with_time(21, 0, 0)?;
+        assert_eq!(
+            night.next_start_after(&datetime).unwrap().to_string(),
+            "2023-10-09 22:00:00"
+        );
+        let datetime = datetime.with_time(22, 0, 0)?;
+        assert_eq!(
+            night.next_start_after(&datetime).unwrap().to_string(),
+            "2023-10-10 22:00:00"
+        );
+        assert_eq!(
+            night.next_end_after(&datetime).unwrap().to_string(),
+            "2023-10-10 06:00:00"
+        );
+        Ok(())
+    }
+
+    #[cfg(feature = "datetime")]
+    #[test]
+    fn weekly_schedule() -> Result<(), SpanError> {
+        use crate::datetime::DateTime;
+
+        let schedule = WeeklySchedule::new()
+            .windows_on(
+                [Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu],
+                TimeWindow::from_str("09:00-17:00")?,
+            )
+            .window(Weekday::Fri, TimeWindow::from_str("22:00-06:00")?);
+        assert!(!schedule.is_empty());
+        assert!(WeeklySchedule::new().is_empty());
+        // Monday
+        let monday = DateTime::new(2023, 10, 9)?;
+        assert!(schedule.contains(&monday.clone().with_time(9, 0, 0)?));
+        assert!(!schedule.contains(&monday.clone().with_time(17, 0, 0)?));
+        // Saturday night belongs to Friday
+        let saturday = DateTime::new(2023, 10, 14)?;
+        assert!(schedule.contains(&saturday.clone().with_time(2, 0, 0)?));
+        assert!(!schedule.contains(&saturday.clone().with_time(22, 0, 0)?));
+        assert_eq!(
+            schedule
+                .next_start_after(&saturday.with_time(2, 0, 0)?)
+                .unwrap()
+                .to_string(),
+            "2023-10-16 09:00:00"
+        );
+        assert_eq!(
+            schedule
+                .next_start_after(&monday.with_time(17, 0, 0)?)
+                .unwrap()
+                .to_string(),
+            "2023-10-10 09:00:00"
+        );
+        assert_eq!(
+            WeeklySchedule::new().next_start_after(&DateTime::new(2023, 10, 9)?),
+            None
+        );
+        Ok(())
+    }
+
+    #[cfg(feature = "datetime")]
+    #[test]
+    fn weekly_schedule_range_end() -> Result<(), SpanError> {
+        use crate::datetime::DateTime;
+
+        let night = TimeWindow::from_str("22:00-06:00")?;
+        let schedule = WeeklySchedule::new().windows_on(
+            [
+                Weekday::Mon,
+                Weekday::Tue,
+                Weekday::Wed,
+                Weekday::Thu,
+                Weekday::Fri,
+                Weekday::Sat,
+                Weekday::Sun,
+            ],
+            night,
+        );
+        // The last night ends out of range and is dropped
+        let last = DateTime::from(chrono::NaiveDateTime::MAX);
+        assert!(!schedule.contains(&last));
+        assert!(schedule.contains(&last.with_time(5, 0, 0)?));
+        assert_eq!(
+            schedule.next_start_after(&DateTime::from(chrono::NaiveDateTime::MAX)),
+            None
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn weekly_schedule_deserialize() -> Result<(), serde_json::Error> {
+        let schedule: WeeklySchedule =
+            serde_json::from_str(r#"{"sat": [{"start": "10:00:00", "end": "13:00:00"}]}"#)?;
+        assert_eq!(
+            schedule,
+            WeeklySchedule::new().window(
+                Weekday::Sat,
+                TimeWindow::new("10:00:00".parse().unwrap(), "13:00:00".parse().unwrap())
+            )
+        );
+        assert!(schedule.windows(Weekday::Mon).is_empty());
+        Ok(())
+    }
+}
@@ -0,0 +1,341 @@
+//! Working-time arithmetic for SLAs
+//!
+//! A [WorkingCalendar] counts only the time inside weekly working windows, skipping holidays.
+//! It is the working-time counterpart of [Span::elapsed](crate::span::Span::elapsed) and of
+//! [Span::update](crate::span::Span::update) with [DateTimeUnit::Hour](crate::datetime::DateTimeUnit::Hour).
+//!
+//! # Example
+//! ```rust,ignore
+//! let calendar = WorkingCalendar::from_str("Mo-Fr 09:00-17:00; 2023 Oct 13 off")?;
+//! // Opened on Thursday 15:00, due 8 working hours later, the Friday being a holiday
+//! let opened = DateTime::build("2023-10-12 15:00:00")?;
+//! let due = calendar.add_working_hours(&opened, 8)?;
+//! assert_eq!(due.to_string(), "2023-10-16 15:00:00");
+//! assert_eq!(calendar.working_time_between(&opened, &due), TimeDelta::hours(8));
+//! ```
+
+use std::str::FromStr;
+
+use chrono::{Days, NaiveDate, NaiveDateTime, TimeDelta};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    datetime::DateTime,
+    error::{DateTimeError, ErrorContext, SpanError},
+    opening::OpeningHours,
+    span::Unit,
+    window::WeeklySchedule,
+};
+
+/// Days of working windows looked at in one go
+const CHUNK_DAYS: u64 = 7;
+
+/// Weekly working windows and holidays
+///
+/// Serialized like [OpeningHours], holidays being exceptions without window.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct WorkingCalendar {
+    hours: OpeningHours,
+}
+
+impl WorkingCalendar {
+    /// Create a calendar working during the windows of `weekly`
+    pub fn new(weekly: WeeklySchedule) -> Self {
+        Self {
+            hours: OpeningHours::new(weekly),
+        }
+    }
+
+    /// Do not work on `date`
+    pub fn holiday(mut self, date: NaiveDate) -> Self {
+        self.hours = self.hours.closed_on(date);
+        self
+    }
+
+    /// Do not work on any of `dates`
+    pub fn holidays(self, dates: impl IntoIterator<Item = NaiveDate>) -> Self {
+        dates.into_iter().fold(self, Self::holiday)
+    }
+
+    /// Getter for the opening hours of the calendar
+    pub fn hours(&self) -> &OpeningHours {
+        &self.hours
+    }
+
+    /// Return `true` if `datetime` is working time
+    pub fn is_working(&self, datetime: &DateTime) -> bool {
+        self.hours.is_open(datetime)
+    }
+
+    /// Working time from `start` to `end`, negative if `end` is before `start`
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let calendar = WorkingCalendar::from_str("Mo-Fr 09:00-17:00")?;
+    /// let friday = DateTime::build("2023-10-13 16:00:00")?;
+    /// let monday = DateTime::build("2023-10-16 10:00:00")?;
+    /// assert_eq!(calendar.working_time_between(&friday, &monday), TimeDelta::hours(2));
+    /// ```
+    pub fn working_time_between(&self, start: &DateTime, end: &DateTime) -> TimeDelta {
+        match end < start {
+            true => -self.hours.open_duration(end, start),
+            false => self.hours.open_duration(start, end),
+        }
+    }
+
+    /// Add `hours` working hours to `datetime`, keeping its format and locale
+    ///
+    /// # Errors
+    /// Return an Err(_) if the calendar has no working time or the result is out of range
+    pub fn add_working_hours(
+        &self,
+        datetime: &DateTime,
+        hours: i64,
+    ) -> Result<DateTime, SpanError> {
+        let delta = TimeDelta::try_hours(hours)
+            .ok_or_else(|| invalid(datetime, hours.saturating_mul(3_600)))
+            .err_ctx(DateTimeError)?;
+        self.add_working_time(datetime, delta)
+    }
+
+    /// Add `delta` of working time to `datetime`, or remove it when negative, keeping its format
+    /// and locale
+    ///
+    /// When the count runs out exactly at the end of a window, the result is that end rather
+    /// than the start of the next window.
+    ///
+    /// # Errors
+    /// Return an Err(_) if the calendar has no working time or the result is out of range
+    pub fn add_working_time(
+        &self,
+        datetime: &DateTime,
+        delta: TimeDelta,
+    ) -> Result<DateTime, SpanError> {
+        if delta.is_zero() {
+            return Ok(datetime.clone());
+        }
+        if self.hours.weekly().is_empty() {
+            return Err(invalid(datetime, delta.num_seconds())).err_ctx(DateTimeError);
+        }
+        match delta > TimeDelta::zero() {
+            true => self.forward(datetime.datetime(), delta),
+            false => self.backward(datetime.datetime(), -delta),
+        }
+        .map(|naive| datetime.with_naive(naive))
+        .ok_or_else(|| invalid(datetime, delta.num_seconds()))
+        .err_ctx(DateTimeError)
+    }
+
+    /// Instant after `remaining` working time from `cursor`
+    fn forward(
+        &self,
+        mut cursor: NaiveDateTime,
+        mut remaining: TimeDelta,
+    ) -> Option<NaiveDateTime> {
+        let mut first = cursor.date();
+        loop {
+            let last = first.checked_add_days(Days::new(CHUNK_DAYS))?;
+            for (start, end) in self.hours.intervals(first, last) {
+                if end <= cursor {
+                    continue;
+                }
+                let start = start.max(cursor);
+                if remaining <= end - start {
+                    return Some(start + remaining);
+                }
+                remaining -= end - start;
+                cursor = end;
+            }
+            first = last.succ_opt()?;
+        }
+    }
+
+    /// Instant `remaining` working time before `cursor`
+    fn backward(
+        &self,
+        mut cursor: NaiveDateTime,
+        mut remaining: TimeDelta,
+    ) -> Option<NaiveDateTime> {
+        let mut last = cursor.date();
+        loop {
+            let first = last.checked_sub_days(Days::new(CHUNK_DAYS))?;
+            for (start, end) in self.hours.intervals(first, last).into_iter().rev() {
+                if start >= cursor {
+                    continue;
+                }
+                let end = end.min(cursor);
+                if remaining <= end - start {
+                    return Some(end - remaining);
+                }
+                remaining -= end - start;
+                cursor = start;
+            }
+            last = first.pred_opt()?;
+        }
+    }
+}
+
+/// Error of an update of `datetime` by `seconds` of working time
+fn invalid(datetime: &DateTime, seconds: i64) -> SpanError {
+    SpanError::InvalidUpdate {
+        input: datetime.to_string(),
+        unit: Unit::Second,
+        value: seconds,
+    }
+}
+
+impl From<OpeningHours> for WorkingCalendar {
+    fn from(hours: OpeningHours) -> Self {
+        Self { hours }
+    }
+}
+
+impl FromStr for WorkingCalendar {
+    type Err = SpanError;
+
+    /// Parse a calendar written like [OpeningHours], holidays being dates `off`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        OpeningHours::from_str(s).map(Self::from)
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use crate::window::TimeWindow;
+
+    const CALENDAR: &str = "Mo-Fr 09:00-17:00; 2023 Oct 13 off";
+
+    #[test]
+    fn working_add_hours() -> Result<(), SpanError> {
+        let calendar = WorkingCalendar::from_str(CALENDAR)?;
+        // Thursday 15:00, Friday being a holiday
+        assert_eq!(
+            calendar
+                .add_working_hours(&DateTime::try_from("2023-10-12 15:00:00")?, 8)?
+                .to_string(),
+            "2023-10-16 15:00:00"
+        );
+        // Ending exactly at the end of a window
+        assert_eq!(
+            calendar
+                .add_working_hours(&DateTime::try_from("2023-10-16 09:00:00")?, 8)?
+                .to_string(),
+            "2023-10-16 17:00:00"
+        );
+        // Starting outside working time
+        assert_eq!(
+            calendar
+                .add_working_hours(&DateTime::try_from("2023-10-14 12:00:00")?, 1)?
+                .to_string(),
+            "2023-10-16 10:00:00"
+        );
+        assert_eq!(
+            calendar
+                .add_working_time(
+                    &DateTime::try_from("2023-10-16 16:30:00")?,
+                    TimeDelta::minutes(45)
+                )?
+                .to_string(),
+            "2023-10-17 09:15:00"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn working_remove_hours() -> Result<(), SpanError> {
+        let calendar = WorkingCalendar::from_str(CALENDAR)?;
+        assert_eq!(
+            calendar
+                .add_working_hours(&DateTime::try_from("2023-10-16 15:00:00")?, -8)?
+                .to_string(),
+            "2023-10-12 15:00:00"
+        );
+        assert_eq!(
+            calendar
+                .add_working_hours(&DateTime::try_from("2023-10-16 08:00:00")?, -1)?
+                .to_string(),
+            "2023-10-12 16:00:00"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn working_time_between() -> Result<(), SpanError> {
+        let calendar = WorkingCalendar::from_str(CALENDAR)?;
+        assert_eq!(
+            calendar.working_time_between(
+                &DateTime::try_from("2023-10-12 15:00:00")?,
+                &DateTime::try_from("2023-10-16 15:00:00")?
+            ),
+            TimeDelta::hours(8)
+        );
+        assert_eq!(
+            calendar.working_time_between(
+                &DateTime::try_from("2023-10-16 15:00:00")?,
+                &DateTime::try_from("2023-10-12 15:00:00")?
+            ),
+            TimeDelta::hours(-8)
+        );
+        assert_eq!(
+            calendar.working_time_between(
+                &DateTime::try_from("2023-10-14 00:00:00")?,
+                &DateTime::try_from("2023-10-15 23:00:00")?
+            ),
+            TimeDelta::zero()
+        );
+        // A whole month of 21 working days, minus the holiday
+        assert_eq!(
+            calendar
+                .working_time_between(&DateTime::new(2023, 10, 1)?, &DateTime::new(2023, 11, 1)?),
+            TimeDelta::hours(21 * 8)
+        );
+        assert!(calendar.is_working(&DateTime::try_from("2023-10-12 09:00:00")?));
+        let weekly = WeeklySchedule::new().windows_on(
+            [
+                chrono::Weekday::Mon,
+                chrono::Weekday::Tue,
+                chrono::Weekday::Wed,
+                chrono::Weekday::Thu,
+                chrono::Weekday::Fri,
+            ],
+            TimeWindow::from_str("09:00-17:00")?,
+        );
+        assert_eq!(
+            WorkingCalendar::new(weekly).holiday(DateTime::new(2023, 10, 13)?.date()),
+            calendar
+        );
+        assert!(!calendar.is_working(&DateTime::try_from("2023-10-13 09:00:00")?));
+        Ok(())
+    }
+
+    #[test]
+    fn working_round_trip() -> Result<(), SpanError> {
+        let calendar = WorkingCalendar::from_str("Mo-Fr 08:00-12:00,13:00-17:00; Sa 22:00-02:00")?;
+        let start = DateTime::try_from("2023-10-09 10:30:00")?;
+        for hours in [1, 5, 13, 40, 100] {
+            let end = calendar.add_working_hours(&start, hours)?;
+            assert_eq!(
+                calendar.working_time_between(&start, &end),
+                TimeDelta::hours(hours)
+            );
+            assert_eq!(calendar.add_working_hours(&end, -hours)?, start);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn working_empty_calendar() -> Result<(), SpanError> {
+        let err = WorkingCalendar::default()
+            .add_working_hours(&DateTime::try_from("2023-10-09 09:00:00")?, 1)
+            .unwrap_err();
+        assert_eq!(err.code(), "invalid_update");
+        assert_eq!(
+            WorkingCalendar::default()
+                .add_working_hours(&DateTime::try_from("2023-10-09 09:00:00")?, 0)?,
+            DateTime::try_from("2023-10-09 09:00:00")?
+        );
+        Ok(())
+    }
+}